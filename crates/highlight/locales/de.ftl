@@ -0,0 +1,458 @@
+language-name = Deutsch
+
+## Durations
+
+duration-day = { $count } { $count ->
+        [one] Tag
+       *[other] Tage
+    }
+duration-hour = { $count } { $count ->
+        [one] Stunde
+       *[other] Stunden
+    }
+duration-minute = { $count } { $count ->
+        [one] Minute
+       *[other] Minuten
+    }
+duration-second = { $count } { $count ->
+        [one] Sekunde
+       *[other] Sekunden
+    }
+
+## Errors
+
+error-not-in-server = Dieser Befehl kann nur auf einem Server verwendet werden
+error-missing-parameter = Fehlender Parameter
+error-missing-permission = Fehlende Berechtigung `{ $permission }`.
+error-bot-missing-permission = Dem Bot fehlt die Berechtigung `{ $permission }`.
+error-bot-not-elevated = Der Bot hat nicht genug Berechtigungen dafür.
+error-unknown = Etwas ist schiefgelaufen, Fehler-ID: `{ $id }`
+error-invalid-channel = Ungültiger Kanal
+error-channel-not-in-server = Der Kanal ist nicht auf diesem Server.
+error-role-too-high = Du kannst { $role } nicht vergeben, da die Rolle nicht unter deiner höchsten Rolle liegt.
+
+## Help
+
+help-all-commands = ### Alle Befehle:
+help-no-description = Keine Beschreibung
+help-usage = Verwendung: { $usage }
+help-aliases = Aliase: { $aliases }
+help-subcommands = Befehle:
+help-command-not-found = Befehl `{ $name }` nicht gefunden.
+
+help-highlight = Verwalte deine Schlüsselwörter.
+help-highlight-add = Fügt ein Schlüsselwort hinzu.
+help-highlight-remove = Entfernt ein Schlüsselwort.
+help-highlight-block = Verhindert, dass ein Benutzer dich mit deinen Schlüsselwörtern erwähnt.
+help-highlight-unblock = Erlaubt einem Benutzer wieder, dich mit deinen Schlüsselwörtern zu erwähnen.
+help-highlight-view = Zeigt die Schlüsselwörter eines Benutzers auf diesem Server.
+help-highlight-clear = Löscht alle deine Schlüsselwörter auf diesem Server.
+help-remind = Erstellt eine Erinnerung.
+help-remind-every = Erstellt eine wiederkehrende Erinnerung.
+help-remind-list = Zeigt deine Erinnerungen.
+help-remind-cancel = Bricht eine deiner Erinnerungen ab.
+help-locale = Zeigt oder ändert deine Sprache.
+help-locale-set = Ändert deine Sprache.
+help-locale-reset = Verwendet die Sprache des Servers.
+help-locale-server = Ändert die Sprache des Servers.
+
+## Highlights
+
+highlight-list = Deine Schlüsselwörter sind:
+highlight-list-user = Die Schlüsselwörter von { $user } sind:
+highlight-max-keywords = Maximale Anzahl an Schlüsselwörtern erreicht ({ $max })
+highlight-added = Zu deinen Schlüsselwörtern hinzugefügt.
+highlight-exists = Das Schlüsselwort existiert bereits.
+highlight-removed = Aus deinen Schlüsselwörtern entfernt.
+highlight-missing = Das Schlüsselwort existiert nicht.
+highlight-cleared = { $count } { $count ->
+        [one] Schlüsselwort
+       *[other] Schlüsselwörter
+    } gelöscht
+highlight-blocked = { $user } blockiert
+highlight-unblocked = { $user } nicht mehr blockiert
+highlight-notification = In [{ $server } › { $channel }]({ $link }) wurdest du mit **{ $keyword }** erwähnt
+jump-to = Zur Nachricht
+
+## Reminders
+
+remind-set = Ich erinnere dich in { $duration }. (ID: { $id })
+remind-set-recurring = Ich erinnere dich alle { $duration }. (ID: { $id })
+remind-none = Du hast keine Erinnerungen.
+remind-list-header = ### Deine Erinnerungen:
+remind-list-entry = - `{ $id }` in { $due }: { $content }
+remind-list-entry-recurring = - `{ $id }` in { $due }, alle { $interval }: { $content }
+remind-cancelled = Erinnerung { $id } abgebrochen.
+remind-not-found = Keine Erinnerung mit der ID { $id }.
+remind-invalid-duration = Die Dauer muss zwischen 1 Sekunde und { $max } liegen.
+remind-invalid-content = Der Erinnerungstext muss zwischen 1 und { $max } Zeichen lang sein.
+remind-max-reminders = Maximale Anzahl an Erinnerungen erreicht ({ $max })
+remind-min-interval = Wiederkehrende Erinnerungen müssen mindestens { $min } auseinander liegen.
+remind-title = Erinnerung
+
+## Timeouts
+
+timeout-too-long = Dauer zu lang
+timeout-applied = { $member } wurde für { $duration } stummgeschaltet. (Fall #{ $case })
+timeout-not-timed-out = Das Mitglied ist nicht stummgeschaltet.
+timeout-removed = Stummschaltung von { $member } aufgehoben (Fall #{ $case })
+
+## Locale
+
+locale-current = Deine Sprache ist **{ $locale }**, die Sprache dieses Servers ist **{ $server }**.
+    Verfügbare Sprachen: { $available }
+locale-default = Standard
+locale-set = Deine Sprache ist jetzt **{ $locale }**.
+locale-reset = Deine Sprache folgt jetzt der des Servers.
+locale-server-set = Die Sprache dieses Servers ist jetzt **{ $locale }**.
+locale-server-reset = Die Sprache des Servers wurde zurückgesetzt.
+locale-unknown = Unbekannte Sprache `{ $locale }`, verfügbare Sprachen sind { $available }.
+
+## Pagination
+
+paginator-page = Seite { $page }/{ $total }
+
+## Moderation
+
+case-not-found = Fall #{ $case } nicht gefunden.
+case-no-reason = Kein Grund
+case-no-target = Kein Ziel für die Aktion.
+case-no-duration = Keine Dauer für den temporären Bann.
+case-unknown-action = Unbekannte Fallaktion `{ $action }`
+case-action-ban = Bann
+case-action-kick = Kick
+case-action-purge = Bereinigung
+case-action-softban = Softban
+case-action-tempban = Temporärer Bann
+case-action-timeout = Timeout
+case-action-unban = Entbannung
+case-action-untimeout = Timeout aufgehoben
+case-action-warn = Verwarnung
+case-embed-title = Fall #{ $case } | { $action }
+case-embed-user = **Benutzer:** <@{ $user }> ({ $user })
+case-embed-moderator = **Moderator:** <@{ $moderator }>
+case-embed-duration = **Dauer:** { $duration }
+case-embed-reason = **Grund:** { $reason }
+case-embed-date = **Datum:** <t:{ $date }:f>
+case-embed-updated = **Aktualisiert:** <t:{ $date }:f>
+cases-none = { $user } hat keine Fälle.
+cases-header = ### { $user } hat { $count } { $count ->
+        [one] Fall
+       *[other] Fälle
+    }:
+ban-done = { $user } gebannt. (Fall #{ $case })
+tempban-done = { $user } für { $duration } gebannt. (Fall #{ $case })
+tempban-expired = Temporärer Bann abgelaufen
+softban-done = { $user } per Softban entfernt und { $count } { $count ->
+        [one] Nachricht
+       *[other] Nachrichten
+    } gelöscht. (Fall #{ $case })
+kick-done = { $user } gekickt. (Fall #{ $case })
+reason-missing = Kein Grund angegeben.
+reason-updated = Grund für Fall #{ $case } aktualisiert.
+unban-no-user = Kein Benutzer angegeben.
+unban-not-found = Kein gebannter Benutzer gefunden.
+unban-multiple = Mehrere gebannte Benutzer gefunden, verwende ihre ID:
+unban-done = { $user } entbannt. (Fall #{ $case })
+warn-done = { $member } verwarnt, jetzt { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    }. (Fall #{ $case })
+warn-escalated = Eskaliert: { $action }. (Fall #{ $case })
+warn-thresholds-none = Keine Verwarnungsschwellen eingerichtet.
+warn-thresholds-header = ### Verwarnungsschwellen:
+warn-threshold-entry = - { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    }: { $action }
+warn-threshold-entry-duration = - { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    }: { $action } für { $duration }
+warn-invalid-count = Ungültige Anzahl an Verwarnungen.
+warn-threshold-removed = Schwelle für { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    } entfernt.
+warn-threshold-missing = Keine Schwelle für { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    } eingerichtet.
+warn-threshold-needs-duration = `{ $action }` benötigt eine Dauer.
+warn-threshold-invalid-action = Die Aktion muss `timeout`, `kick`, `tempban`, `ban` oder `none` sein.
+warn-threshold-set = Mitglieder mit { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    } erhalten jetzt: { $action }.
+warn-escalation-reason = { $count } { $count ->
+        [one] Verwarnung
+       *[other] Verwarnungen
+    } erreicht
+
+## Purge
+
+purge-invalid-regex = Ungültiger regulärer Ausdruck.
+purge-invalid-message-id = Ungültige Nachrichten-ID `{ $id }`.
+purge-unknown-filter = Unbekannter Filter `{ $filter }`.
+purge-no-arguments = Keine Benutzer, kein Limit und keine Filter angegeben.
+purge-done = { $count } { $count ->
+        [one] Nachricht
+       *[other] Nachrichten
+    } gelöscht.
+purge-age-limit = *Nachrichten, die älter als 7 Tage sind, können nicht gesammelt gelöscht werden.*
+purge-reason = { $count } { $count ->
+        [one] Nachricht
+       *[other] Nachrichten
+    } in { $channel } gelöscht
+
+## Slowmode
+
+slowmode-current = Der Slowmode in { $channel } ist auf { $interval } gesetzt.
+slowmode-current-disabled = Der Slowmode ist in { $channel } deaktiviert.
+slowmode-disabled = Slowmode in { $channel } deaktiviert.
+slowmode-invalid-interval = Der Slowmode muss zwischen 1 Sekunde und { $max } liegen.
+slowmode-set = Slowmode in { $channel } auf { $interval } gesetzt, schneller gesendete Nachrichten werden gelöscht.
+
+## Lockdown
+
+lockdown-channel = { $channel } gesperrt.
+lockdown-channel-duration = { $channel } für { $duration } gesperrt.
+lockdown-server = { $count } { $count ->
+        [one] Kanal
+       *[other] Kanäle
+    } gesperrt.
+lockdown-server-duration = { $count } { $count ->
+        [one] Kanal
+       *[other] Kanäle
+    } für { $duration } gesperrt.
+unlock-not-locked = { $channel } ist nicht gesperrt.
+unlock-channel = { $channel } entsperrt.
+unlock-server = { $count } { $count ->
+        [one] Kanal
+       *[other] Kanäle
+    } entsperrt.
+
+## Logs
+
+modlog-current = Der Moderationslog-Kanal ist { $channel }.
+modlog-none = Kein Moderationslog-Kanal eingerichtet.
+modlog-set = Moderationslog-Kanal auf { $channel } gesetzt.
+modlog-disabled = Moderationslog deaktiviert.
+memberlog-current = Der Mitgliederlog-Kanal ist { $channel }.
+memberlog-none = Kein Mitgliederlog-Kanal eingerichtet.
+memberlog-set = Mitgliederlog-Kanal auf { $channel } gesetzt, Beitritte, Austritte, Kicks und Bans werden dort protokolliert.
+memberlog-disabled = Mitgliederlog deaktiviert.
+messagelog-current = Der Nachrichtenlog-Kanal ist { $channel }.
+messagelog-none = Kein Nachrichtenlog-Kanal eingerichtet.
+messagelog-set = Nachrichtenlog-Kanal auf { $channel } gesetzt, gelöschte und bearbeitete Nachrichten werden dort protokolliert.
+messagelog-disabled = Nachrichtenlog deaktiviert.
+memberlog-joined = Mitglied beigetreten: { $user }
+memberlog-left = Mitglied ausgetreten: { $user }
+memberlog-kicked = Mitglied gekickt: { $user }
+memberlog-banned = Mitglied gebannt: { $user }
+memberlog-account-age = Konto vor { $age } erstellt
+messagelog-deleted = **Nachricht von <@{ $author }> in <#{ $channel }> gelöscht**
+messagelog-edited = **Nachricht von <@{ $author }> in <#{ $channel }> bearbeitet** [Zur Nachricht]({ $link })
+messagelog-attachments = **Anhänge:**
+messagelog-bulk-title = Massenlöschung
+messagelog-bulk-deleted = **{ $count } { $count ->
+        [one] Nachricht
+       *[other] Nachrichten
+    } in <#{ $channel }> gelöscht**
+messagelog-bulk-more = ...und { $count } weitere
+snipe-none = Es gibt nichts zum Snipen.
+snipe-title = Kürzlich gelöschte Nachrichten
+
+## Greetings
+
+greeting-current = { $kind ->
+        [welcome] Willkommensnachrichten
+       *[goodbye] Abschiedsnachrichten
+    } werden in { $channel } gesendet:
+    ```
+    { $message }
+    ```
+greeting-none = { $kind ->
+        [welcome] Willkommensnachrichten
+       *[goodbye] Abschiedsnachrichten
+    } sind deaktiviert.
+greeting-too-long = Die Nachricht darf höchstens { $max } Zeichen lang sein.
+greeting-set = { $kind ->
+        [welcome] Willkommensnachrichten
+       *[goodbye] Abschiedsnachrichten
+    } werden in { $channel } gesendet.
+greeting-disabled = { $kind ->
+        [welcome] Willkommensnachrichten
+       *[goodbye] Abschiedsnachrichten
+    } deaktiviert.
+
+## Auto roles
+
+autorole-none = Keine automatischen Rollen eingerichtet.
+autorole-current = Mitglieder erhalten beim Beitritt { $roles }.
+autorole-exists = { $role } ist bereits eine automatische Rolle.
+autorole-max-roles = Server können höchstens { $max } automatische Rollen haben.
+autorole-added = Neue Mitglieder erhalten { $role }.
+autorole-missing = { $role } ist keine automatische Rolle.
+autorole-removed = Neue Mitglieder erhalten { $role } nicht mehr.
+
+## Reaction roles
+
+reactionroles-invalid-emoji = `{ $emoji }` ist kein Emoji.
+reactionroles-none = Keine Reaktionsrollen-Panels eingerichtet.
+reactionroles-list-header = ### Reaktionsrollen-Panels:
+reactionroles-expected-pairs = Erwartet wurden Paare aus `<emoji> <role>`.
+reactionroles-max-roles = Panels können höchstens { $max } Rollen haben.
+reactionroles-duplicate-emoji = { $emoji } wird mehrfach verwendet.
+reactionroles-panel-title = ### Reagiere, um eine Rolle zu erhalten
+reactionroles-panel-title-unique = ### Reagiere, um eine Rolle zu erhalten (wähle eine)
+reactionroles-created = Reaktionsrollen-Panel `{ $id }` in { $channel } erstellt.
+reactionroles-not-found = Kein Reaktionsrollen-Panel mit der ID `{ $id }`.
+reactionroles-deleted = Reaktionsrollen-Panel gelöscht.
+reactionroles-unknown-mode = Unbekannter Modus `{ $mode }`, erlaubt sind `unique` und `multi`.
+
+## Prefixes
+
+prefix-list = ### Präfixe:
+prefix-list-default = ### Standardpräfixe:
+prefix-invalid-length = Ein Präfix muss zwischen 1 und { $max } Zeichen lang sein.
+prefix-exists = `{ $prefix }` ist bereits ein Präfix.
+prefix-max-prefixes = Server können höchstens { $max } Präfixe haben.
+prefix-added = Präfix `{ $prefix }` hinzugefügt.
+prefix-missing = `{ $prefix }` ist kein Präfix.
+prefix-last = Das letzte Präfix kann nicht entfernt werden, eine Erwähnung des Bots funktioniert immer als Präfix.
+prefix-removed = Präfix `{ $prefix }` entfernt.
+
+## Starboard
+
+starboard-current = Der Starboard-Kanal ist { $channel }, mit mindestens { $count } { $count ->
+        [one] Stern
+       *[other] Sternen
+    }.
+starboard-none = Kein Starboard-Kanal eingerichtet.
+starboard-channel-set = Starboard-Kanal auf { $channel } gesetzt.
+starboard-invalid-limit = Ungültiges Limit
+starboard-limit-set = Sternlimit des Starboards auf { $count } gesetzt.
+
+## Automod
+
+automod-rule-spam = { $threshold } gleiche Nachrichten innerhalb von { $window }
+automod-rule-mentions = { $threshold } oder mehr Erwähnungen
+automod-rule-caps = { $threshold }% oder mehr Großbuchstaben
+automod-rule-emoji = { $threshold } oder mehr Emojis
+automod-rule-invites = Einladungen zu anderen Servern
+automod-rule-phrases = verbotenen Ausdrücken
+automod-none = Keine Automod-Regeln aktiviert.
+automod-list-header = ### Automod-Regeln:
+automod-list-entry = - `{ $rule }`: { $description } → { $actions }
+automod-list-entry-timeout = - `{ $rule }`: { $description } → { $actions } (Timeout { $timeout })
+automod-exempt-list = Ausgenommen: { $targets }
+automod-no-actions = Keine Aktionen angegeben.
+automod-actions-set = `{ $rule }` wird jetzt: { $actions }.
+automod-not-enabled = `{ $rule }` ist nicht aktiviert.
+automod-disabled = `{ $rule }` deaktiviert.
+automod-invalid-threshold = Ungültige Schwelle.
+automod-enabled = `{ $rule }` aktiviert, gegen Nachrichten mit { $description } wird vorgegangen.
+automod-exempted = { $target } ist jetzt vom Automod ausgenommen.
+automod-unexempted = { $target } ist nicht mehr vom Automod ausgenommen.
+automod-not-exempt = { $target } ist nicht vom Automod ausgenommen.
+automod-phrases-none = Keine verbotenen Ausdrücke.
+automod-phrases-header = Verbotene Ausdrücke:
+automod-phrase-missing = Kein Ausdruck angegeben.
+automod-phrase-added = Verbotenen Ausdruck hinzugefügt.
+automod-phrase-removed = Verbotenen Ausdruck entfernt.
+automod-phrase-not-banned = Der Ausdruck ist nicht verboten.
+automod-reason = Automod: { $rule }
+automod-unknown-rule = Unbekannte Automod-Regel `{ $rule }`, erlaubt sind { $rules }.
+automod-unknown-action = Unbekannte Automod-Aktion `{ $action }`, erlaubt sind `delete`, `warn` und `timeout`.
+
+## Command restrictions
+
+command-unknown = Unbekannter Befehl `{ $command }`.
+command-protected = `{ $command }` kann nicht eingeschränkt werden.
+command-policies-none = Keine Befehlseinschränkungen eingerichtet.
+command-policies-header = ### Befehlseinschränkungen:
+command-policy-disabled = deaktiviert
+command-policy-channels = Kanäle: { $channels }
+command-policy-allowed = erlaubt: { $roles }
+command-policy-denied = verboten: { $roles }
+command-reset = Alle Einschränkungen von `{ $command }` entfernt.
+command-no-restrictions = `{ $command }` hat keine Einschränkungen.
+command-disabled = `{ $command }` ist jetzt deaktiviert.
+command-enabled = `{ $command }` ist jetzt aktiviert.
+command-unrestricted = `{ $command }` kann jetzt in jedem Kanal verwendet werden.
+command-restricted = `{ $command }` kann jetzt nur noch in { $channels } verwendet werden.
+command-no-roles = Keine Rollen angegeben.
+command-roles-allowed = { $roles } können `{ $command }` jetzt verwenden.
+command-roles-denied = { $roles } können `{ $command }` nicht mehr verwenden.
+policy-disabled = Dieser Befehl ist auf diesem Server deaktiviert.
+policy-wrong-channel = Dieser Befehl kann nur in { $channels } verwendet werden.
+policy-not-allowed = Du darfst diesen Befehl nicht verwenden.
+
+## Admin
+
+admin-summary =
+    ```
+    Server: { $servers }
+    Benutzer: { $users }
+    Schlüsselwörter: { $keywords }
+    Gesperrt: { $blacklisted }
+    ```
+admin-reload-unchanged = Konfiguration neu geladen, nichts hat sich geändert.
+admin-reloaded =
+    Konfiguration neu geladen:
+    ```
+    { $changes }
+    ```
+admin-reload-failed = Konfiguration wird nicht neu geladen: { $error }
+admin-flushed = { $count } { $count ->
+        [one] Cache-Eintrag
+       *[other] Cache-Einträge
+    } geleert.
+admin-servers-header = ### Server ({ $count }), Seite { $page }/{ $total }:
+admin-not-in-server = Nicht auf diesem Server.
+admin-left-server = { $server } (`{ $id }`) verlassen.
+admin-status-set = Status auf `{ $status }` gesetzt.
+admin-status-reset = Status auf `{ $status }` zurückgesetzt.
+admin-unknown-server = Unbekannt
+admin-none = Keiner
+admin-default-prefixes = Standard
+admin-user-keywords = Schlüsselwörter: { $count } auf { $servers } { $servers ->
+        [one] Server
+       *[other] Servern
+    }
+admin-user-summary =
+    Blockierte Benutzer: { $blocked }
+    Moderationsfälle: { $cases }
+    Gesperrt: { $blacklisted ->
+        [yes] ja
+       *[no] nein
+    }
+admin-server-summary =
+    Schlüsselwörter: { $keywords } von { $users } { $users ->
+        [one] Benutzer
+       *[other] Benutzern
+    }
+    Präfixe: { $prefixes }
+    Starboard: { $starboard } ({ $stars } { $stars ->
+        [one] Stern
+       *[other] Sterne
+    })
+    Moderationslog: { $modlog }
+    Moderationsfälle: { $cases }
+    Automod-Regeln: { $automod }
+    Befehlsrichtlinien: { $policies }
+    Gesperrt: { $blacklisted ->
+        [yes] ja
+       *[no] nein
+    }
+blacklist-empty = Die Sperrliste ist leer.
+blacklist-header = ### Sperrliste:
+blacklist-no-reason = Kein Grund
+blacklist-unknown-target = `{ $id }` ist kein bekannter Benutzer oder Server.
+blacklist-added = { $kind ->
+        [server] Server
+       *[user] Benutzer
+    } `{ $id }` gesperrt.
+blacklist-removed = `{ $id }` von der Sperrliste entfernt.
+blacklist-missing = `{ $id }` ist nicht gesperrt.
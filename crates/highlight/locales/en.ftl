@@ -0,0 +1,454 @@
+# English, the fallback for every other locale.
+
+language-name = English
+
+## Durations
+
+duration-day = { $count } { $count ->
+        [one] day
+       *[other] days
+    }
+duration-hour = { $count } { $count ->
+        [one] hour
+       *[other] hours
+    }
+duration-minute = { $count } { $count ->
+        [one] minute
+       *[other] minutes
+    }
+duration-second = { $count } { $count ->
+        [one] second
+       *[other] seconds
+    }
+
+## Errors
+
+error-not-in-server = This command can only be used in a server
+error-missing-parameter = Missing parameter
+error-missing-permission = Missing `{ $permission }` permission.
+error-bot-missing-permission = Bot is missing permission `{ $permission }`.
+error-bot-not-elevated = Bot does not have enough permission to do this.
+error-unknown = Something went wrong, error ID: `{ $id }`
+error-invalid-channel = Invalid channel
+error-channel-not-in-server = Channel is not in this server.
+error-role-too-high = You cannot hand out { $role } as it is not below your highest role.
+
+## Help
+
+help-all-commands = ### All commands:
+help-no-description = No description
+help-usage = Usage: { $usage }
+help-aliases = Aliases: { $aliases }
+help-subcommands = Commands:
+help-command-not-found = Command `{ $name }` not found.
+
+help-highlight = Manage highlight keywords.
+help-highlight-add = Adds a highlight keyword.
+help-highlight-remove = Removes a highlighted keyword.
+help-highlight-block = Blocks a user from highlighting you.
+help-highlight-unblock = Unblocks a user from highlighting you.
+help-highlight-view = Views the keywords a user has in this server.
+help-highlight-clear = Clears all keywords in this server.
+help-remind = Sets a reminder.
+help-remind-every = Sets a recurring reminder.
+help-remind-list = Lists your reminders.
+help-remind-cancel = Cancels one of your reminders.
+help-locale = Shows or changes your language.
+help-locale-set = Changes your language.
+help-locale-reset = Uses the server's language.
+help-locale-server = Changes the server's language.
+
+## Highlights
+
+highlight-list = Your highlights are:
+highlight-list-user = { $user }'s highlights are:
+highlight-max-keywords = Max keyword amount reached ({ $max })
+highlight-added = Added to your highlights.
+highlight-exists = Keyword already exists.
+highlight-removed = Removed from your highlights.
+highlight-missing = Keyword doesn't exist.
+highlight-cleared = Cleared { $count } { $count ->
+        [one] keyword
+       *[other] keywords
+    }
+highlight-blocked = Blocked { $user }
+highlight-unblocked = Unblocked { $user }
+highlight-notification = In [{ $server } › { $channel }]({ $link }), you were mentioned with **{ $keyword }**
+jump-to = Jump to
+
+## Reminders
+
+remind-set = I'll remind you in { $duration }. (ID: { $id })
+remind-set-recurring = I'll remind you every { $duration }. (ID: { $id })
+remind-none = You have no reminders.
+remind-list-header = ### Your reminders:
+remind-list-entry = - `{ $id }` in { $due }: { $content }
+remind-list-entry-recurring = - `{ $id }` in { $due }, every { $interval }: { $content }
+remind-cancelled = Cancelled reminder { $id }.
+remind-not-found = No reminder with ID { $id }.
+remind-invalid-duration = Duration must be between 1 second and { $max }.
+remind-invalid-content = Reminder text must be between 1 and { $max } characters.
+remind-max-reminders = Max reminder amount reached ({ $max })
+remind-min-interval = Recurring reminders must be at least { $min } apart.
+remind-title = Reminder
+
+## Timeouts
+
+timeout-too-long = Duration too long
+timeout-applied = Timed out { $member } for { $duration }. (Case #{ $case })
+timeout-not-timed-out = Member is not timed out.
+timeout-removed = Removed timeout for { $member } (Case #{ $case })
+
+## Locale
+
+locale-current = Your language is **{ $locale }**, this server's language is **{ $server }**.
+    Available languages: { $available }
+locale-default = default
+locale-set = Your language is now **{ $locale }**.
+locale-reset = Your language now follows the server.
+locale-server-set = This server's language is now **{ $locale }**.
+locale-server-reset = This server's language was reset to the default.
+locale-unknown = Unknown language `{ $locale }`, available languages are { $available }.
+
+## Pagination
+
+paginator-page = Page { $page }/{ $total }
+
+## Moderation
+
+case-not-found = Case #{ $case } not found.
+case-no-reason = No reason
+case-no-target = No target for action.
+case-no-duration = No duration for tempban.
+case-unknown-action = Unknown case action `{ $action }`
+case-action-ban = Ban
+case-action-kick = Kick
+case-action-purge = Purge
+case-action-softban = Softban
+case-action-tempban = Tempban
+case-action-timeout = Timeout
+case-action-unban = Unban
+case-action-untimeout = Timeout Removed
+case-action-warn = Warn
+case-embed-title = Case #{ $case } | { $action }
+case-embed-user = **User:** <@{ $user }> ({ $user })
+case-embed-moderator = **Moderator:** <@{ $moderator }>
+case-embed-duration = **Duration:** { $duration }
+case-embed-reason = **Reason:** { $reason }
+case-embed-date = **Date:** <t:{ $date }:f>
+case-embed-updated = **Updated:** <t:{ $date }:f>
+cases-none = { $user } has no cases.
+cases-header = ### { $user } has { $count } { $count ->
+        [one] case
+       *[other] cases
+    }:
+ban-done = Banned { $user }. (Case #{ $case })
+tempban-done = Banned { $user } for { $duration }. (Case #{ $case })
+tempban-expired = Tempban expired
+softban-done = Softbanned { $user } and deleted { $count } { $count ->
+        [one] message
+       *[other] messages
+    }. (Case #{ $case })
+kick-done = Kicked { $user }. (Case #{ $case })
+reason-missing = No reason given.
+reason-updated = Updated reason for case #{ $case }.
+unban-no-user = No user specified.
+unban-not-found = No banned user found.
+unban-multiple = Multiple banned users found, use their ID:
+unban-done = Unbanned { $user }. (Case #{ $case })
+warn-done = Warned { $member }, they now have { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    }. (Case #{ $case })
+warn-escalated = Escalated: { $action }. (Case #{ $case })
+warn-thresholds-none = No warn thresholds configured.
+warn-thresholds-header = ### Warn thresholds:
+warn-threshold-entry = - { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    }: { $action }
+warn-threshold-entry-duration = - { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    }: { $action } for { $duration }
+warn-invalid-count = Invalid warn count.
+warn-threshold-removed = Removed threshold for { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    }.
+warn-threshold-missing = No threshold set for { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    }.
+warn-threshold-needs-duration = `{ $action }` requires a duration.
+warn-threshold-invalid-action = Action must be one of `timeout`, `kick`, `tempban`, `ban` or `none`.
+warn-threshold-set = Members reaching { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    } will now receive: { $action }.
+warn-escalation-reason = Reached { $count } { $count ->
+        [one] warning
+       *[other] warnings
+    }
+
+## Purge
+
+purge-invalid-regex = Invalid regex.
+purge-invalid-message-id = Invalid message ID `{ $id }`.
+purge-unknown-filter = Unknown purge filter `{ $filter }`.
+purge-no-arguments = No specified users, limit or filters.
+purge-done = Deleted { $count } { $count ->
+        [one] message
+       *[other] messages
+    }.
+purge-age-limit = *Messages older than 7 days cannot be bulk deleted.*
+purge-reason = Deleted { $count } { $count ->
+        [one] message
+       *[other] messages
+    } in { $channel }
+
+## Slowmode
+
+slowmode-current = Slowmode in { $channel } is set to { $interval }.
+slowmode-current-disabled = Slowmode is disabled in { $channel }.
+slowmode-disabled = Disabled slowmode in { $channel }.
+slowmode-invalid-interval = Slowmode must be between 1 second and { $max }.
+slowmode-set = Set slowmode in { $channel } to { $interval }, messages sent faster than this will be deleted.
+
+## Lockdown
+
+lockdown-channel = Locked { $channel }.
+lockdown-channel-duration = Locked { $channel } for { $duration }.
+lockdown-server = Locked { $count } { $count ->
+        [one] channel
+       *[other] channels
+    }.
+lockdown-server-duration = Locked { $count } { $count ->
+        [one] channel
+       *[other] channels
+    } for { $duration }.
+unlock-not-locked = { $channel } is not locked.
+unlock-channel = Unlocked { $channel }.
+unlock-server = Unlocked { $count } { $count ->
+        [one] channel
+       *[other] channels
+    }.
+
+## Logs
+
+modlog-current = Mod log channel is set to { $channel }.
+modlog-none = No mod log channel configured.
+modlog-set = Mod log channel set to { $channel }.
+modlog-disabled = Mod log disabled.
+memberlog-current = Member log channel is set to { $channel }.
+memberlog-none = No member log channel configured.
+memberlog-set = Member log channel set to { $channel }, joins, leaves, kicks and bans will be logged there.
+memberlog-disabled = Member log disabled.
+messagelog-current = Message log channel is set to { $channel }.
+messagelog-none = No message log channel configured.
+messagelog-set = Message log channel set to { $channel }, deleted and edited messages will be logged there.
+messagelog-disabled = Message log disabled.
+memberlog-joined = Member joined: { $user }
+memberlog-left = Member left: { $user }
+memberlog-kicked = Member kicked: { $user }
+memberlog-banned = Member banned: { $user }
+memberlog-account-age = Account created { $age } ago
+messagelog-deleted = **Message by <@{ $author }> deleted in <#{ $channel }>**
+messagelog-edited = **Message by <@{ $author }> edited in <#{ $channel }>** [Jump to]({ $link })
+messagelog-attachments = **Attachments:**
+messagelog-bulk-title = Bulk delete
+messagelog-bulk-deleted = **{ $count } { $count ->
+        [one] message
+       *[other] messages
+    } deleted in <#{ $channel }>**
+messagelog-bulk-more = ...and { $count } more
+snipe-none = There is nothing to snipe.
+snipe-title = Recently deleted messages
+
+## Greetings
+
+greeting-current = { $kind ->
+        [welcome] Welcome
+       *[goodbye] Goodbye
+    } messages are sent to { $channel }:
+    ```
+    { $message }
+    ```
+greeting-none = { $kind ->
+        [welcome] Welcome
+       *[goodbye] Goodbye
+    } messages are disabled.
+greeting-too-long = Message must be at most { $max } characters.
+greeting-set = { $kind ->
+        [welcome] Welcome
+       *[goodbye] Goodbye
+    } messages will be sent to { $channel }.
+greeting-disabled = { $kind ->
+        [welcome] Welcome
+       *[goodbye] Goodbye
+    } messages disabled.
+
+## Auto roles
+
+autorole-none = No auto roles configured.
+autorole-current = Members are given { $roles } when joining.
+autorole-exists = { $role } is already an auto role.
+autorole-max-roles = Servers can have at most { $max } auto roles.
+autorole-added = New members will be given { $role }.
+autorole-missing = { $role } is not an auto role.
+autorole-removed = New members will no longer be given { $role }.
+
+## Reaction roles
+
+reactionroles-invalid-emoji = `{ $emoji }` is not an emoji.
+reactionroles-none = No reaction role panels configured.
+reactionroles-list-header = ### Reaction role panels:
+reactionroles-expected-pairs = Expected pairs of `<emoji> <role>`.
+reactionroles-max-roles = Panels can have at most { $max } roles.
+reactionroles-duplicate-emoji = { $emoji } is used more than once.
+reactionroles-panel-title = ### React to get a role
+reactionroles-panel-title-unique = ### React to get a role (pick one)
+reactionroles-created = Created reaction role panel `{ $id }` in { $channel }.
+reactionroles-not-found = No reaction role panel with ID `{ $id }`.
+reactionroles-deleted = Deleted reaction role panel.
+reactionroles-unknown-mode = Unknown reaction role mode `{ $mode }`, must be either `unique` or `multi`.
+
+## Prefixes
+
+prefix-list = ### Prefixes:
+prefix-list-default = ### Default prefixes:
+prefix-invalid-length = Prefix must be between 1 and { $max } characters.
+prefix-exists = `{ $prefix }` is already a prefix.
+prefix-max-prefixes = Servers can have at most { $max } prefixes.
+prefix-added = Added prefix `{ $prefix }`.
+prefix-missing = `{ $prefix }` is not a prefix.
+prefix-last = Cannot remove the last prefix, mentioning the bot always works as a prefix.
+prefix-removed = Removed prefix `{ $prefix }`.
+
+## Starboard
+
+starboard-current = Starboard channel is set to { $channel }, with a minimum star count of { $count }.
+starboard-none = No starboard channel configured.
+starboard-channel-set = Starboard channel set to { $channel }.
+starboard-invalid-limit = Invalid limit
+starboard-limit-set = Starboard star limit set to { $count }.
+
+## Automod
+
+automod-rule-spam = { $threshold } duplicate messages within { $window }
+automod-rule-mentions = { $threshold } or more mentions
+automod-rule-caps = { $threshold }% or more capital letters
+automod-rule-emoji = { $threshold } or more emojis
+automod-rule-invites = invites to other servers
+automod-rule-phrases = banned phrases
+automod-none = No automod rules enabled.
+automod-list-header = ### Automod rules:
+automod-list-entry = - `{ $rule }`: { $description } → { $actions }
+automod-list-entry-timeout = - `{ $rule }`: { $description } → { $actions } (timeout { $timeout })
+automod-exempt-list = Exempt: { $targets }
+automod-no-actions = No actions given.
+automod-actions-set = `{ $rule }` will now { $actions }.
+automod-not-enabled = `{ $rule }` is not enabled.
+automod-disabled = Disabled `{ $rule }`.
+automod-invalid-threshold = Invalid threshold.
+automod-enabled = Enabled `{ $rule }`, messages with { $description } will be actioned.
+automod-exempted = { $target } is now exempt from automod.
+automod-unexempted = { $target } is no longer exempt from automod.
+automod-not-exempt = { $target } is not exempt from automod.
+automod-phrases-none = No banned phrases.
+automod-phrases-header = Banned phrases:
+automod-phrase-missing = No phrase given.
+automod-phrase-added = Added banned phrase.
+automod-phrase-removed = Removed banned phrase.
+automod-phrase-not-banned = Phrase is not banned.
+automod-reason = Automod: { $rule }
+automod-unknown-rule = Unknown automod rule `{ $rule }`, must be one of { $rules }.
+automod-unknown-action = Unknown automod action `{ $action }`, must be one of `delete`, `warn` or `timeout`.
+
+## Command restrictions
+
+command-unknown = Unknown command `{ $command }`.
+command-protected = `{ $command }` cannot be restricted.
+command-policies-none = No command restrictions configured.
+command-policies-header = ### Command restrictions:
+command-policy-disabled = disabled
+command-policy-channels = channels: { $channels }
+command-policy-allowed = allowed: { $roles }
+command-policy-denied = denied: { $roles }
+command-reset = Removed all restrictions from `{ $command }`.
+command-no-restrictions = `{ $command }` has no restrictions.
+command-disabled = `{ $command }` is now disabled.
+command-enabled = `{ $command }` is now enabled.
+command-unrestricted = `{ $command }` can now be used in any channel.
+command-restricted = `{ $command }` can now only be used in { $channels }.
+command-no-roles = No roles given.
+command-roles-allowed = { $roles } can now use `{ $command }`.
+command-roles-denied = { $roles } can no longer use `{ $command }`.
+policy-disabled = This command is disabled in this server.
+policy-wrong-channel = This command can only be used in { $channels }.
+policy-not-allowed = You are not allowed to use this command.
+
+## Admin
+
+admin-summary =
+    ```
+    Servers: { $servers }
+    Users: { $users }
+    Keywords: { $keywords }
+    Blacklisted: { $blacklisted }
+    ```
+admin-reload-unchanged = Config reloaded, nothing changed.
+admin-reloaded =
+    Config reloaded:
+    ```
+    { $changes }
+    ```
+admin-reload-failed = Refusing to reload config: { $error }
+admin-flushed = Flushed { $count } cached { $count ->
+        [one] entry
+       *[other] entries
+    }.
+admin-servers-header = ### Servers ({ $count }), page { $page }/{ $total }:
+admin-not-in-server = Not in that server.
+admin-left-server = Left { $server } (`{ $id }`).
+admin-status-set = Status set to `{ $status }`.
+admin-status-reset = Status reset to `{ $status }`.
+admin-unknown-server = Unknown
+admin-none = None
+admin-default-prefixes = default
+admin-user-keywords = Keywords: { $count } across { $servers } { $servers ->
+        [one] server
+       *[other] servers
+    }
+admin-user-summary =
+    Blocked users: { $blocked }
+    Moderation cases: { $cases }
+    Blacklisted: { $blacklisted ->
+        [yes] yes
+       *[no] no
+    }
+admin-server-summary =
+    Keywords: { $keywords } from { $users } { $users ->
+        [one] user
+       *[other] users
+    }
+    Prefixes: { $prefixes }
+    Starboard: { $starboard } ({ $stars } { $stars ->
+        [one] star
+       *[other] stars
+    })
+    Mod log: { $modlog }
+    Moderation cases: { $cases }
+    Automod rules: { $automod }
+    Command policies: { $policies }
+    Blacklisted: { $blacklisted ->
+        [yes] yes
+       *[no] no
+    }
+blacklist-empty = The blacklist is empty.
+blacklist-header = ### Blacklist:
+blacklist-no-reason = No reason
+blacklist-unknown-target = `{ $id }` is not a known user or server.
+blacklist-added = Blacklisted { $kind } `{ $id }`.
+blacklist-removed = Removed `{ $id }` from the blacklist.
+blacklist-missing = `{ $id }` is not blacklisted.
@@ -0,0 +1,458 @@
+language-name = Español
+
+## Durations
+
+duration-day = { $count } { $count ->
+        [one] día
+       *[other] días
+    }
+duration-hour = { $count } { $count ->
+        [one] hora
+       *[other] horas
+    }
+duration-minute = { $count } { $count ->
+        [one] minuto
+       *[other] minutos
+    }
+duration-second = { $count } { $count ->
+        [one] segundo
+       *[other] segundos
+    }
+
+## Errors
+
+error-not-in-server = Este comando solo se puede usar en un servidor
+error-missing-parameter = Falta un parámetro
+error-missing-permission = Falta el permiso `{ $permission }`.
+error-bot-missing-permission = Al bot le falta el permiso `{ $permission }`.
+error-bot-not-elevated = El bot no tiene suficientes permisos para hacer esto.
+error-unknown = Algo salió mal, ID del error: `{ $id }`
+error-invalid-channel = Canal no válido
+error-channel-not-in-server = El canal no está en este servidor.
+error-role-too-high = No puedes otorgar { $role } porque no está por debajo de tu rol más alto.
+
+## Help
+
+help-all-commands = ### Todos los comandos:
+help-no-description = Sin descripción
+help-usage = Uso: { $usage }
+help-aliases = Alias: { $aliases }
+help-subcommands = Comandos:
+help-command-not-found = No se encontró el comando `{ $name }`.
+
+help-highlight = Gestiona tus palabras clave.
+help-highlight-add = Añade una palabra clave.
+help-highlight-remove = Elimina una palabra clave.
+help-highlight-block = Impide que un usuario te mencione con tus palabras clave.
+help-highlight-unblock = Vuelve a permitir que un usuario te mencione con tus palabras clave.
+help-highlight-view = Muestra las palabras clave de un usuario en este servidor.
+help-highlight-clear = Borra todas tus palabras clave en este servidor.
+help-remind = Crea un recordatorio.
+help-remind-every = Crea un recordatorio recurrente.
+help-remind-list = Muestra tus recordatorios.
+help-remind-cancel = Cancela uno de tus recordatorios.
+help-locale = Muestra o cambia tu idioma.
+help-locale-set = Cambia tu idioma.
+help-locale-reset = Usa el idioma del servidor.
+help-locale-server = Cambia el idioma del servidor.
+
+## Highlights
+
+highlight-list = Tus palabras clave son:
+highlight-list-user = Las palabras clave de { $user } son:
+highlight-max-keywords = Has alcanzado el máximo de palabras clave ({ $max })
+highlight-added = Añadida a tus palabras clave.
+highlight-exists = La palabra clave ya existe.
+highlight-removed = Eliminada de tus palabras clave.
+highlight-missing = La palabra clave no existe.
+highlight-cleared = { $count ->
+        [one] Se borró { $count } palabra clave
+       *[other] Se borraron { $count } palabras clave
+    }
+highlight-blocked = Has bloqueado a { $user }
+highlight-unblocked = Has desbloqueado a { $user }
+highlight-notification = En [{ $server } › { $channel }]({ $link }), te mencionaron con **{ $keyword }**
+jump-to = Ir al mensaje
+
+## Reminders
+
+remind-set = Te lo recordaré en { $duration }. (ID: { $id })
+remind-set-recurring = Te lo recordaré cada { $duration }. (ID: { $id })
+remind-none = No tienes recordatorios.
+remind-list-header = ### Tus recordatorios:
+remind-list-entry = - `{ $id }` en { $due }: { $content }
+remind-list-entry-recurring = - `{ $id }` en { $due }, cada { $interval }: { $content }
+remind-cancelled = Recordatorio { $id } cancelado.
+remind-not-found = No hay ningún recordatorio con ID { $id }.
+remind-invalid-duration = La duración debe estar entre 1 segundo y { $max }.
+remind-invalid-content = El texto del recordatorio debe tener entre 1 y { $max } caracteres.
+remind-max-reminders = Has alcanzado el máximo de recordatorios ({ $max })
+remind-min-interval = Los recordatorios recurrentes deben estar separados al menos { $min }.
+remind-title = Recordatorio
+
+## Timeouts
+
+timeout-too-long = Duración demasiado larga
+timeout-applied = Se aisló a { $member } durante { $duration }. (Caso #{ $case })
+timeout-not-timed-out = El miembro no está aislado.
+timeout-removed = Se quitó el aislamiento de { $member } (Caso #{ $case })
+
+## Locale
+
+locale-current = Tu idioma es **{ $locale }**, el idioma de este servidor es **{ $server }**.
+    Idiomas disponibles: { $available }
+locale-default = predeterminado
+locale-set = Tu idioma ahora es **{ $locale }**.
+locale-reset = Tu idioma ahora sigue al del servidor.
+locale-server-set = El idioma de este servidor ahora es **{ $locale }**.
+locale-server-reset = Se restableció el idioma del servidor.
+locale-unknown = Idioma `{ $locale }` desconocido, los idiomas disponibles son { $available }.
+
+## Pagination
+
+paginator-page = Página { $page }/{ $total }
+
+## Moderation
+
+case-not-found = Caso #{ $case } no encontrado.
+case-no-reason = Sin motivo
+case-no-target = No hay objetivo para la acción.
+case-no-duration = No hay duración para el baneo temporal.
+case-unknown-action = Acción de caso desconocida `{ $action }`
+case-action-ban = Baneo
+case-action-kick = Expulsión
+case-action-purge = Purga
+case-action-softban = Softban
+case-action-tempban = Baneo temporal
+case-action-timeout = Aislamiento
+case-action-unban = Desbaneo
+case-action-untimeout = Aislamiento retirado
+case-action-warn = Advertencia
+case-embed-title = Caso #{ $case } | { $action }
+case-embed-user = **Usuario:** <@{ $user }> ({ $user })
+case-embed-moderator = **Moderador:** <@{ $moderator }>
+case-embed-duration = **Duración:** { $duration }
+case-embed-reason = **Motivo:** { $reason }
+case-embed-date = **Fecha:** <t:{ $date }:f>
+case-embed-updated = **Actualizado:** <t:{ $date }:f>
+cases-none = { $user } no tiene casos.
+cases-header = ### { $user } tiene { $count } { $count ->
+        [one] caso
+       *[other] casos
+    }:
+ban-done = { $user } baneado. (Caso #{ $case })
+tempban-done = { $user } baneado durante { $duration }. (Caso #{ $case })
+tempban-expired = Baneo temporal expirado
+softban-done = { $user } recibió un softban y se { $count ->
+        [one] eliminó { $count } mensaje
+       *[other] eliminaron { $count } mensajes
+    }. (Caso #{ $case })
+kick-done = { $user } expulsado. (Caso #{ $case })
+reason-missing = No se indicó ningún motivo.
+reason-updated = Motivo del caso #{ $case } actualizado.
+unban-no-user = No se indicó ningún usuario.
+unban-not-found = No se encontró ningún usuario baneado.
+unban-multiple = Se encontraron varios usuarios baneados, usa su ID:
+unban-done = { $user } desbaneado. (Caso #{ $case })
+warn-done = { $member } advertido, ahora tiene { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    }. (Caso #{ $case })
+warn-escalated = Escalado: { $action }. (Caso #{ $case })
+warn-thresholds-none = No hay umbrales de advertencias configurados.
+warn-thresholds-header = ### Umbrales de advertencias:
+warn-threshold-entry = - { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    }: { $action }
+warn-threshold-entry-duration = - { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    }: { $action } durante { $duration }
+warn-invalid-count = Número de advertencias no válido.
+warn-threshold-removed = Umbral de { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    } eliminado.
+warn-threshold-missing = No hay umbral configurado para { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    }.
+warn-threshold-needs-duration = `{ $action }` requiere una duración.
+warn-threshold-invalid-action = La acción debe ser `timeout`, `kick`, `tempban`, `ban` o `none`.
+warn-threshold-set = Los miembros que lleguen a { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    } ahora recibirán: { $action }.
+warn-escalation-reason = Alcanzó { $count } { $count ->
+        [one] advertencia
+       *[other] advertencias
+    }
+
+## Purge
+
+purge-invalid-regex = Expresión regular no válida.
+purge-invalid-message-id = ID de mensaje no válido `{ $id }`.
+purge-unknown-filter = Filtro desconocido `{ $filter }`.
+purge-no-arguments = No se indicaron usuarios, límite ni filtros.
+purge-done = { $count ->
+        [one] Se eliminó { $count } mensaje
+       *[other] Se eliminaron { $count } mensajes
+    }.
+purge-age-limit = *Los mensajes con más de 7 días no se pueden eliminar en masa.*
+purge-reason = { $count ->
+        [one] Se eliminó { $count } mensaje
+       *[other] Se eliminaron { $count } mensajes
+    } en { $channel }
+
+## Slowmode
+
+slowmode-current = El modo lento en { $channel } está configurado a { $interval }.
+slowmode-current-disabled = El modo lento está desactivado en { $channel }.
+slowmode-disabled = Modo lento desactivado en { $channel }.
+slowmode-invalid-interval = El modo lento debe estar entre 1 segundo y { $max }.
+slowmode-set = Modo lento en { $channel } configurado a { $interval }, los mensajes enviados más rápido se eliminarán.
+
+## Lockdown
+
+lockdown-channel = { $channel } bloqueado.
+lockdown-channel-duration = { $channel } bloqueado durante { $duration }.
+lockdown-server = { $count } { $count ->
+        [one] canal bloqueado
+       *[other] canales bloqueados
+    }.
+lockdown-server-duration = { $count } { $count ->
+        [one] canal bloqueado
+       *[other] canales bloqueados
+    } durante { $duration }.
+unlock-not-locked = { $channel } no está bloqueado.
+unlock-channel = { $channel } desbloqueado.
+unlock-server = { $count } { $count ->
+        [one] canal desbloqueado
+       *[other] canales desbloqueados
+    }.
+
+## Logs
+
+modlog-current = El canal de registro de moderación es { $channel }.
+modlog-none = No hay canal de registro de moderación configurado.
+modlog-set = Canal de registro de moderación configurado a { $channel }.
+modlog-disabled = Registro de moderación desactivado.
+memberlog-current = El canal de registro de miembros es { $channel }.
+memberlog-none = No hay canal de registro de miembros configurado.
+memberlog-set = Canal de registro de miembros configurado a { $channel }, allí se registrarán entradas, salidas, expulsiones y baneos.
+memberlog-disabled = Registro de miembros desactivado.
+messagelog-current = El canal de registro de mensajes es { $channel }.
+messagelog-none = No hay canal de registro de mensajes configurado.
+messagelog-set = Canal de registro de mensajes configurado a { $channel }, allí se registrarán los mensajes eliminados y editados.
+messagelog-disabled = Registro de mensajes desactivado.
+memberlog-joined = Miembro unido: { $user }
+memberlog-left = Miembro salió: { $user }
+memberlog-kicked = Miembro expulsado: { $user }
+memberlog-banned = Miembro baneado: { $user }
+memberlog-account-age = Cuenta creada hace { $age }
+messagelog-deleted = **Mensaje de <@{ $author }> eliminado en <#{ $channel }>**
+messagelog-edited = **Mensaje de <@{ $author }> editado en <#{ $channel }>** [Ir al mensaje]({ $link })
+messagelog-attachments = **Archivos adjuntos:**
+messagelog-bulk-title = Eliminación masiva
+messagelog-bulk-deleted = **{ $count ->
+        [one] Se eliminó { $count } mensaje
+       *[other] Se eliminaron { $count } mensajes
+    } en <#{ $channel }>**
+messagelog-bulk-more = ...y { $count } más
+snipe-none = No hay nada que recuperar.
+snipe-title = Mensajes eliminados recientemente
+
+## Greetings
+
+greeting-current = Los mensajes de { $kind ->
+        [welcome] bienvenida
+       *[goodbye] despedida
+    } se envían a { $channel }:
+    ```
+    { $message }
+    ```
+greeting-none = Los mensajes de { $kind ->
+        [welcome] bienvenida
+       *[goodbye] despedida
+    } están desactivados.
+greeting-too-long = El mensaje puede tener como máximo { $max } caracteres.
+greeting-set = Los mensajes de { $kind ->
+        [welcome] bienvenida
+       *[goodbye] despedida
+    } se enviarán a { $channel }.
+greeting-disabled = Mensajes de { $kind ->
+        [welcome] bienvenida
+       *[goodbye] despedida
+    } desactivados.
+
+## Auto roles
+
+autorole-none = No hay roles automáticos configurados.
+autorole-current = Los miembros reciben { $roles } al unirse.
+autorole-exists = { $role } ya es un rol automático.
+autorole-max-roles = Los servidores pueden tener como máximo { $max } roles automáticos.
+autorole-added = Los nuevos miembros recibirán { $role }.
+autorole-missing = { $role } no es un rol automático.
+autorole-removed = Los nuevos miembros ya no recibirán { $role }.
+
+## Reaction roles
+
+reactionroles-invalid-emoji = `{ $emoji }` no es un emoji.
+reactionroles-none = No hay paneles de roles por reacción configurados.
+reactionroles-list-header = ### Paneles de roles por reacción:
+reactionroles-expected-pairs = Se esperaban pares de `<emoji> <role>`.
+reactionroles-max-roles = Los paneles pueden tener como máximo { $max } roles.
+reactionroles-duplicate-emoji = { $emoji } se usa más de una vez.
+reactionroles-panel-title = ### Reacciona para obtener un rol
+reactionroles-panel-title-unique = ### Reacciona para obtener un rol (elige uno)
+reactionroles-created = Panel de roles por reacción `{ $id }` creado en { $channel }.
+reactionroles-not-found = No hay ningún panel de roles por reacción con el ID `{ $id }`.
+reactionroles-deleted = Panel de roles por reacción eliminado.
+reactionroles-unknown-mode = Modo de roles por reacción desconocido `{ $mode }`, debe ser `unique` o `multi`.
+
+## Prefixes
+
+prefix-list = ### Prefijos:
+prefix-list-default = ### Prefijos predeterminados:
+prefix-invalid-length = El prefijo debe tener entre 1 y { $max } caracteres.
+prefix-exists = `{ $prefix }` ya es un prefijo.
+prefix-max-prefixes = Los servidores pueden tener como máximo { $max } prefijos.
+prefix-added = Prefijo `{ $prefix }` añadido.
+prefix-missing = `{ $prefix }` no es un prefijo.
+prefix-last = No se puede eliminar el último prefijo, mencionar al bot siempre funciona como prefijo.
+prefix-removed = Prefijo `{ $prefix }` eliminado.
+
+## Starboard
+
+starboard-current = El canal del starboard es { $channel }, con un mínimo de { $count } { $count ->
+        [one] estrella
+       *[other] estrellas
+    }.
+starboard-none = No hay canal de starboard configurado.
+starboard-channel-set = Canal del starboard configurado a { $channel }.
+starboard-invalid-limit = Límite no válido
+starboard-limit-set = Límite de estrellas del starboard configurado a { $count }.
+
+## Automod
+
+automod-rule-spam = { $threshold } mensajes repetidos en { $window }
+automod-rule-mentions = { $threshold } o más menciones
+automod-rule-caps = { $threshold }% o más letras mayúsculas
+automod-rule-emoji = { $threshold } o más emojis
+automod-rule-invites = invitaciones a otros servidores
+automod-rule-phrases = frases prohibidas
+automod-none = No hay reglas de automod activadas.
+automod-list-header = ### Reglas de automod:
+automod-list-entry = - `{ $rule }`: { $description } → { $actions }
+automod-list-entry-timeout = - `{ $rule }`: { $description } → { $actions } (aislamiento { $timeout })
+automod-exempt-list = Exentos: { $targets }
+automod-no-actions = No se indicaron acciones.
+automod-actions-set = `{ $rule }` ahora aplicará: { $actions }.
+automod-not-enabled = `{ $rule }` no está activada.
+automod-disabled = `{ $rule }` desactivada.
+automod-invalid-threshold = Umbral no válido.
+automod-enabled = `{ $rule }` activada, se actuará sobre los mensajes con { $description }.
+automod-exempted = { $target } ahora está exento del automod.
+automod-unexempted = { $target } ya no está exento del automod.
+automod-not-exempt = { $target } no está exento del automod.
+automod-phrases-none = No hay frases prohibidas.
+automod-phrases-header = Frases prohibidas:
+automod-phrase-missing = No se indicó ninguna frase.
+automod-phrase-added = Frase prohibida añadida.
+automod-phrase-removed = Frase prohibida eliminada.
+automod-phrase-not-banned = La frase no está prohibida.
+automod-reason = Automod: { $rule }
+automod-unknown-rule = Regla de automod desconocida `{ $rule }`, debe ser una de { $rules }.
+automod-unknown-action = Acción de automod desconocida `{ $action }`, debe ser `delete`, `warn` o `timeout`.
+
+## Command restrictions
+
+command-unknown = Comando desconocido `{ $command }`.
+command-protected = `{ $command }` no se puede restringir.
+command-policies-none = No hay restricciones de comandos configuradas.
+command-policies-header = ### Restricciones de comandos:
+command-policy-disabled = desactivado
+command-policy-channels = canales: { $channels }
+command-policy-allowed = permitidos: { $roles }
+command-policy-denied = denegados: { $roles }
+command-reset = Se eliminaron todas las restricciones de `{ $command }`.
+command-no-restrictions = `{ $command }` no tiene restricciones.
+command-disabled = `{ $command }` ahora está desactivado.
+command-enabled = `{ $command }` ahora está activado.
+command-unrestricted = `{ $command }` ahora se puede usar en cualquier canal.
+command-restricted = `{ $command }` ahora solo se puede usar en { $channels }.
+command-no-roles = No se indicaron roles.
+command-roles-allowed = { $roles } ahora pueden usar `{ $command }`.
+command-roles-denied = { $roles } ya no pueden usar `{ $command }`.
+policy-disabled = Este comando está desactivado en este servidor.
+policy-wrong-channel = Este comando solo se puede usar en { $channels }.
+policy-not-allowed = No tienes permiso para usar este comando.
+
+## Admin
+
+admin-summary =
+    ```
+    Servidores: { $servers }
+    Usuarios: { $users }
+    Palabras clave: { $keywords }
+    En la lista negra: { $blacklisted }
+    ```
+admin-reload-unchanged = Configuración recargada, no cambió nada.
+admin-reloaded =
+    Configuración recargada:
+    ```
+    { $changes }
+    ```
+admin-reload-failed = No se recargará la configuración: { $error }
+admin-flushed = { $count ->
+        [one] Se vació { $count } entrada
+       *[other] Se vaciaron { $count } entradas
+    } de la caché.
+admin-servers-header = ### Servidores ({ $count }), página { $page }/{ $total }:
+admin-not-in-server = No estoy en ese servidor.
+admin-left-server = Salí de { $server } (`{ $id }`).
+admin-status-set = Estado configurado a `{ $status }`.
+admin-status-reset = Estado restablecido a `{ $status }`.
+admin-unknown-server = Desconocido
+admin-none = Ninguno
+admin-default-prefixes = predeterminados
+admin-user-keywords = Palabras clave: { $count } en { $servers } { $servers ->
+        [one] servidor
+       *[other] servidores
+    }
+admin-user-summary =
+    Usuarios bloqueados: { $blocked }
+    Casos de moderación: { $cases }
+    En la lista negra: { $blacklisted ->
+        [yes] sí
+       *[no] no
+    }
+admin-server-summary =
+    Palabras clave: { $keywords } de { $users } { $users ->
+        [one] usuario
+       *[other] usuarios
+    }
+    Prefijos: { $prefixes }
+    Starboard: { $starboard } ({ $stars } { $stars ->
+        [one] estrella
+       *[other] estrellas
+    })
+    Registro de moderación: { $modlog }
+    Casos de moderación: { $cases }
+    Reglas de automod: { $automod }
+    Políticas de comandos: { $policies }
+    En la lista negra: { $blacklisted ->
+        [yes] sí
+       *[no] no
+    }
+blacklist-empty = La lista negra está vacía.
+blacklist-header = ### Lista negra:
+blacklist-no-reason = Sin motivo
+blacklist-unknown-target = `{ $id }` no es un usuario ni un servidor conocido.
+blacklist-added = { $kind ->
+        [server] Servidor
+       *[user] Usuario
+    } `{ $id }` añadido a la lista negra.
+blacklist-removed = `{ $id }` eliminado de la lista negra.
+blacklist-missing = `{ $id }` no está en la lista negra.
@@ -0,0 +1,189 @@
+create table if not exists highlights (
+    user_id text not null,
+    server_id text not null,
+    keyword text not null,
+
+    unique (user_id, server_id, keyword)
+);
+
+create index if not exists highlights_user_id_index on highlights (user_id, server_id);
+create index if not exists highlights_server_id_index on highlights (server_id);
+
+create table if not exists blocks (
+    user_id text not null,
+    blocked_user text not null,
+
+    unique (user_id, blocked_user)
+);
+
+create index if not exists blocked_user_id on blocks (user_id);
+
+create table if not exists server_configs (
+    server_id text primary key,
+    star_count integer not null,
+    starboard_channel text,
+    mod_log_channel text,
+    message_log_channel text,
+    prefixes text not null default '[]',
+    welcome_channel text,
+    welcome_message text,
+    goodbye_channel text,
+    goodbye_message text,
+    member_log_channel text,
+    auto_roles text not null default '[]',
+    locale text
+);
+
+create table if not exists starboard_messages (
+    starboard_message_id text not null,
+    message_id text not null,
+    user_id text not null,
+    channel_id text not null,
+    server_id text not null,
+    star_count integer not null,
+
+    unique (starboard_message_id),
+    unique (message_id)
+);
+
+create index if not exists starboard_messages_server_id_index on starboard_messages (server_id);
+create index if not exists starboard_messages_user_id_index on starboard_messages (user_id);
+
+create table if not exists mod_cases (
+    server_id text not null,
+    case_id integer not null,
+    action text not null,
+    target_id text,
+    moderator_id text not null,
+    reason text,
+    duration integer,
+    created_at integer not null default (unixepoch()),
+    updated_at integer,
+    log_message_id text,
+
+    primary key (server_id, case_id)
+);
+
+create index if not exists mod_cases_target_id_index on mod_cases (server_id, target_id);
+
+create table if not exists warn_thresholds (
+    server_id text not null,
+    warn_count integer not null,
+    action text not null,
+    duration integer,
+
+    primary key (server_id, warn_count)
+);
+
+create table if not exists scheduled_unbans (
+    server_id text not null,
+    user_id text not null,
+    unban_at integer not null,
+
+    primary key (server_id, user_id)
+);
+
+create index if not exists scheduled_unbans_unban_at_index on scheduled_unbans (unban_at);
+
+create table if not exists automod_rules (
+    server_id text not null,
+    rule text not null,
+    threshold integer,
+    actions text not null,
+    timeout_duration integer,
+
+    primary key (server_id, rule)
+);
+
+create table if not exists automod_exemptions (
+    server_id text not null,
+    target_id text not null,
+    kind text not null,
+
+    primary key (server_id, target_id)
+);
+
+create table if not exists automod_phrases (
+    server_id text not null,
+    phrase text not null,
+
+    primary key (server_id, phrase)
+);
+
+create table if not exists command_policies (
+    server_id text not null,
+    command text not null,
+    disabled boolean not null default false,
+    channels text not null default '[]',
+    allowed_roles text not null default '[]',
+    denied_roles text not null default '[]',
+
+    primary key (server_id, command)
+);
+
+create table if not exists blacklist (
+    target_id text primary key,
+    kind text not null,
+    reason text,
+    created_at integer not null default (unixepoch())
+);
+
+create table if not exists reminders (
+    id integer primary key autoincrement,
+    user_id text not null,
+    channel_id text not null,
+    message_id text,
+    content text not null,
+    due_at integer not null,
+    interval integer,
+    created_at integer not null default (unixepoch())
+);
+
+create index if not exists reminders_due_at_index on reminders (due_at);
+create index if not exists reminders_user_id_index on reminders (user_id);
+
+create table if not exists lockdowns (
+    channel_id text primary key,
+    server_id text not null,
+    previous_allow integer,
+    previous_deny integer,
+    expires_at integer
+);
+
+create index if not exists lockdowns_expires_at_index on lockdowns (expires_at);
+
+create table if not exists slowmodes (
+    channel_id text primary key,
+    server_id text not null,
+    interval integer not null
+);
+
+create table if not exists reaction_role_panels (
+    message_id text primary key,
+    server_id text not null,
+    channel_id text not null,
+    mode text not null default 'multi'
+);
+
+create index if not exists reaction_role_panels_server_id_index on reaction_role_panels (server_id);
+
+create table if not exists reaction_roles (
+    message_id text not null references reaction_role_panels(message_id) on delete cascade,
+    emoji text not null,
+    role_id text not null,
+
+    primary key (message_id, emoji)
+);
+
+create table if not exists user_settings (
+    user_id text primary key,
+    locale text
+);
+
+create table if not exists membership_hints (
+    server_id text not null,
+    user_id text not null,
+    updated_at integer not null default (unixepoch()),
+
+    primary key (server_id, user_id)
+);
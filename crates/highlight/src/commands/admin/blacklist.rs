@@ -0,0 +1,106 @@
+use stoat::{
+    commands::{ConsumeRest, is_owner},
+    ulid::Ulid,
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result};
+
+async fn blacklist(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let entries = ctx.state.fetch_blacklist().await?;
+
+    if entries.is_empty() {
+        ctx.send()
+            .content(locale.t("blacklist-empty", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut lines = vec![locale.t("blacklist-header", &[])];
+
+    for entry in entries {
+        lines.push(format!(
+            "- {} `{}` <t:{}:R>: {}",
+            entry.kind,
+            entry.target_id,
+            entry.created_at,
+            entry
+                .reason
+                .unwrap_or_else(|| locale.t("blacklist-no-reason", &[]))
+        ));
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn add(ctx: CmdCtx, id: Ulid, ConsumeRest(reason): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let id = id.to_string();
+
+    let kind = if ctx.cache.get_server(&id).is_some() {
+        "server"
+    } else if ctx.http.fetch_user(&id).await.is_ok() {
+        "user"
+    } else {
+        return Err(Error::UserError(
+            locale.t("blacklist-unknown-target", &[("id", id.into())]),
+        ));
+    };
+
+    ctx.state
+        .add_to_blacklist(&id, kind, (!reason.is_empty()).then_some(reason.as_str()))
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "blacklist-added",
+            &[("kind", kind.into()), ("id", id.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn remove(ctx: CmdCtx, id: Ulid) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let id = id.to_string();
+
+    let removed = ctx.state.remove_from_blacklist(&id).await?;
+
+    ctx.send()
+        .content(locale.t(
+            if removed {
+                "blacklist-removed"
+            } else {
+                "blacklist-missing"
+            },
+            &[("id", id.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("blacklist", blacklist)
+        .description("Manage users and servers blocked from using the bot.")
+        .check(is_owner)
+        .child(
+            Command::new("add", add)
+                .description("Blacklists a user or server.")
+                .signature("<id> [reason]")
+                .check(is_owner),
+        )
+        .child(
+            Command::new("remove", remove)
+                .description("Removes a user or server from the blacklist.")
+                .signature("<id>")
+                .check(is_owner),
+        )
+}
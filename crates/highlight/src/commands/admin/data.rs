@@ -0,0 +1,129 @@
+use stoat::{Identifiable, commands::is_owner, either::Either, types::User, ulid::Ulid};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result};
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+async fn user(ctx: CmdCtx, user: Either<User, Ulid>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let user_id = user.id().to_string();
+
+    let keywords = ctx.state.fetch_keyword_counts_for_user(&user_id).await?;
+    let blocked = ctx.state.fetch_blocked_users(user_id.clone()).await?;
+    let cases = ctx.state.count_mod_cases_for_target(&user_id).await?;
+
+    let mut lines = vec![format!("### <@{user_id}> (`{user_id}`)")];
+
+    lines.push(locale.t(
+        "admin-user-keywords",
+        &[
+            (
+                "count",
+                keywords.iter().map(|(_, count)| count).sum::<i64>().into(),
+            ),
+            ("servers", keywords.len().into()),
+        ],
+    ));
+
+    for (server_id, count) in &keywords {
+        let name = ctx
+            .cache
+            .get_server(server_id)
+            .map(|server| server.name)
+            .unwrap_or_else(|| locale.t("admin-unknown-server", &[]));
+
+        lines.push(format!("- {name} (`{server_id}`): {count}"));
+    }
+
+    lines.push(locale.t(
+        "admin-user-summary",
+        &[
+            ("blocked", blocked.len().into()),
+            ("cases", cases.into()),
+            (
+                "blacklisted",
+                yes_no(ctx.state.is_blacklisted(&user_id).await).into(),
+            ),
+        ],
+    ));
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn server(ctx: CmdCtx, server_id: Ulid) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server_id = server_id.to_string();
+
+    let name = ctx
+        .cache
+        .get_server(&server_id)
+        .map(|server| server.name)
+        .unwrap_or_else(|| locale.t("admin-unknown-server", &[]));
+
+    let config = ctx.state.fetch_server_config(&server_id).await?;
+    let prefixes = ctx.state.get_server_prefixes(&server_id).await?;
+    let (keywords, users) = ctx.state.count_keywords_for_server(&server_id).await?;
+    let automod = ctx.state.get_automod_config(&server_id).await?;
+    let policies = ctx.state.get_command_policies(&server_id).await?;
+    let cases = ctx.state.count_mod_cases_for_server(&server_id).await?;
+
+    let channel = |channel: Option<String>| {
+        channel
+            .map(|channel| format!("<#{channel}>"))
+            .unwrap_or_else(|| locale.t("admin-none", &[]))
+    };
+
+    let prefixes = if prefixes.is_empty() {
+        locale.t("admin-default-prefixes", &[])
+    } else {
+        prefixes
+            .iter()
+            .map(|prefix| format!("`{prefix}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    let lines = [
+        format!("### {name} (`{server_id}`)"),
+        locale.t(
+            "admin-server-summary",
+            &[
+                ("keywords", keywords.into()),
+                ("users", users.into()),
+                ("prefixes", prefixes.into()),
+                ("starboard", channel(config.starboard_channel).into()),
+                ("stars", config.star_count.into()),
+                ("modlog", channel(config.mod_log_channel).into()),
+                ("cases", cases.into()),
+                ("automod", automod.rules.len().into()),
+                ("policies", policies.len().into()),
+                (
+                    "blacklisted",
+                    yes_no(ctx.state.is_blacklisted(&server_id).await).into(),
+                ),
+            ],
+        ),
+    ];
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+pub fn user_command() -> Command {
+    Command::new("user", user)
+        .description("Shows the data stored for a user.")
+        .signature("<user>")
+        .check(is_owner)
+}
+
+pub fn server_command() -> Command {
+    Command::new("server", server)
+        .description("Shows the data stored for a server.")
+        .signature("<server id>")
+        .check(is_owner)
+}
@@ -0,0 +1,78 @@
+use stoat::commands::is_owner;
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result};
+
+mod blacklist;
+mod data;
+mod servers;
+mod status;
+
+async fn admin(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let keywords = ctx.state.get_total_keyword_count().await?;
+    let blacklisted = ctx.state.blacklist.read().await.len();
+
+    ctx.send()
+        .content(locale.t(
+            "admin-summary",
+            &[
+                ("servers", ctx.cache.servers.len().into()),
+                ("users", ctx.cache.users.len().into()),
+                ("keywords", keywords.into()),
+                ("blacklisted", blacklisted.into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn reload(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let content = match ctx.state.reload_config() {
+        Ok(changes) if changes.is_empty() => locale.t("admin-reload-unchanged", &[]),
+        Ok(changes) => locale.t("admin-reloaded", &[("changes", changes.join("\n").into())]),
+        Err(e) => locale.t("admin-reload-failed", &[("error", e.to_string().into())]),
+    };
+
+    ctx.send().content(content).build().await?;
+
+    Ok(())
+}
+
+async fn flush(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let flushed = ctx.state.flush_caches().await;
+
+    ctx.send()
+        .content(locale.t("admin-flushed", &[("count", flushed.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("admin", admin)
+        .description("Bot owner tools.")
+        .hidden()
+        .check(is_owner)
+        .child(servers::command())
+        .child(servers::leave_command())
+        .child(data::user_command())
+        .child(data::server_command())
+        .child(blacklist::command())
+        .child(status::command())
+        .child(
+            Command::new("reload", reload)
+                .description("Reloads the config file.")
+                .check(is_owner),
+        )
+        .child(
+            Command::new("flush", flush)
+                .description("Clears all in-memory caches.")
+                .check(is_owner),
+        )
+}
@@ -0,0 +1,79 @@
+use stoat::{commands::is_owner, types::OptionsServerDelete, ulid::Ulid};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result};
+
+const PAGE_SIZE: usize = 20;
+
+async fn servers(ctx: CmdCtx, page: Option<u32>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let mut servers = Vec::new();
+
+    ctx.cache.servers.iter_sync(|_, server| {
+        servers.push((server.name.clone(), server.id.clone()));
+        true
+    });
+
+    servers.sort();
+
+    let pages = servers.len().div_ceil(PAGE_SIZE).max(1);
+    let page = (page.unwrap_or(1) as usize).clamp(1, pages);
+
+    let mut lines = vec![locale.t(
+        "admin-servers-header",
+        &[
+            ("count", servers.len().into()),
+            ("page", page.into()),
+            ("total", pages.into()),
+        ],
+    )];
+
+    for (name, id) in servers.iter().skip((page - 1) * PAGE_SIZE).take(PAGE_SIZE) {
+        lines.push(format!("- {name} (`{id}`)"));
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn leave(ctx: CmdCtx, server_id: Ulid) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server_id = server_id.to_string();
+
+    let Some(server) = ctx.cache.get_server(&server_id) else {
+        return Err(Error::UserError(locale.t("admin-not-in-server", &[])));
+    };
+
+    ctx.http
+        .delete_server(
+            &server_id,
+            &OptionsServerDelete {
+                leave_silently: Some(true),
+            },
+        )
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "admin-left-server",
+            &[("server", server.name.into()), ("id", server_id.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("servers", servers)
+        .description("Lists the servers the bot is in.")
+        .signature("[page]")
+        .check(is_owner)
+}
+
+pub fn leave_command() -> Command {
+    Command::new("leave", leave)
+        .description("Leaves a server.")
+        .signature("<server id>")
+        .check(is_owner)
+}
@@ -0,0 +1,74 @@
+use stoat::{
+    commands::{ConsumeRest, is_owner},
+    types::{DataEditUser, UserStatus},
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result};
+
+async fn update_status(ctx: &CmdCtx) -> Result<String> {
+    let text = ctx.state.status_text().await;
+
+    ctx.http
+        .edit_user(
+            "@me",
+            &DataEditUser {
+                status: Some(UserStatus {
+                    text: Some(text.clone()),
+                    presence: None,
+                }),
+                display_name: None,
+                avatar: None,
+                profile: None,
+                badges: None,
+                flags: None,
+                remove: Vec::new(),
+            },
+        )
+        .await?;
+
+    Ok(text)
+}
+
+async fn status(ctx: CmdCtx, ConsumeRest(text): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if !text.is_empty() {
+        *ctx.state.status_override.write().await = Some(text);
+    };
+
+    let text = update_status(&ctx).await?;
+
+    ctx.send()
+        .content(locale.t("admin-status-set", &[("status", text.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn reset(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    *ctx.state.status_override.write().await = None;
+
+    let text = update_status(&ctx).await?;
+
+    ctx.send()
+        .content(locale.t("admin-status-reset", &[("status", text.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("status", status)
+        .description("Sets the bot's status, e.g. to announce maintenance.")
+        .signature("<text>")
+        .check(is_owner)
+        .child(
+            Command::new("reset", reset)
+                .description("Resets the bot's status to the default.")
+                .check(is_owner),
+        )
+}
@@ -0,0 +1,75 @@
+use stoat::{
+    commands::{Command, ConsumeRest, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{AutomodAction, AutomodRuleKind, CmdCtx, ContextLocaleExt, Error, Result, State};
+
+async fn actions(ctx: CmdCtx, rule: String, ConsumeRest(input): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let rule = AutomodRuleKind::parse(&locale, &rule.to_lowercase())?;
+
+    let mut actions = Vec::new();
+    let mut timeout_duration = None;
+
+    for word in input.split_ascii_whitespace() {
+        let (action, value) = word.split_once(':').unwrap_or((word, ""));
+        let action = AutomodAction::parse(&locale, &action.to_lowercase())?;
+
+        if action == AutomodAction::Timeout && !value.is_empty() {
+            timeout_duration = Some(
+                humantime::parse_duration(value).map_err(|e| Error::UserError(e.to_string()))?,
+            );
+        };
+
+        if !actions.contains(&action) {
+            actions.push(action);
+        };
+    }
+
+    if actions.is_empty() {
+        return Err(Error::UserError(locale.t("automod-no-actions", &[])));
+    };
+
+    let server = ctx.get_current_server()?;
+
+    let updated = ctx
+        .state
+        .set_automod_actions(&server.id, rule, &actions, timeout_duration)
+        .await?;
+
+    ctx.send()
+        .content(if updated {
+            locale.t(
+                "automod-actions-set",
+                &[
+                    ("rule", rule.as_str().into()),
+                    (
+                        "actions",
+                        actions
+                            .iter()
+                            .map(|action| action.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                            .into(),
+                    ),
+                ],
+            )
+        } else {
+            locale.t("automod-not-enabled", &[("rule", rule.as_str().into())])
+        })
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command<Error, State> {
+    Command::new("actions", actions)
+        .description("Sets the actions taken when a rule is broken.\n\nActions: `delete`, `warn`, `timeout[:duration]`")
+        .signature("<rule> <actions...>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
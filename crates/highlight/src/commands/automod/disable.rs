@@ -0,0 +1,38 @@
+use stoat::{
+    commands::{Command, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{AutomodRuleKind, CmdCtx, ContextLocaleExt, Error, Result, State};
+
+async fn disable(ctx: CmdCtx, rule: String) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let rule = AutomodRuleKind::parse(&locale, &rule.to_lowercase())?;
+    let server = ctx.get_current_server()?;
+
+    let removed = ctx.state.disable_automod_rule(&server.id, rule).await?;
+
+    ctx.send()
+        .content(locale.t(
+            if removed {
+                "automod-disabled"
+            } else {
+                "automod-not-enabled"
+            },
+            &[("rule", rule.as_str().into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command<Error, State> {
+    Command::new("disable", disable)
+        .description("Disables an automod rule.")
+        .signature("<rule>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
@@ -0,0 +1,45 @@
+use stoat::{
+    commands::{Command, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{AutomodRuleKind, CmdCtx, ContextLocaleExt, Error, Result, State};
+
+async fn enable(ctx: CmdCtx, rule: String, threshold: Option<u32>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let rule = AutomodRuleKind::parse(&locale, &rule.to_lowercase())?;
+
+    if threshold == Some(0) {
+        return Err(Error::UserError(locale.t("automod-invalid-threshold", &[])));
+    };
+
+    let server = ctx.get_current_server()?;
+    let threshold = threshold.map(|threshold| threshold as i32);
+
+    ctx.state
+        .enable_automod_rule(&server.id, rule, threshold)
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "automod-enabled",
+            &[
+                ("rule", rule.as_str().into()),
+                ("description", rule.describe(&locale, threshold).into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command<Error, State> {
+    Command::new("enable", enable)
+        .description("Enables an automod rule.\n\nRules: `spam`, `mentions`, `caps`, `emoji`, `invites`, `phrases`")
+        .signature("<rule> [threshold]")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
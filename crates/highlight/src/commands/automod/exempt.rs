@@ -0,0 +1,78 @@
+use stoat::{
+    ChannelExt,
+    commands::{Command, HasServerPermissions, server_only},
+    either::Either,
+    types::{Channel, ChannelPermission, Role},
+};
+
+use crate::{CmdCtx, ContextLocaleExt, Error, Result, State};
+
+async fn exempt(ctx: CmdCtx, target: Either<Channel, Role>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let (target_id, kind, mention) = match &target {
+        Either::Left(channel) => (channel.id().to_string(), "channel", channel.mention()),
+        Either::Right(role) => (role.id.clone(), "role", format!("<%{}>", role.id)),
+    };
+
+    ctx.state
+        .add_automod_exemption(&server.id, &target_id, kind)
+        .await?;
+
+    ctx.send()
+        .content(locale.t("automod-exempted", &[("target", mention.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn unexempt(ctx: CmdCtx, target: Either<Channel, Role>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let (target_id, mention) = match &target {
+        Either::Left(channel) => (channel.id().to_string(), channel.mention()),
+        Either::Right(role) => (role.id.clone(), format!("<%{}>", role.id)),
+    };
+
+    let removed = ctx
+        .state
+        .remove_automod_exemption(&server.id, &target_id)
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            if removed {
+                "automod-unexempted"
+            } else {
+                "automod-not-exempt"
+            },
+            &[("target", mention.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command<Error, State> {
+    Command::new("exempt", exempt)
+        .description("Exempts a channel or role from automod.")
+        .signature("<channel|role>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
+
+pub fn remove_command() -> Command<Error, State> {
+    Command::new("unexempt", unexempt)
+        .description("Removes an automod exemption.")
+        .signature("<channel|role>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use stoat::{
+    commands::{Command, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{CmdCtx, ContextLocaleExt, Error, Result, State};
+
+mod actions;
+mod disable;
+mod enable;
+mod exempt;
+mod phrase;
+
+async fn automod(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let config = ctx.state.get_automod_config(&server.id).await?;
+
+    if config.rules.is_empty() {
+        ctx.send()
+            .content(locale.t("automod-none", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut lines = vec![locale.t("automod-list-header", &[])];
+
+    for rule in &config.rules {
+        let actions = rule
+            .actions
+            .0
+            .iter()
+            .map(|action| action.as_str())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut args = vec![
+            ("rule", rule.rule.as_str().into()),
+            (
+                "description",
+                rule.rule.describe(&locale, rule.threshold).into(),
+            ),
+            ("actions", actions.into()),
+        ];
+
+        let key = match rule.timeout_duration {
+            Some(duration) => {
+                args.push((
+                    "timeout",
+                    locale
+                        .format_duration(Duration::from_secs(duration as u64))
+                        .into(),
+                ));
+
+                "automod-list-entry-timeout"
+            }
+            None => "automod-list-entry",
+        };
+
+        lines.push(locale.t(key, &args));
+    }
+
+    let exemptions = ctx.state.fetch_automod_exemptions(&server.id).await?;
+
+    if !exemptions.is_empty() {
+        lines.push(String::new());
+        lines.push(
+            locale.t(
+                "automod-exempt-list",
+                &[(
+                    "targets",
+                    exemptions
+                        .iter()
+                        .map(|exemption| match exemption.kind.as_str() {
+                            "role" => format!("<%{}>", exemption.target_id),
+                            _ => format!("<#{}>", exemption.target_id),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                        .into(),
+                )],
+            ),
+        );
+    };
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command<Error, State> {
+    Command::new("automod", automod)
+        .description("Manage automatic moderation rules.")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+        .child(enable::command())
+        .child(disable::command())
+        .child(actions::command())
+        .child(exempt::command())
+        .child(exempt::remove_command())
+        .child(phrase::command())
+}
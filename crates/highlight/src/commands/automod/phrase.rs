@@ -0,0 +1,110 @@
+use stoat::{
+    commands::{Command, ConsumeRest, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{CmdCtx, ContextLocaleExt, Error, Result, State};
+
+async fn phrase(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let config = ctx.state.get_automod_config(&server.id).await?;
+
+    ctx.send()
+        .content(if config.phrases.is_empty() {
+            locale.t("automod-phrases-none", &[])
+        } else {
+            [locale.t("automod-phrases-header", &[])]
+                .into_iter()
+                .chain(
+                    config
+                        .phrases
+                        .iter()
+                        .map(|phrase| format!("- ||{phrase}||")),
+                )
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn add(ctx: CmdCtx, ConsumeRest(phrase): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if phrase.is_empty() {
+        return Err(Error::UserError(locale.t("automod-phrase-missing", &[])));
+    };
+
+    let server = ctx.get_current_server()?;
+
+    ctx.state
+        .add_automod_phrase(&server.id, &phrase.to_lowercase())
+        .await?;
+
+    ctx.http
+        .delete_message(&ctx.message.channel, &ctx.message.id)
+        .await?;
+
+    ctx.send()
+        .content(locale.t("automod-phrase-added", &[]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn remove(ctx: CmdCtx, ConsumeRest(phrase): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let removed = ctx
+        .state
+        .remove_automod_phrase(&server.id, &phrase.to_lowercase())
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            if removed {
+                "automod-phrase-removed"
+            } else {
+                "automod-phrase-not-banned"
+            },
+            &[],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command<Error, State> {
+    Command::new("phrase", phrase)
+        .alias("phrases")
+        .description("Manage banned phrases for the `phrases` rule.")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+        .child(
+            Command::new("add", add)
+                .description("Adds a banned phrase.")
+                .signature("<phrase...>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+        .child(
+            Command::new("remove", remove)
+                .description("Removes a banned phrase.")
+                .signature("<phrase...>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+}
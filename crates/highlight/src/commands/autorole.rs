@@ -0,0 +1,133 @@
+use stoat::{
+    commands::{HasServerPermissions, server_only},
+    types::{ChannelPermission, Role},
+};
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, Error, MAX_AUTO_ROLES, Result, check_role_hierarchy,
+};
+
+async fn autorole(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let config = ctx.state.fetch_server_config(&server.id).await?;
+
+    ctx.send()
+        .content(if config.auto_roles.is_empty() {
+            locale.t("autorole-none", &[])
+        } else {
+            locale.t(
+                "autorole-current",
+                &[(
+                    "roles",
+                    config
+                        .auto_roles
+                        .iter()
+                        .map(|role| format!("<%{role}>"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                        .into(),
+                )],
+            )
+        })
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn add(ctx: CmdCtx, role: Role) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    check_role_hierarchy(&ctx, &role).await?;
+
+    let mut auto_roles = ctx.state.fetch_server_config(&server.id).await?.auto_roles;
+
+    if auto_roles.contains(&role.id) {
+        return Err(Error::UserError(locale.t(
+            "autorole-exists",
+            &[("role", format!("<%{}>", role.id).into())],
+        )));
+    };
+
+    if auto_roles.len() >= MAX_AUTO_ROLES {
+        return Err(Error::UserError(
+            locale.t("autorole-max-roles", &[("max", MAX_AUTO_ROLES.into())]),
+        ));
+    };
+
+    auto_roles.push(role.id.clone());
+
+    ctx.state
+        .update_server_config_auto_roles(&server.id, &auto_roles)
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "autorole-added",
+            &[("role", format!("<%{}>", role.id).into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn remove(ctx: CmdCtx, role: Role) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let mut auto_roles = ctx.state.fetch_server_config(&server.id).await?.auto_roles;
+
+    if !auto_roles.contains(&role.id) {
+        return Err(Error::UserError(locale.t(
+            "autorole-missing",
+            &[("role", format!("<%{}>", role.id).into())],
+        )));
+    };
+
+    auto_roles.retain(|id| id != &role.id);
+
+    ctx.state
+        .update_server_config_auto_roles(&server.id, &auto_roles)
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "autorole-removed",
+            &[("role", format!("<%{}>", role.id).into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("autorole", autorole)
+        .description("Manage roles given to members when they join.")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageRole,
+        ]))
+        .child(
+            Command::new("add", add)
+                .description("Adds a role given to new members.")
+                .signature("<role>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageRole,
+                ])),
+        )
+        .child(
+            Command::new("remove", remove)
+                .description("Stops giving a role to new members.")
+                .signature("<role>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageRole,
+                ])),
+        )
+}
@@ -0,0 +1,61 @@
+use stoat::{
+    commands::{HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result};
+
+use super::{fetch_policy, resolve_command};
+
+async fn set_disabled(ctx: CmdCtx, name: String, disabled: bool) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let command = resolve_command(&ctx, &locale, &name)?;
+    let server = ctx.get_current_server()?;
+
+    let mut policy = fetch_policy(&ctx, &server.id, command).await?;
+    policy.disabled = disabled;
+
+    ctx.state.save_command_policy(&server.id, &policy).await?;
+
+    ctx.send()
+        .content(locale.t(
+            if disabled {
+                "command-disabled"
+            } else {
+                "command-enabled"
+            },
+            &[("command", policy.command.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn disable(ctx: CmdCtx, name: String) -> Result<()> {
+    set_disabled(ctx, name, true).await
+}
+
+async fn enable(ctx: CmdCtx, name: String) -> Result<()> {
+    set_disabled(ctx, name, false).await
+}
+
+pub fn command() -> Command {
+    Command::new("disable", disable)
+        .description("Disables a command in this server.")
+        .signature("<command>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
+
+pub fn enable_command() -> Command {
+    Command::new("enable", enable)
+        .description("Re-enables a disabled command.")
+        .signature("<command>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
@@ -0,0 +1,155 @@
+use stoat::{
+    commands::{HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{
+    CmdCtx, Command, CommandPolicy, ContextLocaleExt, Error, Locale, PROTECTED_COMMANDS, Result,
+};
+
+mod disable;
+mod restrict;
+mod roles;
+
+fn resolve_command(ctx: &CmdCtx, locale: &Locale, name: &str) -> Result<String> {
+    let Some(command) = ctx.commands.get_command(&name.to_lowercase()) else {
+        return Err(Error::UserError(
+            locale.t("command-unknown", &[("command", name.into())]),
+        ));
+    };
+
+    if PROTECTED_COMMANDS.contains(&command.name.as_str()) {
+        return Err(Error::UserError(
+            locale.t("command-protected", &[("command", command.name.into())]),
+        ));
+    };
+
+    Ok(command.name)
+}
+
+async fn fetch_policy(ctx: &CmdCtx, server_id: &str, command: String) -> Result<CommandPolicy> {
+    let policies = ctx.state.get_command_policies(server_id).await?;
+
+    Ok(policies.get(&command).cloned().unwrap_or(CommandPolicy {
+        command,
+        ..Default::default()
+    }))
+}
+
+fn mentions(ids: &[String], format: fn(&String) -> String) -> String {
+    ids.iter().map(format).collect::<Vec<_>>().join(", ")
+}
+
+async fn list(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let policies = ctx.state.get_command_policies(&server.id).await?;
+
+    if policies.is_empty() {
+        ctx.send()
+            .content(locale.t("command-policies-none", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut policies = policies.values().collect::<Vec<_>>();
+    policies.sort_by(|a, b| a.command.cmp(&b.command));
+
+    let mut lines = vec![locale.t("command-policies-header", &[])];
+
+    for policy in policies {
+        let mut details = Vec::new();
+
+        if policy.disabled {
+            details.push(locale.t("command-policy-disabled", &[]));
+        };
+
+        if !policy.channels.is_empty() {
+            details.push(locale.t(
+                "command-policy-channels",
+                &[(
+                    "channels",
+                    mentions(&policy.channels, |id| format!("<#{id}>")).into(),
+                )],
+            ));
+        };
+
+        if !policy.allowed_roles.is_empty() {
+            details.push(locale.t(
+                "command-policy-allowed",
+                &[(
+                    "roles",
+                    mentions(&policy.allowed_roles, |id| format!("<%{id}>")).into(),
+                )],
+            ));
+        };
+
+        if !policy.denied_roles.is_empty() {
+            details.push(locale.t(
+                "command-policy-denied",
+                &[(
+                    "roles",
+                    mentions(&policy.denied_roles, |id| format!("<%{id}>")).into(),
+                )],
+            ));
+        };
+
+        lines.push(format!("- `{}`: {}", policy.command, details.join("; ")));
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn reset(ctx: CmdCtx, name: String) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let command = resolve_command(&ctx, &locale, &name)?;
+    let server = ctx.get_current_server()?;
+
+    let removed = ctx
+        .state
+        .remove_command_policy(&server.id, &command)
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            if removed {
+                "command-reset"
+            } else {
+                "command-no-restrictions"
+            },
+            &[("command", command.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("command", list)
+        .alias("commands")
+        .description("Manage which commands can be used in this server.")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+        .child(disable::command())
+        .child(disable::enable_command())
+        .child(restrict::command())
+        .child(roles::allow_command())
+        .child(roles::deny_command())
+        .child(
+            Command::new("reset", reset)
+                .description("Removes all restrictions from a command.")
+                .signature("<command>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+}
@@ -0,0 +1,72 @@
+use stoat::{
+    ChannelExt,
+    commands::{Greedy, HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission},
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result};
+
+use super::{fetch_policy, resolve_command};
+
+async fn restrict(ctx: CmdCtx, name: String, Greedy(channels): Greedy<Channel>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let command = resolve_command(&ctx, &locale, &name)?;
+    let server = ctx.get_current_server()?;
+
+    if channels.iter().any(|channel| match channel {
+        Channel::TextChannel {
+            server: server_id, ..
+        } => server_id != &server.id,
+        _ => true,
+    }) {
+        return Err(Error::UserError(locale.t("error-invalid-channel", &[])));
+    };
+
+    let mut policy = fetch_policy(&ctx, &server.id, command).await?;
+    policy.channels = channels
+        .iter()
+        .map(|channel| channel.id().to_string())
+        .collect();
+
+    ctx.state.save_command_policy(&server.id, &policy).await?;
+
+    ctx.send()
+        .content(if channels.is_empty() {
+            locale.t(
+                "command-unrestricted",
+                &[("command", policy.command.into())],
+            )
+        } else {
+            locale.t(
+                "command-restricted",
+                &[
+                    ("command", policy.command.into()),
+                    (
+                        "channels",
+                        channels
+                            .iter()
+                            .map(|channel| channel.mention())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                            .into(),
+                    ),
+                ],
+            )
+        })
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("restrict", restrict)
+        .description(
+            "Restricts a command to the given channels, or lifts the restriction if none are given.",
+        )
+        .signature("<command> [channels...]")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
@@ -0,0 +1,91 @@
+use stoat::{
+    commands::{Greedy, HasServerPermissions, server_only},
+    types::{ChannelPermission, Role},
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result};
+
+use super::{fetch_policy, resolve_command};
+
+async fn update_roles(ctx: CmdCtx, name: String, roles: Vec<Role>, allow: bool) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let command = resolve_command(&ctx, &locale, &name)?;
+    let server = ctx.get_current_server()?;
+
+    if roles.is_empty() {
+        return Err(Error::UserError(locale.t("command-no-roles", &[])));
+    };
+
+    let mut policy = fetch_policy(&ctx, &server.id, command).await?;
+
+    let (add_to, remove_from) = if allow {
+        (&mut policy.allowed_roles, &mut policy.denied_roles)
+    } else {
+        (&mut policy.denied_roles, &mut policy.allowed_roles)
+    };
+
+    for role in &roles {
+        remove_from.retain(|id| id != &role.id);
+
+        if !add_to.contains(&role.id) {
+            add_to.push(role.id.clone());
+        };
+    }
+
+    ctx.state.save_command_policy(&server.id, &policy).await?;
+
+    ctx.send()
+        .content(
+            locale.t(
+                if allow {
+                    "command-roles-allowed"
+                } else {
+                    "command-roles-denied"
+                },
+                &[
+                    (
+                        "roles",
+                        roles
+                            .iter()
+                            .map(|role| format!("<%{}>", role.id))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                            .into(),
+                    ),
+                    ("command", policy.command.into()),
+                ],
+            ),
+        )
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn allow(ctx: CmdCtx, name: String, Greedy(roles): Greedy<Role>) -> Result<()> {
+    update_roles(ctx, name, roles, true).await
+}
+
+async fn deny(ctx: CmdCtx, name: String, Greedy(roles): Greedy<Role>) -> Result<()> {
+    update_roles(ctx, name, roles, false).await
+}
+
+pub fn allow_command() -> Command {
+    Command::new("allow", allow)
+        .description("Limits a command to members with one of the given roles.")
+        .signature("<command> <roles...>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
+
+pub fn deny_command() -> Command {
+    Command::new("deny", deny)
+        .description("Prevents members with any of the given roles from using a command.")
+        .signature("<command> <roles...>")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+}
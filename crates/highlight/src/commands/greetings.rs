@@ -0,0 +1,246 @@
+use stoat::{
+    ChannelExt,
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission},
+};
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, DEFAULT_GOODBYE_MESSAGE, DEFAULT_WELCOME_MESSAGE, Error,
+    Result, render_greeting,
+};
+
+const MAX_GREETING_LENGTH: usize = 1500;
+
+#[derive(Clone, Copy)]
+enum Greeting {
+    Welcome,
+    Goodbye,
+}
+
+impl Greeting {
+    fn kind(&self) -> &'static str {
+        match self {
+            Greeting::Welcome => "welcome",
+            Greeting::Goodbye => "goodbye",
+        }
+    }
+
+    fn default_message(&self) -> &'static str {
+        match self {
+            Greeting::Welcome => DEFAULT_WELCOME_MESSAGE,
+            Greeting::Goodbye => DEFAULT_GOODBYE_MESSAGE,
+        }
+    }
+
+    async fn fetch(&self, ctx: &CmdCtx) -> Result<(Option<String>, String)> {
+        let server = ctx.get_current_server()?;
+        let config = ctx.state.fetch_server_config(&server.id).await?;
+
+        let (channel, message) = match self {
+            Greeting::Welcome => (config.welcome_channel, config.welcome_message),
+            Greeting::Goodbye => (config.goodbye_channel, config.goodbye_message),
+        };
+
+        Ok((
+            channel,
+            message.unwrap_or_else(|| self.default_message().to_string()),
+        ))
+    }
+
+    async fn update(
+        &self,
+        ctx: &CmdCtx,
+        channel: Option<&str>,
+        message: Option<&str>,
+    ) -> Result<()> {
+        let server = ctx.get_current_server()?;
+
+        match self {
+            Greeting::Welcome => {
+                ctx.state
+                    .update_server_config_welcome(&server.id, channel, message)
+                    .await
+            }
+            Greeting::Goodbye => {
+                ctx.state
+                    .update_server_config_goodbye(&server.id, channel, message)
+                    .await
+            }
+        }
+    }
+}
+
+async fn show(ctx: &CmdCtx, greeting: Greeting) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let (channel, message) = greeting.fetch(ctx).await?;
+
+    ctx.send()
+        .content(match channel {
+            Some(channel) => locale.t(
+                "greeting-current",
+                &[
+                    ("kind", greeting.kind().into()),
+                    ("channel", format!("<#{channel}>").into()),
+                    ("message", message.into()),
+                ],
+            ),
+            None => locale.t("greeting-none", &[("kind", greeting.kind().into())]),
+        })
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn set(ctx: &CmdCtx, greeting: Greeting, channel: Channel, message: String) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    if channel.server() != Some(server.id.as_str()) {
+        return Err(Error::UserError(
+            locale.t("error-channel-not-in-server", &[]),
+        ));
+    };
+
+    if message.chars().count() > MAX_GREETING_LENGTH {
+        return Err(Error::UserError(
+            locale.t("greeting-too-long", &[("max", MAX_GREETING_LENGTH.into())]),
+        ));
+    };
+
+    let message = (!message.is_empty()).then_some(message);
+
+    greeting
+        .update(ctx, Some(channel.id()), message.as_deref())
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "greeting-set",
+            &[
+                ("kind", greeting.kind().into()),
+                ("channel", channel.mention().into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn disable(ctx: &CmdCtx, greeting: Greeting) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    greeting.update(ctx, None, None).await?;
+
+    ctx.send()
+        .content(locale.t("greeting-disabled", &[("kind", greeting.kind().into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn test(ctx: &CmdCtx, greeting: Greeting) -> Result<()> {
+    let (_, message) = greeting.fetch(ctx).await?;
+    let server = ctx.get_current_server()?;
+    let user = ctx.get_user().await?;
+
+    let content = render_greeting(ctx, &ctx.state, &message, &server.id, &user).await;
+
+    ctx.send().content(content).build().await?;
+
+    Ok(())
+}
+
+async fn welcome(ctx: CmdCtx) -> Result<()> {
+    show(&ctx, Greeting::Welcome).await
+}
+
+async fn welcome_set(
+    ctx: CmdCtx,
+    channel: Channel,
+    ConsumeRest(message): ConsumeRest,
+) -> Result<()> {
+    set(&ctx, Greeting::Welcome, channel, message).await
+}
+
+async fn welcome_disable(ctx: CmdCtx) -> Result<()> {
+    disable(&ctx, Greeting::Welcome).await
+}
+
+async fn welcome_test(ctx: CmdCtx) -> Result<()> {
+    test(&ctx, Greeting::Welcome).await
+}
+
+async fn goodbye(ctx: CmdCtx) -> Result<()> {
+    show(&ctx, Greeting::Goodbye).await
+}
+
+async fn goodbye_set(
+    ctx: CmdCtx,
+    channel: Channel,
+    ConsumeRest(message): ConsumeRest,
+) -> Result<()> {
+    set(&ctx, Greeting::Goodbye, channel, message).await
+}
+
+async fn goodbye_disable(ctx: CmdCtx) -> Result<()> {
+    disable(&ctx, Greeting::Goodbye).await
+}
+
+async fn goodbye_test(ctx: CmdCtx) -> Result<()> {
+    test(&ctx, Greeting::Goodbye).await
+}
+
+fn build(
+    greeting: Greeting,
+    root: Command,
+    set: Command,
+    disable: Command,
+    test: Command,
+) -> Command {
+    let name = greeting.kind();
+
+    root.description(format!("Manage {name} messages."))
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![ChannelPermission::ManageServer]))
+        .child(
+            set.description(format!(
+                "Sets the {name} channel and message, supports `{{user}}`, `{{username}}`, `{{server}}` and `{{count}}`."
+            ))
+            .signature("<channel> [message]")
+            .check(server_only)
+            .check(HasServerPermissions::new(vec![ChannelPermission::ManageServer])),
+        )
+        .child(
+            disable
+                .description(format!("Disables {name} messages."))
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![ChannelPermission::ManageServer])),
+        )
+        .child(
+            test.description(format!("Previews the {name} message."))
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![ChannelPermission::ManageServer])),
+        )
+}
+
+pub fn commands() -> Vec<Command> {
+    vec![
+        build(
+            Greeting::Welcome,
+            Command::new("welcome", welcome),
+            Command::new("set", welcome_set),
+            Command::new("disable", welcome_disable),
+            Command::new("test", welcome_test),
+        ),
+        build(
+            Greeting::Goodbye,
+            Command::new("goodbye", goodbye),
+            Command::new("set", goodbye_set),
+            Command::new("disable", goodbye_disable),
+            Command::new("test", goodbye_test),
+        ),
+    ]
+}
@@ -5,22 +5,23 @@ use stoat::{
     commands::{Command, ConsumeRest, Context, server_only},
 };
 
-use crate::{Error, MessageExt, State};
+use crate::{ContextLocaleExt, Error, MessageExt, State};
 
 async fn add(ctx: Context<Error, State>, ConsumeRest(keyword): ConsumeRest) -> Result<(), Error> {
     let server_id = ctx.get_current_server().as_ref().unwrap().id.clone();
+    let locale = ctx.locale().await?;
 
     let current_keywords = ctx
         .state
         .fetch_keywords_for_user(&ctx.message.author, &server_id)
         .await?;
 
-    if current_keywords.len() >= ctx.state.config.limits.max_keywords {
+    if current_keywords.len() >= ctx.state.config().limits.max_keywords {
         ctx.get_current_channel()?
             .send(&ctx)
-            .content(format!(
-                "Max keyword amount reached ({})",
-                ctx.state.config.limits.max_keywords
+            .content(locale.t(
+                "highlight-max-keywords",
+                &[("max", ctx.state.config().limits.max_keywords.into())],
             ))
             .build()
             .await?
@@ -37,7 +38,7 @@ async fn add(ctx: Context<Error, State>, ConsumeRest(keyword): ConsumeRest) -> R
         Ok(_) => {
             ctx.get_current_channel()?
                 .send(&ctx)
-                .content("Added to your highlights.".to_string())
+                .content(locale.t("highlight-added", &[]))
                 .build()
                 .await?
                 .delete_after(&ctx, Duration::from_secs(5));
@@ -45,7 +46,7 @@ async fn add(ctx: Context<Error, State>, ConsumeRest(keyword): ConsumeRest) -> R
         Err(Error::PgError(e)) if e.as_database_error().unwrap().is_unique_violation() => {
             ctx.get_current_channel()?
                 .send(&ctx)
-                .content("Keyword already exists.".to_string())
+                .content(locale.t("highlight-exists", &[]))
                 .build()
                 .await?
                 .delete_after(&ctx, Duration::from_secs(5));
@@ -6,16 +6,21 @@ use stoat::{
     types::User,
 };
 
-use crate::{Error, State, utils::MessageExt};
+use crate::{ContextLocaleExt, Error, State, utils::MessageExt};
 
 async fn block(ctx: Context<Error, State>, user: User) -> Result<(), Error> {
+    let locale = ctx.locale().await?;
+
     ctx.state
         .block_user(ctx.message.author.clone(), user.id.clone())
         .await?;
 
     ctx.get_current_channel()?
         .send(&ctx)
-        .content(format!("Blocked {}", user.username))
+        .content(locale.t(
+            "highlight-blocked",
+            &[("user", user.username.as_str().into())],
+        ))
         .build()
         .await?
         .delete_after(&ctx, Duration::from_secs(5));
@@ -5,17 +5,18 @@ use stoat::{
     commands::{Command, Context, server_only},
 };
 
-use crate::{Error, State, utils::MessageExt};
+use crate::{ContextLocaleExt, Error, State, utils::MessageExt};
 
 async fn clear(ctx: Context<Error, State>) -> Result<(), Error> {
     let server_id = ctx.get_current_server().unwrap().id;
     let user = ctx.get_user().await?;
+    let locale = ctx.locale().await?;
 
     let keywords = ctx.state.clear_keywords(&user.id, &server_id).await?;
 
     ctx.get_current_channel()?
         .send(&ctx)
-        .content(format!("Cleared {} keywords", keywords.len()))
+        .content(locale.t("highlight-cleared", &[("count", keywords.len().into())]))
         .build()
         .await?
         .delete_after(&ctx, Duration::from_secs(5));
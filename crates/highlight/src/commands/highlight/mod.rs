@@ -1,11 +1,8 @@
 use std::time::Duration;
 
-use stoat::{
-    ChannelExt,
-    commands::{Command, Context, server_only},
-};
+use stoat::commands::{Command, Context, server_only};
 
-use crate::{Error, State, utils::MessageExt};
+use crate::{ContextLocaleExt, Error, Paginator, State};
 
 mod add;
 mod block;
@@ -14,8 +11,11 @@ mod remove;
 mod unblock;
 mod view;
 
+const KEYWORDS_PER_PAGE: usize = 15;
+
 async fn highlight(ctx: Context<Error, State>) -> Result<(), Error> {
     let server_id = ctx.get_current_server()?.id;
+    let locale = ctx.locale().await?;
 
     let highlights = ctx
         .state
@@ -23,15 +23,17 @@ async fn highlight(ctx: Context<Error, State>) -> Result<(), Error> {
         .await?
         .into_iter()
         .map(|keyword| format!("- {keyword}"))
-        .collect::<Vec<_>>()
-        .join("\n");
-
-    ctx.get_current_channel()?
-        .send(&ctx)
-        .content(format!("Your highlights are:\n{highlights}"))
-        .build()
-        .await?
-        .delete_after(&ctx, Duration::from_secs(5));
+        .collect();
+
+    Paginator::from_lines(
+        locale.t("highlight-list", &[]),
+        highlights,
+        KEYWORDS_PER_PAGE,
+    )
+    .timeout(Duration::from_secs(30))
+    .delete_on_timeout()
+    .run(&ctx)
+    .await?;
 
     Ok(())
 }
@@ -5,13 +5,14 @@ use stoat::{
     commands::{Command, ConsumeRest, Context, server_only},
 };
 
-use crate::{Error, State, utils::MessageExt};
+use crate::{ContextLocaleExt, Error, State, utils::MessageExt};
 
 async fn remove(
     ctx: Context<Error, State>,
     ConsumeRest(keyword): ConsumeRest,
 ) -> Result<(), Error> {
     let server_id = ctx.get_current_server()?.id;
+    let locale = ctx.locale().await?;
 
     let removed = ctx
         .state
@@ -21,13 +22,13 @@ async fn remove(
     if removed {
         ctx.get_current_channel()?
             .send(&ctx)
-            .content("Removed from your highlights.".to_string())
+            .content(locale.t("highlight-removed", &[]))
             .build()
             .await?
     } else {
         ctx.get_current_channel()?
             .send(&ctx)
-            .content("Keyword doesnt exist.".to_string())
+            .content(locale.t("highlight-missing", &[]))
             .build()
             .await?
     }
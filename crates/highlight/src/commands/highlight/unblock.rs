@@ -6,16 +6,21 @@ use stoat::{
     types::User,
 };
 
-use crate::{Error, State, utils::MessageExt};
+use crate::{ContextLocaleExt, Error, State, utils::MessageExt};
 
 async fn unblock(ctx: Context<Error, State>, user: User) -> Result<(), Error> {
+    let locale = ctx.locale().await?;
+
     ctx.state
         .unblock_user(ctx.message.author.clone(), user.id.clone())
         .await?;
 
     ctx.get_current_channel()?
         .send(&ctx)
-        .content(format!("Unblocked {}", user.username))
+        .content(locale.t(
+            "highlight-unblocked",
+            &[("user", user.username.as_str().into())],
+        ))
         .build()
         .await?
         .delete_after(&ctx, Duration::from_secs(5));
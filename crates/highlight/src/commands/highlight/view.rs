@@ -1,16 +1,17 @@
 use std::time::Duration;
 
 use stoat::{
-    ChannelExt,
     commands::{Command, Context, HasChannelPermissions, server_only},
     permissions::ChannelPermission,
     types::User,
 };
 
-use crate::{Error, State, utils::MessageExt};
+use super::KEYWORDS_PER_PAGE;
+use crate::{ContextLocaleExt, Error, Paginator, State};
 
 async fn view(ctx: Context<Error, State>, user: User) -> Result<(), Error> {
     let server_id = ctx.get_current_server()?.id;
+    let locale = ctx.locale().await?;
 
     let highlights = ctx
         .state
@@ -18,18 +19,20 @@ async fn view(ctx: Context<Error, State>, user: User) -> Result<(), Error> {
         .await?
         .into_iter()
         .map(|keyword| format!("- {keyword}"))
-        .collect::<Vec<_>>()
-        .join("\n");
+        .collect();
 
-    ctx.get_current_channel()?
-        .send(&ctx)
-        .content(format!(
-            "{}'s highlights are:\n{highlights}",
-            &user.username
-        ))
-        .build()
-        .await?
-        .delete_after(&ctx, Duration::from_secs(5));
+    Paginator::from_lines(
+        locale.t(
+            "highlight-list-user",
+            &[("user", user.username.as_str().into())],
+        ),
+        highlights,
+        KEYWORDS_PER_PAGE,
+    )
+    .timeout(Duration::from_secs(30))
+    .delete_on_timeout()
+    .run(&ctx)
+    .await?;
 
     Ok(())
 }
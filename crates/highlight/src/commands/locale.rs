@@ -0,0 +1,134 @@
+use stoat::{
+    commands::{HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, Error, Locale, Result, available_locales, normalize_locale,
+};
+
+fn format_available() -> String {
+    available_locales()
+        .into_iter()
+        .map(|locale| format!("`{locale}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parse_locale(locale: &Locale, input: &str) -> Result<String> {
+    normalize_locale(input).ok_or_else(|| {
+        Error::UserError(locale.t(
+            "locale-unknown",
+            &[
+                ("locale", input.into()),
+                ("available", format_available().into()),
+            ],
+        ))
+    })
+}
+
+async fn locale(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let user_locale = ctx
+        .state
+        .get_user_locale(&ctx.message.author)
+        .await?
+        .unwrap_or_else(|| locale.t("locale-default", &[]));
+
+    let server_locale = match ctx.get_current_server() {
+        Ok(server) => ctx.state.get_server_locale(&server.id).await?,
+        Err(_) => None,
+    }
+    .unwrap_or_else(|| locale.t("locale-default", &[]));
+
+    ctx.send()
+        .content(locale.t(
+            "locale-current",
+            &[
+                ("locale", user_locale.into()),
+                ("server", server_locale.into()),
+                ("available", format_available().into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn set(ctx: CmdCtx, input: String) -> Result<()> {
+    let code = parse_locale(&ctx.locale().await?, &input)?;
+
+    ctx.state
+        .set_user_locale(&ctx.message.author, Some(&code))
+        .await?;
+
+    let locale = ctx.locale().await?;
+
+    ctx.send()
+        .content(locale.t("locale-set", &[("locale", code.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn reset(ctx: CmdCtx) -> Result<()> {
+    ctx.state.set_user_locale(&ctx.message.author, None).await?;
+
+    let locale = ctx.locale().await?;
+
+    ctx.send()
+        .content(locale.t("locale-reset", &[]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn server(ctx: CmdCtx, input: String) -> Result<()> {
+    let server = ctx.get_current_server()?;
+
+    let code = if input.eq_ignore_ascii_case("reset") {
+        None
+    } else {
+        Some(parse_locale(&ctx.locale().await?, &input)?)
+    };
+
+    ctx.state
+        .set_server_locale(&server.id, code.as_deref())
+        .await?;
+
+    let locale = ctx.locale().await?;
+
+    let content = match code {
+        Some(code) => locale.t("locale-server-set", &[("locale", code.into())]),
+        None => locale.t("locale-server-reset", &[]),
+    };
+
+    ctx.send().content(content).build().await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("locale", locale)
+        .alias("language")
+        .description("Shows or changes your language.")
+        .child(
+            Command::new("set", set)
+                .description("Changes your language.")
+                .signature("<language>"),
+        )
+        .child(Command::new("reset", reset).description("Uses the server's language."))
+        .child(
+            Command::new("server", server)
+                .description("Changes the server's language.")
+                .signature("<language|reset>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+}
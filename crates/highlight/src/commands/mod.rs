@@ -6,11 +6,22 @@ use stoat::{
     error::{StoatHttpError, StoatHttpErrorType},
 };
 
-use crate::{Error, State, utils::MessageExt};
+use crate::{
+    ContextLocaleExt, Error, ErrorReport, Handled, Metrics, State, report_error, utils::MessageExt,
+};
 
+mod admin;
+mod automod;
+mod autorole;
+mod command;
+mod greetings;
 mod highlight;
 mod info;
+mod locale;
 mod moderation;
+mod prefix;
+mod reactionroles;
+mod remind;
 mod starboard;
 mod stats;
 
@@ -23,7 +34,21 @@ impl CommandEventHandler for CommandEvents {
     type State = State;
 
     async fn get_prefix(&self, ctx: Context<Error, State>) -> Result<Vec<String>, Error> {
-        Ok(when_mentioned_or(&ctx, &ctx.state.config.bot.prefix))
+        if let Ok(server) = ctx.get_current_server() {
+            let prefixes = ctx.state.get_server_prefixes(&server.id).await?;
+
+            if !prefixes.is_empty() {
+                return Ok(when_mentioned_or(&ctx, &prefixes));
+            };
+        };
+
+        Ok(when_mentioned_or(&ctx, &ctx.state.config().bot.prefix))
+    }
+
+    async fn command(&self, ctx: Context<Error, State>) -> Result<(), Error> {
+        Metrics::increment(&ctx.state.metrics.commands_invoked);
+
+        Ok(())
     }
 
     async fn after_command(&self, ctx: Context<Error, State>) -> Result<(), Error> {
@@ -39,36 +64,49 @@ impl CommandEventHandler for CommandEvents {
     }
 
     async fn error(&self, ctx: Context<Error, State>, error: Error) -> Result<(), Error> {
+        Metrics::increment(&ctx.state.metrics.command_errors);
+
         if ctx.local_cache(|| None::<Handled>).is_some() {
             return Ok(());
         };
 
-        let msg = match error {
-            Error::StoatError(StoatError::NotInServer) => {
-                "This command can only be used in a server".to_string()
+        let locale = ctx.locale().await.unwrap_or_default();
+
+        let msg = match error.clone() {
+            Error::StoatError(StoatError::NotInServer) => locale.t("error-not-in-server", &[]),
+            Error::StoatError(StoatError::MissingParameter) => {
+                locale.t("error-missing-parameter", &[])
             }
-            Error::StoatError(StoatError::MissingParameter) => "Missing parameter".to_string(),
             Error::StoatError(StoatError::ConverterError(msg)) => msg,
-            Error::StoatError(StoatError::MissingChannelPermission { permissions }) => {
-                format!("Missing `{permissions}` permission.")
-            }
+            Error::UserError(msg) => msg,
+            Error::StoatError(StoatError::MissingChannelPermission { permissions }) => locale.t(
+                "error-missing-permission",
+                &[("permission", permissions.to_string().into())],
+            ),
             Error::StoatError(StoatError::HttpError(StoatHttpError { error_type, .. })) => {
                 match error_type {
-                    StoatHttpErrorType::MissingPermission { permission } => {
-                        format!("Bot is missing permission `{permission}`.")
-                    }
-                    StoatHttpErrorType::NotElevated => {
-                        "Bot does not have enough permission to do this.".to_string()
-                    }
+                    StoatHttpErrorType::MissingPermission { permission } => locale.t(
+                        "error-bot-missing-permission",
+                        &[("permission", permission.to_string().into())],
+                    ),
+                    StoatHttpErrorType::NotElevated => locale.t("error-bot-not-elevated", &[]),
                     _ => {
-                        log::error!("{error_type:?}");
-                        return Ok(());
+                        let id = report_error(&ctx.state, ErrorReport::from_context(&ctx, &error));
+
+                        locale.t("error-unknown", &[("id", id.into())])
                     }
                 }
             }
+            Error::StoatError(
+                StoatError::CheckFailure
+                | StoatError::NotOwner
+                | StoatError::NotNsfw
+                | StoatError::NotInDM,
+            ) => return Ok(()),
             _ => {
-                log::error!("{error:?}");
-                return Ok(());
+                let id = report_error(&ctx.state, ErrorReport::from_context(&ctx, &error));
+
+                locale.t("error-unknown", &[("id", id.into())])
             }
         };
 
@@ -276,13 +314,22 @@ impl CommandEventHandler for CommandEvents {
 pub fn commands() -> Vec<Command<Error, State>> {
     [
         vec![
+            admin::command(),
+            autorole::command(),
+            automod::command(),
+            command::command(),
             highlight::command(),
             info::command(),
+            locale::command(),
+            prefix::command(),
+            reactionroles::command(),
+            remind::command(),
             starboard::command(),
             stats::command(),
         ]
         .as_slice(),
         moderation::commands().as_slice(),
+        greetings::commands().as_slice(),
     ]
     .concat()
 }
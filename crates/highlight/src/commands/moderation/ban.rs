@@ -2,34 +2,41 @@ use stoat::{
     Identifiable, Ulid,
     commands::{ConsumeRest, HasServerPermissions, server_only},
     either::Either,
-    types::{ChannelPermission, DataBanCreate, Member},
+    types::{ChannelPermission, Member},
 };
 
-use crate::{CmdCtx, Command, Result};
+use crate::{CaseAction, CmdCtx, Command, ContextLocaleExt, NewModCase, Result, apply_mod_action};
 
 pub async fn ban(
     ctx: CmdCtx,
     member: Either<Member, Ulid>,
     ConsumeRest(reason): ConsumeRest,
 ) -> Result<()> {
+    let locale = ctx.locale().await?;
     let server = ctx.get_current_server()?;
 
-    ctx.http
-        .ban_member(
-            &server.id,
-            member.id(),
-            &DataBanCreate {
-                reason: Some(if reason.is_empty() {
-                    "No reason".to_string()
-                } else {
-                    reason
-                }),
-            },
-        )
-        .await?;
+    let case = apply_mod_action(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: server.id.clone(),
+            action: CaseAction::Ban,
+            target_id: Some(member.id().to_string()),
+            moderator_id: ctx.message.author.clone(),
+            reason: (!reason.is_empty()).then_some(reason),
+            duration: None,
+        },
+    )
+    .await?;
 
     ctx.send()
-        .content(format!("Banned <@{}>.", member.id(),))
+        .content(locale.t(
+            "ban-done",
+            &[
+                ("user", format!("<@{}>", member.id()).into()),
+                ("case", case.id.into()),
+            ],
+        ))
         .build()
         .await?;
 
@@ -0,0 +1,35 @@
+use stoat::{
+    commands::{HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result};
+
+async fn case(ctx: CmdCtx, case_id: i32) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let Some(case) = ctx.state.fetch_mod_case(&server.id, case_id).await? else {
+        ctx.send()
+            .content(locale.t("case-not-found", &[("case", case_id.into())]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    ctx.send().embed(case.embed(&locale)).build().await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("case", case)
+        .description("Shows a moderation case.")
+        .signature("<case>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageMessages,
+        ]))
+}
@@ -0,0 +1,74 @@
+use stoat::{
+    Identifiable, Ulid,
+    commands::{HasServerPermissions, server_only},
+    either::Either,
+    types::{ChannelPermission, User},
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Paginator, Result};
+
+const CASES_PER_PAGE: usize = 10;
+
+async fn cases(ctx: CmdCtx, user: Either<User, Ulid>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let cases = ctx
+        .state
+        .fetch_mod_cases_for_user(&server.id, user.id())
+        .await?;
+
+    if cases.is_empty() {
+        ctx.send()
+            .content(locale.t(
+                "cases-none",
+                &[("user", format!("<@{}>", user.id()).into())],
+            ))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let lines = cases
+        .iter()
+        .map(|case| {
+            format!(
+                "- `#{}` {} - {} (<t:{}:R>)",
+                case.id,
+                case.action.name(&locale),
+                case.reason
+                    .clone()
+                    .unwrap_or_else(|| locale.t("case-no-reason", &[])),
+                case.created_at
+            )
+        })
+        .collect();
+
+    Paginator::from_lines(
+        locale.t(
+            "cases-header",
+            &[
+                ("user", format!("<@{}>", user.id()).into()),
+                ("count", cases.len().into()),
+            ],
+        ),
+        lines,
+        CASES_PER_PAGE,
+    )
+    .run(&ctx)
+    .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("cases", cases)
+        .description("Lists the moderation cases for a user.")
+        .signature("<user>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageMessages,
+        ]))
+}
@@ -0,0 +1,46 @@
+use stoat::{
+    MemberExt,
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::{ChannelPermission, Member},
+};
+
+use crate::{CaseAction, CmdCtx, Command, ContextLocaleExt, NewModCase, Result, apply_mod_action};
+
+pub async fn kick(ctx: CmdCtx, member: Member, ConsumeRest(reason): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let case = apply_mod_action(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: member.id.server.clone(),
+            action: CaseAction::Kick,
+            target_id: Some(member.id.user.clone()),
+            moderator_id: ctx.message.author.clone(),
+            reason: (!reason.is_empty()).then_some(reason),
+            duration: None,
+        },
+    )
+    .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "kick-done",
+            &[("user", member.mention().into()), ("case", case.id.into())],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("kick", kick)
+        .description("Kicks a member.")
+        .signature("<member> <reason...>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::KickMembers,
+        ]))
+}
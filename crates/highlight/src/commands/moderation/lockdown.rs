@@ -0,0 +1,182 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use stoat::{
+    ChannelExt,
+    commands::{Converter, HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission},
+};
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, DurationConverter, Error, Locale, Result, is_locked,
+    lock_channel, unlock_channel,
+};
+
+enum LockdownTarget {
+    Channel(Box<Channel>),
+    Server,
+}
+
+async fn resolve_target(
+    ctx: &CmdCtx,
+    locale: &Locale,
+    input: Option<String>,
+) -> Result<LockdownTarget> {
+    let Some(input) = input else {
+        return Ok(LockdownTarget::Channel(Box::new(
+            ctx.get_current_channel()?,
+        )));
+    };
+
+    if input.eq_ignore_ascii_case("server") {
+        return Ok(LockdownTarget::Server);
+    };
+
+    let channel = Channel::convert(ctx, input).await?;
+
+    if channel.server() != Some(ctx.get_current_server()?.id.as_str()) {
+        return Err(Error::UserError(
+            locale.t("error-channel-not-in-server", &[]),
+        ));
+    };
+
+    Ok(LockdownTarget::Channel(Box::new(channel)))
+}
+
+async fn lockdown(ctx: CmdCtx, first: Option<String>, second: Option<String>) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let (target, duration) = match (first, second) {
+        (Some(first), None) if humantime::parse_duration(&first).is_ok() => (None, Some(first)),
+        (first, second) => (first, second),
+    };
+
+    let target = resolve_target(&ctx, &locale, target).await?;
+
+    let duration = match duration {
+        Some(duration) => Some(DurationConverter::convert(&ctx, duration).await?.0),
+        None => None,
+    };
+
+    let expires_at = duration.map(|duration| {
+        (SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            + duration)
+            .as_secs() as i64
+    });
+
+    let duration = duration.map(|duration: Duration| locale.format_duration(duration));
+
+    match target {
+        LockdownTarget::Channel(channel) => {
+            lock_channel(&ctx.http, &ctx.state, &channel, expires_at).await?;
+
+            ctx.send()
+                .content(match duration {
+                    Some(duration) => locale.t(
+                        "lockdown-channel-duration",
+                        &[
+                            ("channel", channel.mention().into()),
+                            ("duration", duration.into()),
+                        ],
+                    ),
+                    None => locale.t("lockdown-channel", &[("channel", channel.mention().into())]),
+                })
+                .build()
+                .await?;
+        }
+        LockdownTarget::Server => {
+            let server = ctx.get_current_server()?;
+            let mut locked = 0;
+
+            for channel_id in &server.channels {
+                let Some(channel) = ctx.cache.get_channel(channel_id) else {
+                    continue;
+                };
+
+                if is_locked(&channel) && ctx.state.fetch_lockdown(channel_id).await?.is_none() {
+                    continue;
+                };
+
+                match lock_channel(&ctx.http, &ctx.state, &channel, expires_at).await {
+                    Ok(()) => locked += 1,
+                    Err(e) => log::warn!("Failed to lock {channel_id}: {e:?}"),
+                };
+            }
+
+            ctx.send()
+                .content(match duration {
+                    Some(duration) => locale.t(
+                        "lockdown-server-duration",
+                        &[("count", locked.into()), ("duration", duration.into())],
+                    ),
+                    None => locale.t("lockdown-server", &[("count", locked.into())]),
+                })
+                .build()
+                .await?;
+        }
+    };
+
+    Ok(())
+}
+
+async fn unlock(ctx: CmdCtx, target: Option<String>) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    match resolve_target(&ctx, &locale, target).await? {
+        LockdownTarget::Channel(channel) => {
+            let Some(lockdown) = ctx.state.fetch_lockdown(channel.id()).await? else {
+                return Err(Error::UserError(locale.t(
+                    "unlock-not-locked",
+                    &[("channel", channel.mention().into())],
+                )));
+            };
+
+            unlock_channel(&ctx.http, &ctx.state, &lockdown).await?;
+
+            ctx.send()
+                .content(locale.t("unlock-channel", &[("channel", channel.mention().into())]))
+                .build()
+                .await?;
+        }
+        LockdownTarget::Server => {
+            let server = ctx.get_current_server()?;
+            let mut unlocked = 0;
+
+            for lockdown in ctx.state.fetch_lockdowns_for_server(&server.id).await? {
+                match unlock_channel(&ctx.http, &ctx.state, &lockdown).await {
+                    Ok(()) => unlocked += 1,
+                    Err(e) => log::warn!("Failed to unlock {}: {e:?}", lockdown.channel_id),
+                };
+            }
+
+            ctx.send()
+                .content(locale.t("unlock-server", &[("count", unlocked.into())]))
+                .build()
+                .await?;
+        }
+    };
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("lockdown", lockdown)
+        .alias("lock")
+        .description("Stops members from sending messages in a channel or the whole server.")
+        .signature("[channel|server] [duration]")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManagePermissions,
+        ]))
+}
+
+pub fn unlock_command() -> Command {
+    Command::new("unlock", unlock)
+        .description("Restores permissions changed by a lockdown.")
+        .signature("[channel|server]")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManagePermissions,
+        ]))
+}
@@ -0,0 +1,100 @@
+use stoat::{
+    ChannelExt,
+    commands::{HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission},
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result};
+
+async fn messagelog(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let config = ctx.state.fetch_server_config(&server.id).await?;
+
+    ctx.send()
+        .content(match &config.message_log_channel {
+            Some(channel) => locale.t(
+                "messagelog-current",
+                &[("channel", format!("<#{channel}>").into())],
+            ),
+            None => locale.t("messagelog-none", &[]),
+        })
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn channel(ctx: CmdCtx, channel: Channel) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    if match &channel {
+        Channel::TextChannel {
+            server: server_id, ..
+        } => server_id != &server.id,
+        _ => true,
+    } {
+        ctx.send()
+            .content(locale.t("error-invalid-channel", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    }
+
+    ctx.state
+        .update_server_config_message_log_channel(&server.id, Some(channel.id()))
+        .await?;
+
+    ctx.send()
+        .content(locale.t("messagelog-set", &[("channel", channel.mention().into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn disable(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    ctx.state
+        .update_server_config_message_log_channel(&server.id, None)
+        .await?;
+
+    ctx.send()
+        .content(locale.t("messagelog-disabled", &[]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("messagelog", messagelog)
+        .description("Manage the deleted and edited message log channel.")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageServer,
+        ]))
+        .child(
+            Command::new("channel", channel)
+                .description("Sets the message log channel.")
+                .signature("<channel>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+        .child(
+            Command::new("disable", disable)
+                .description("Disables the message log.")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+}
@@ -3,9 +3,42 @@ use stoat::commands::Command;
 use crate::{Error, State};
 
 mod ban;
+mod case;
+mod cases;
+mod kick;
+mod lockdown;
+mod memberlog;
+mod messagelog;
+mod modlog;
 mod purge;
+mod reason;
+mod slowmode;
+mod snipe;
+mod softban;
+mod tempban;
 mod timeout;
+mod unban;
+mod warn;
 
 pub fn commands() -> Vec<Command<Error, State>> {
-    vec![timeout::command(), purge::command(), ban::command()]
+    vec![
+        timeout::command(),
+        purge::command(),
+        ban::command(),
+        kick::command(),
+        unban::command(),
+        softban::command(),
+        tempban::command(),
+        warn::command(),
+        case::command(),
+        cases::command(),
+        reason::command(),
+        modlog::command(),
+        lockdown::command(),
+        lockdown::unlock_command(),
+        slowmode::command(),
+        messagelog::command(),
+        memberlog::command(),
+        snipe::command(),
+    ]
 }
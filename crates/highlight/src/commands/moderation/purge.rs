@@ -1,58 +1,302 @@
-use std::time::Duration;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::LazyLock,
+    time::{Duration, SystemTime},
+};
 
+use regex::Regex;
 use stoat::{
-    ChannelExt, Identifiable,
-    commands::{Greedy, HasChannelPermissions, server_only},
+    ChannelExt, Identifiable, async_trait,
+    commands::{Converter, Greedy, HasChannelPermissions, server_only},
     either::Either,
-    types::{ChannelPermission, OptionsBulkDelete, User},
+    types::{ChannelPermission, Message, OptionsBulkDelete, User},
     ulid::Ulid,
 };
 
-use crate::{CmdCtx, Command, Error, MessageExt, Result};
+use crate::{
+    CaseAction, CmdCtx, Command, ContextLocaleExt, Error, MessageExt, NewModCase, Result, State,
+    log_mod_case,
+};
+
+static LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"https?://\S+").unwrap());
+
+const MAX_LIMIT: u32 = 1000;
+const BULK_DELETE_MAX_AGE: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+
+#[derive(Default)]
+struct PurgeOptions {
+    limit: Option<u32>,
+    bots: bool,
+    links: bool,
+    attachments: bool,
+    embeds: bool,
+    contains: Option<String>,
+    regex: Option<Regex>,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl PurgeOptions {
+    fn has_filters(&self) -> bool {
+        self.bots
+            || self.links
+            || self.attachments
+            || self.embeds
+            || self.contains.is_some()
+            || self.regex.is_some()
+            || self.before.is_some()
+            || self.after.is_some()
+    }
+
+    fn matches(&self, message: &Message, bots: &HashSet<String>) -> bool {
+        let content = message.content.as_deref().unwrap_or_default();
+
+        (!self.bots || message.webhook.is_some() || bots.contains(&message.author))
+            && (!self.links || LINK_REGEX.is_match(content))
+            && (!self.attachments
+                || message
+                    .attachments
+                    .as_ref()
+                    .is_some_and(|attachments| !attachments.is_empty()))
+            && (!self.embeds
+                || message
+                    .embeds
+                    .as_ref()
+                    .is_some_and(|embeds| !embeds.is_empty()))
+            && self
+                .contains
+                .as_ref()
+                .is_none_or(|text| content.to_lowercase().contains(text))
+            && self
+                .regex
+                .as_ref()
+                .is_none_or(|regex| regex.is_match(content))
+    }
+}
+
+fn split_arguments(input: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+
+    for char in input.chars() {
+        match char {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    args.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        args.push(current);
+    };
+
+    args
+}
+
+#[async_trait]
+impl Converter<Error, State> for PurgeOptions {
+    async fn from_context(context: &CmdCtx) -> Result<Self> {
+        let input = context.words.rest().join(" ");
+
+        Self::convert(context, input).await
+    }
+
+    async fn convert(context: &CmdCtx, input: String) -> Result<Self> {
+        let locale = context.locale().await?;
+        let mut options = PurgeOptions::default();
+
+        for (i, arg) in split_arguments(&input).into_iter().enumerate() {
+            if i == 0
+                && let Ok(limit) = arg.parse::<u32>()
+            {
+                options.limit = Some(limit);
+                continue;
+            };
+
+            let (key, value) = arg.split_once(':').unwrap_or((&arg, ""));
+
+            match key.to_lowercase().as_str() {
+                "bots" => options.bots = true,
+                "links" => options.links = true,
+                "attachments" | "files" => options.attachments = true,
+                "embeds" => options.embeds = true,
+                "contains" if !value.is_empty() => options.contains = Some(value.to_lowercase()),
+                "regex" if !value.is_empty() => {
+                    options.regex = Some(
+                        Regex::new(value)
+                            .map_err(|_| Error::UserError(locale.t("purge-invalid-regex", &[])))?,
+                    )
+                }
+                "before" | "after" => {
+                    let id = Ulid::from_string(value.to_string())
+                        .map_err(|_| {
+                            Error::UserError(
+                                locale.t("purge-invalid-message-id", &[("id", value.into())]),
+                            )
+                        })?
+                        .to_string();
+
+                    if key == "before" {
+                        options.before = Some(id);
+                    } else {
+                        options.after = Some(id);
+                    }
+                }
+                _ => {
+                    return Err(Error::UserError(
+                        locale.t("purge-unknown-filter", &[("filter", arg.as_str().into())]),
+                    ));
+                }
+            };
+        }
+
+        Ok(options)
+    }
+}
 
 async fn purge(
     ctx: CmdCtx,
     Greedy(users): Greedy<Either<User, Ulid>>,
-    limit: Option<u32>,
+    options: PurgeOptions,
 ) -> Result<()> {
-    if users.is_empty() && limit.is_none() {
-        return Err(Error::UserError("No specified users or limit.".to_string()));
+    let locale = ctx.locale().await?;
+
+    if users.is_empty() && options.limit.is_none() && !options.has_filters() {
+        return Err(Error::UserError(locale.t("purge-no-arguments", &[])));
     };
 
-    let limit = limit.unwrap_or(10).min(100);
+    let limit = options.limit.unwrap_or(10).min(MAX_LIMIT);
     let channel = ctx.get_current_channel().unwrap();
 
-    let messages = channel
-        .fetch_messages(&ctx)
-        .limit(limit as i64)
-        .build()
-        .await?
-        .into_iter()
-        .filter(|msg| users.is_empty() || users.iter().any(|user| user.id() == &msg.author))
-        .map(|msg| msg.id)
-        .collect::<Vec<_>>();
+    let oldest_allowed = SystemTime::now() - BULK_DELETE_MAX_AGE;
+
+    let mut before = options.before.clone();
+    let mut scanned = 0;
+    let mut hit_age_limit = false;
+    let mut messages = Vec::new();
+
+    'outer: while scanned < limit {
+        let mut builder = channel.fetch_messages(&ctx);
+        builder.limit((limit - scanned).min(100) as i64);
+
+        if let Some(before) = before.clone() {
+            builder.before(before);
+        };
+
+        let page = builder.build_with_users().await?;
+
+        if page.messages.is_empty() {
+            break;
+        };
+
+        let bots = page
+            .users
+            .iter()
+            .filter(|user| user.bot.is_some())
+            .map(|user| user.id.clone())
+            .collect::<HashSet<_>>();
+
+        before = page.messages.last().map(|msg| msg.id.clone());
+
+        for message in page.messages {
+            if options
+                .after
+                .as_ref()
+                .is_some_and(|after| &message.id <= after)
+            {
+                break 'outer;
+            };
+
+            if Ulid::from_string_unchecked(message.id.clone()).timestamp() < oldest_allowed {
+                hit_age_limit = true;
+                break 'outer;
+            };
+
+            scanned += 1;
+
+            if (users.is_empty() || users.iter().any(|user| user.id() == message.author))
+                && options.matches(&message, &bots)
+            {
+                messages.push(message);
+            };
+        }
+    }
 
     let len = messages.len();
 
-    if !messages.is_empty() {
+    let mut breakdown = HashMap::<String, usize>::new();
+
+    for message in &messages {
+        *breakdown.entry(message.author.clone()).or_default() += 1;
+    }
+
+    for chunk in messages.chunks(100) {
         channel
-            .delete_messages(&ctx, &OptionsBulkDelete { ids: messages })
+            .delete_messages(
+                &ctx,
+                &OptionsBulkDelete {
+                    ids: chunk.iter().map(|msg| msg.id.clone()).collect(),
+                },
+            )
             .await?;
+    }
+
+    if len != 0 {
+        let server_id = ctx.get_current_server()?.id;
+        let server_locale = ctx.state.server_locale(&server_id).await?;
+
+        log_mod_case(
+            &ctx.http,
+            &ctx.state,
+            NewModCase {
+                server_id,
+                action: CaseAction::Purge,
+                target_id: None,
+                moderator_id: ctx.message.author.clone(),
+                reason: Some(server_locale.t(
+                    "purge-reason",
+                    &[("count", len.into()), ("channel", channel.mention().into())],
+                )),
+                duration: None,
+            },
+        )
+        .await?;
+    };
+
+    let mut breakdown = breakdown.into_iter().collect::<Vec<_>>();
+    breakdown.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let mut lines = vec![locale.t("purge-done", &[("count", len.into())])];
+
+    for (user_id, count) in breakdown {
+        lines.push(format!("- <@{user_id}>: {count}"));
+    }
+
+    if hit_age_limit {
+        lines.push(locale.t("purge-age-limit", &[]));
     };
 
     ctx.send()
-        .content(format!("Deleted {len} messages."))
+        .content(lines.join("\n"))
         .build()
         .await?
-        .delete_after(&ctx, Duration::from_secs(5));
+        .delete_after(&ctx, Duration::from_secs(10));
 
     Ok(())
 }
 
 pub fn command() -> Command {
     Command::new("purge", purge)
-        .description("Bulk deletes messages")
-        .signature("<users...> <limit>")
+        .description(
+            "Bulk deletes messages\n\n\
+Filters: `bots`, `links`, `attachments`, `embeds`, `contains:\"text\"`, `regex:\"pattern\"`, `before:<message id>`, `after:<message id>`",
+        )
+        .signature("<users...> <limit> <filters...>")
         .hidden()
         .check(server_only)
         .check(HasChannelPermissions::new(vec![
@@ -0,0 +1,49 @@
+use stoat::{
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result, update_mod_case_log};
+
+async fn reason(ctx: CmdCtx, case_id: i32, ConsumeRest(reason): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if reason.is_empty() {
+        return Err(Error::UserError(locale.t("reason-missing", &[])));
+    };
+
+    let server = ctx.get_current_server()?;
+
+    let Some(case) = ctx
+        .state
+        .update_mod_case_reason(&server.id, case_id, &reason)
+        .await?
+    else {
+        ctx.send()
+            .content(locale.t("case-not-found", &[("case", case_id.into())]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    update_mod_case_log(&ctx.http, &ctx.state, &case).await?;
+
+    ctx.send()
+        .content(locale.t("reason-updated", &[("case", case_id.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("reason", reason)
+        .description("Updates the reason for a moderation case.")
+        .signature("<case> <reason...>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageMessages,
+        ]))
+}
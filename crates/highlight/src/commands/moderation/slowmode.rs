@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use stoat::{
+    ChannelExt,
+    commands::{Converter, HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission},
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, DurationConverter, Error, Result};
+
+const MAX_SLOWMODE: Duration = Duration::from_secs(60 * 60 * 6);
+
+async fn slowmode(ctx: CmdCtx, first: Option<String>, second: Option<String>) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let (channel, interval) = match (first, second) {
+        (Some(channel), Some(interval)) => (Channel::convert(&ctx, channel).await?, Some(interval)),
+        (interval, None) => (ctx.get_current_channel()?, interval),
+        (None, Some(_)) => unreachable!(),
+    };
+
+    let server = ctx.get_current_server()?;
+
+    if channel.server() != Some(server.id.as_str()) {
+        return Err(Error::UserError(
+            locale.t("error-channel-not-in-server", &[]),
+        ));
+    };
+
+    let Some(interval) = interval else {
+        let content = match ctx.state.get_slowmode(channel.id()).await? {
+            Some(interval) => locale.t(
+                "slowmode-current",
+                &[
+                    ("channel", channel.mention().into()),
+                    (
+                        "interval",
+                        locale.format_duration(Duration::from_secs(interval)).into(),
+                    ),
+                ],
+            ),
+            None => locale.t(
+                "slowmode-current-disabled",
+                &[("channel", channel.mention().into())],
+            ),
+        };
+
+        ctx.send().content(content).build().await?;
+
+        return Ok(());
+    };
+
+    if matches!(interval.to_lowercase().as_str(), "off" | "0" | "disable") {
+        ctx.state
+            .set_slowmode(&server.id, channel.id(), None)
+            .await?;
+
+        ctx.send()
+            .content(locale.t(
+                "slowmode-disabled",
+                &[("channel", channel.mention().into())],
+            ))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let DurationConverter(interval) = DurationConverter::convert(&ctx, interval).await?;
+
+    if interval < Duration::from_secs(1) || interval > MAX_SLOWMODE {
+        return Err(Error::UserError(locale.t(
+            "slowmode-invalid-interval",
+            &[("max", locale.format_duration(MAX_SLOWMODE).into())],
+        )));
+    };
+
+    ctx.state
+        .set_slowmode(&server.id, channel.id(), Some(interval.as_secs()))
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "slowmode-set",
+            &[
+                ("channel", channel.mention().into()),
+                ("interval", locale.format_duration(interval).into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("slowmode", slowmode)
+        .description("Deletes messages sent faster than the set interval in a channel.")
+        .signature("[channel] [interval|off]")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageChannel,
+        ]))
+}
@@ -0,0 +1,65 @@
+use stoat::{
+    EmbedExt,
+    commands::{HasServerPermissions, server_only},
+    types::{ChannelPermission, SendableEmbed},
+};
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, Result, SNIPE_LIMIT, attachment_links, author_name,
+    fetch_snipes, truncate_content,
+};
+
+async fn snipe(ctx: CmdCtx, count: Option<u32>) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let count = (count.unwrap_or(1) as usize).clamp(1, SNIPE_LIMIT);
+
+    let messages = fetch_snipes(&ctx.state, &ctx.message.channel, count).await;
+
+    if messages.is_empty() {
+        ctx.send()
+            .content(locale.t("snipe-none", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut lines = Vec::new();
+
+    for message in &messages {
+        let mut line = format!(
+            "**{}**: {}",
+            author_name(&ctx, message),
+            truncate_content(message.content.as_deref().unwrap_or_default(), 300)
+        );
+
+        let attachments = attachment_links(&ctx, message);
+
+        if !attachments.is_empty() {
+            line.push_str(&format!("\n{}", attachments.join(", ")));
+        };
+
+        lines.push(line);
+    }
+
+    ctx.send()
+        .embed(
+            SendableEmbed::default()
+                .title(locale.t("snipe-title", &[]))
+                .description(lines.join("\n\n")),
+        )
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("snipe", snipe)
+        .description("Shows the most recently deleted messages in this channel.")
+        .signature("[count]")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageMessages,
+        ]))
+}
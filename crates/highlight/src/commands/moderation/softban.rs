@@ -0,0 +1,108 @@
+use stoat::{
+    ChannelExt, MemberExt,
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission, DataBanCreate, Member, OptionsBulkDelete},
+};
+
+use crate::{CaseAction, CmdCtx, Command, ContextLocaleExt, NewModCase, Result, log_mod_case};
+
+async fn purge_messages(ctx: &CmdCtx, channel: &Channel, user_id: &str) -> Result<usize> {
+    let messages = channel
+        .fetch_messages(ctx)
+        .limit(100)
+        .build()
+        .await?
+        .into_iter()
+        .filter(|msg| msg.author == user_id)
+        .map(|msg| msg.id)
+        .collect::<Vec<_>>();
+
+    let len = messages.len();
+
+    if !messages.is_empty() {
+        channel
+            .delete_messages(ctx, &OptionsBulkDelete { ids: messages })
+            .await?;
+    };
+
+    Ok(len)
+}
+
+pub async fn softban(ctx: CmdCtx, member: Member, ConsumeRest(reason): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let channel = ctx.get_current_channel()?;
+    let reason = (!reason.is_empty()).then_some(reason);
+    let server_locale = ctx.state.server_locale(&member.id.server).await?;
+
+    ctx.http
+        .ban_member(
+            &member.id.server,
+            &member.id.user,
+            &DataBanCreate {
+                reason: Some(
+                    reason
+                        .clone()
+                        .unwrap_or_else(|| server_locale.t("case-no-reason", &[])),
+                ),
+            },
+        )
+        .await?;
+
+    let len = match purge_messages(&ctx, &channel, &member.id.user).await {
+        Ok(len) => len,
+        Err(e) => {
+            log::warn!(
+                "Failed to purge messages from {} in {}: {e:?}",
+                member.id.user,
+                channel.id()
+            );
+
+            0
+        }
+    };
+
+    ctx.http
+        .unban_member(&member.id.server, &member.id.user)
+        .await?;
+
+    let case = log_mod_case(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: member.id.server.clone(),
+            action: CaseAction::Softban,
+            target_id: Some(member.id.user.clone()),
+            moderator_id: ctx.message.author.clone(),
+            reason,
+            duration: None,
+        },
+    )
+    .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "softban-done",
+            &[
+                ("user", member.mention().into()),
+                ("count", len.into()),
+                ("case", case.id.into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("softban", softban)
+        .description(
+            "Bans and immediately unbans a member, deleting their recent messages in this channel.",
+        )
+        .signature("<member> <reason...>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::BanMembers,
+        ]))
+}
@@ -0,0 +1,60 @@
+use stoat::{
+    Identifiable, Ulid,
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    either::Either,
+    types::{ChannelPermission, Member},
+};
+
+use crate::{
+    CaseAction, CmdCtx, Command, ContextLocaleExt, DurationConverter, NewModCase, Result,
+    apply_mod_action,
+};
+
+pub async fn tempban(
+    ctx: CmdCtx,
+    member: Either<Member, Ulid>,
+    DurationConverter(duration): DurationConverter,
+    ConsumeRest(reason): ConsumeRest,
+) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let case = apply_mod_action(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: server.id.clone(),
+            action: CaseAction::Tempban,
+            target_id: Some(member.id().to_string()),
+            moderator_id: ctx.message.author.clone(),
+            reason: (!reason.is_empty()).then_some(reason),
+            duration: Some(duration),
+        },
+    )
+    .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "tempban-done",
+            &[
+                ("user", format!("<@{}>", member.id()).into()),
+                ("duration", locale.format_duration(duration).into()),
+                ("case", case.id.into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("tempban", tempban)
+        .description("Bans a member for a period of time.")
+        .signature("<member> <duration> <reason...>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::BanMembers,
+        ]))
+}
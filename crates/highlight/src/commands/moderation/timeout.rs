@@ -5,28 +5,50 @@ use stoat::{
     types::{ChannelPermission, Member},
 };
 
-use crate::{CmdCtx, Command, DurationConverter, Result};
+use crate::{
+    CaseAction, CmdCtx, Command, ContextLocaleExt, DurationConverter, NewModCase, Result,
+    log_mod_case,
+};
 
 pub async fn timeout(
     ctx: CmdCtx,
     member: Member,
     ConsumeRest(DurationConverter(duration)): ConsumeRest<DurationConverter>,
 ) -> Result<()> {
+    let locale = ctx.locale().await?;
+
     let Ok(iso_duration) = duration.try_into() else {
-        return Err(crate::Error::UserError("Duration too long".to_string()));
+        return Err(crate::Error::UserError(locale.t("timeout-too-long", &[])));
     };
 
     let Some(timestamp) = Timestamp::now_utc().checked_add(iso_duration) else {
-        return Err(crate::Error::UserError("Duration too long".to_string()));
+        return Err(crate::Error::UserError(locale.t("timeout-too-long", &[])));
     };
 
     member.edit(&ctx).timeout(Some(timestamp)).build().await?;
 
+    let case = log_mod_case(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: member.id.server.clone(),
+            action: CaseAction::Timeout,
+            target_id: Some(member.id.user.clone()),
+            moderator_id: ctx.message.author.clone(),
+            reason: None,
+            duration: Some(duration),
+        },
+    )
+    .await?;
+
     ctx.send()
-        .content(format!(
-            "Timed out {} for {}.",
-            member.mention(),
-            humantime::format_duration(duration)
+        .content(locale.t(
+            "timeout-applied",
+            &[
+                ("member", member.mention().into()),
+                ("duration", locale.format_duration(duration).into()),
+                ("case", case.id.into()),
+            ],
         ))
         .build()
         .await?;
@@ -35,9 +57,11 @@ pub async fn timeout(
 }
 
 pub async fn remove(ctx: CmdCtx, member: Member) -> Result<()> {
+    let locale = ctx.locale().await?;
+
     if member.timeout.is_none() {
         ctx.send()
-            .content("Member is not timed out.".to_string())
+            .content(locale.t("timeout-not-timed-out", &[]))
             .build()
             .await?;
 
@@ -46,8 +70,28 @@ pub async fn remove(ctx: CmdCtx, member: Member) -> Result<()> {
 
     member.edit(&ctx).timeout(None).build().await?;
 
+    let case = log_mod_case(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: member.id.server.clone(),
+            action: CaseAction::Untimeout,
+            target_id: Some(member.id.user.clone()),
+            moderator_id: ctx.message.author.clone(),
+            reason: None,
+            duration: None,
+        },
+    )
+    .await?;
+
     ctx.send()
-        .content(format!("Removed timeout for {}", member.mention()))
+        .content(locale.t(
+            "timeout-removed",
+            &[
+                ("member", member.mention().into()),
+                ("case", case.id.into()),
+            ],
+        ))
         .build()
         .await?;
 
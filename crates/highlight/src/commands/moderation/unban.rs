@@ -0,0 +1,94 @@
+use stoat::{
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{
+    CaseAction, CmdCtx, Command, ContextLocaleExt, Error, NewModCase, Result, apply_mod_action,
+};
+
+pub async fn unban(ctx: CmdCtx, ConsumeRest(query): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if query.is_empty() {
+        return Err(Error::UserError(locale.t("unban-no-user", &[])));
+    };
+
+    let server = ctx.get_current_server()?;
+    let bans = ctx.http.fetch_bans(&server.id).await?;
+
+    let query = query
+        .trim_start_matches("<@")
+        .trim_end_matches('>')
+        .to_lowercase();
+
+    let matches = bans
+        .users
+        .iter()
+        .filter(|user| {
+            user.id.to_lowercase() == query
+                || user.username.to_lowercase() == query
+                || format!("{}#{}", user.username, user.discriminator).to_lowercase() == query
+        })
+        .collect::<Vec<_>>();
+
+    let user = match matches.as_slice() {
+        [] => return Err(Error::UserError(locale.t("unban-not-found", &[]))),
+        [user] => user,
+        users => {
+            let mut lines = vec![locale.t("unban-multiple", &[])];
+
+            for user in users.iter().take(10) {
+                lines.push(format!(
+                    "- {}#{} ({})",
+                    user.username, user.discriminator, user.id
+                ));
+            }
+
+            ctx.send().content(lines.join("\n")).build().await?;
+
+            return Ok(());
+        }
+    };
+
+    let case = apply_mod_action(
+        &ctx.http,
+        &ctx.state,
+        NewModCase {
+            server_id: server.id.clone(),
+            action: CaseAction::Unban,
+            target_id: Some(user.id.clone()),
+            moderator_id: ctx.message.author.clone(),
+            reason: None,
+            duration: None,
+        },
+    )
+    .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "unban-done",
+            &[
+                (
+                    "user",
+                    format!("{}#{}", user.username, user.discriminator).into(),
+                ),
+                ("case", case.id.into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("unban", unban)
+        .description("Unbans a user by name or ID.")
+        .signature("<user...>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::BanMembers,
+        ]))
+}
@@ -0,0 +1,199 @@
+use std::time::Duration;
+
+use stoat::{
+    MemberExt,
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::{ChannelPermission, Member},
+};
+
+use crate::{
+    CaseAction, CmdCtx, Command, ContextLocaleExt, DurationConverter, Error, Result, warn_member,
+};
+
+pub async fn warn(ctx: CmdCtx, member: Member, ConsumeRest(reason): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let outcome = warn_member(
+        &ctx.http,
+        &ctx.state,
+        &member.id.server,
+        &member.id.user,
+        &ctx.message.author,
+        (!reason.is_empty()).then_some(reason),
+    )
+    .await?;
+
+    let mut lines = vec![locale.t(
+        "warn-done",
+        &[
+            ("member", member.mention().into()),
+            ("count", outcome.warn_count.into()),
+            ("case", outcome.case.id.into()),
+        ],
+    )];
+
+    if let Some(case) = outcome.escalation {
+        lines.push(locale.t(
+            "warn-escalated",
+            &[
+                ("action", case.action.name(&locale).into()),
+                ("case", case.id.into()),
+            ],
+        ));
+    };
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn thresholds(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let thresholds = ctx.state.fetch_warn_thresholds(&server.id).await?;
+
+    if thresholds.is_empty() {
+        ctx.send()
+            .content(locale.t("warn-thresholds-none", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut lines = vec![locale.t("warn-thresholds-header", &[])];
+
+    for threshold in thresholds {
+        let action = threshold.action.name(&locale);
+
+        lines.push(match threshold.duration {
+            Some(duration) => locale.t(
+                "warn-threshold-entry-duration",
+                &[
+                    ("count", threshold.warn_count.into()),
+                    ("action", action.into()),
+                    (
+                        "duration",
+                        locale
+                            .format_duration(Duration::from_secs(duration as u64))
+                            .into(),
+                    ),
+                ],
+            ),
+            None => locale.t(
+                "warn-threshold-entry",
+                &[
+                    ("count", threshold.warn_count.into()),
+                    ("action", action.into()),
+                ],
+            ),
+        });
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn threshold(
+    ctx: CmdCtx,
+    warn_count: u32,
+    action: String,
+    duration: Option<DurationConverter>,
+) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if warn_count == 0 {
+        return Err(Error::UserError(locale.t("warn-invalid-count", &[])));
+    };
+
+    let server = ctx.get_current_server()?;
+
+    if action == "none" {
+        let removed = ctx
+            .state
+            .remove_warn_threshold(&server.id, warn_count as i32)
+            .await?;
+
+        ctx.send()
+            .content(locale.t(
+                if removed {
+                    "warn-threshold-removed"
+                } else {
+                    "warn-threshold-missing"
+                },
+                &[("count", warn_count.into())],
+            ))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let action = CaseAction::parse(&locale, &action.to_lowercase())?;
+    let duration = duration.map(|DurationConverter(duration)| duration);
+
+    match action {
+        CaseAction::Kick | CaseAction::Ban => {}
+        CaseAction::Timeout | CaseAction::Tempban if duration.is_some() => {}
+        CaseAction::Timeout | CaseAction::Tempban => {
+            return Err(Error::UserError(locale.t(
+                "warn-threshold-needs-duration",
+                &[("action", action.as_str().into())],
+            )));
+        }
+        _ => {
+            return Err(Error::UserError(
+                locale.t("warn-threshold-invalid-action", &[]),
+            ));
+        }
+    };
+
+    ctx.state
+        .set_warn_threshold(&server.id, warn_count as i32, action, duration)
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "warn-threshold-set",
+            &[
+                ("count", warn_count.into()),
+                ("action", action.name(&locale).into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("warn", warn)
+        .description("Warns a member, escalating once configured thresholds are reached.")
+        .signature("<member> <reason...>")
+        .hidden()
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::KickMembers,
+        ]))
+        .child(
+            Command::new("thresholds", thresholds)
+                .description("Lists the warn escalation thresholds.")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::KickMembers,
+                ])),
+        )
+        .child(
+            Command::new("threshold", threshold)
+                .description(
+                    "Sets the action taken when a member reaches a warn count, use `none` to remove it.",
+                )
+                .signature("<count> <timeout|kick|tempban|ban|none> [duration]")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+}
@@ -0,0 +1,147 @@
+use stoat::{
+    commands::{ConsumeRest, HasServerPermissions, server_only},
+    types::ChannelPermission,
+};
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result};
+
+const MAX_PREFIXES: usize = 10;
+const MAX_PREFIX_LENGTH: usize = 32;
+
+async fn prefix(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let prefixes = ctx.state.get_server_prefixes(&server.id).await?;
+
+    let (prefixes, default) = if prefixes.is_empty() {
+        (ctx.state.config().bot.prefix.clone(), true)
+    } else {
+        (prefixes, false)
+    };
+
+    let mut lines = vec![locale.t(
+        if default {
+            "prefix-list-default"
+        } else {
+            "prefix-list"
+        },
+        &[],
+    )];
+
+    for prefix in prefixes {
+        lines.push(format!("- `{prefix}`"));
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn add(ctx: CmdCtx, ConsumeRest(prefix): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if prefix.is_empty() || prefix.chars().count() > MAX_PREFIX_LENGTH {
+        return Err(Error::UserError(locale.t(
+            "prefix-invalid-length",
+            &[("max", MAX_PREFIX_LENGTH.into())],
+        )));
+    };
+
+    let server = ctx.get_current_server()?;
+
+    let mut prefixes = ctx.state.get_server_prefixes(&server.id).await?;
+
+    if prefixes.is_empty() {
+        prefixes = ctx.state.config().bot.prefix.clone();
+    };
+
+    if prefixes
+        .iter()
+        .any(|existing| existing.to_lowercase() == prefix.to_lowercase())
+    {
+        return Err(Error::UserError(
+            locale.t("prefix-exists", &[("prefix", prefix.as_str().into())]),
+        ));
+    };
+
+    if prefixes.len() >= MAX_PREFIXES {
+        return Err(Error::UserError(
+            locale.t("prefix-max-prefixes", &[("max", MAX_PREFIXES.into())]),
+        ));
+    };
+
+    prefixes.push(prefix.clone());
+
+    ctx.state.set_server_prefixes(&server.id, &prefixes).await?;
+
+    ctx.send()
+        .content(locale.t("prefix-added", &[("prefix", prefix.as_str().into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn remove(ctx: CmdCtx, ConsumeRest(prefix): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let mut prefixes = ctx.state.get_server_prefixes(&server.id).await?;
+
+    if prefixes.is_empty() {
+        prefixes = ctx.state.config().bot.prefix.clone();
+    };
+
+    let len = prefixes.len();
+    prefixes.retain(|existing| existing.to_lowercase() != prefix.to_lowercase());
+
+    if prefixes.len() == len {
+        return Err(Error::UserError(
+            locale.t("prefix-missing", &[("prefix", prefix.as_str().into())]),
+        ));
+    };
+
+    if prefixes.is_empty() {
+        return Err(Error::UserError(locale.t("prefix-last", &[])));
+    };
+
+    ctx.state.set_server_prefixes(&server.id, &prefixes).await?;
+
+    ctx.send()
+        .content(locale.t("prefix-removed", &[("prefix", prefix.as_str().into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("prefix", prefix)
+        .alias("prefixes")
+        .description("Manage the server's command prefixes.")
+        .check(server_only)
+        .child(
+            Command::new("list", prefix)
+                .description("Lists the server's command prefixes.")
+                .check(server_only),
+        )
+        .child(
+            Command::new("add", add)
+                .description("Adds a command prefix.")
+                .signature("<prefix>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+        .child(
+            Command::new("remove", remove)
+                .description("Removes a command prefix.")
+                .signature("<prefix>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageServer,
+                ])),
+        )
+}
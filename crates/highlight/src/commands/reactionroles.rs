@@ -0,0 +1,238 @@
+use stoat::{
+    ChannelExt,
+    builders::SendMessageBuilder,
+    commands::{ConsumeRest, Converter, HasServerPermissions, server_only},
+    types::{Channel, ChannelPermission, Interactions, Role},
+};
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, Error, Locale, MAX_REACTION_ROLES, ReactionRole,
+    ReactionRoleMode, ReactionRolePanel, Result, check_role_hierarchy, format_emoji,
+};
+
+fn parse_emoji(locale: &Locale, input: &str) -> Result<String> {
+    if let Some(id) = input
+        .strip_prefix(':')
+        .and_then(|input| input.strip_suffix(':'))
+        .filter(|id| id.len() == 26)
+    {
+        return Ok(id.to_string());
+    };
+
+    if input.chars().count() > 8 || input.chars().any(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::UserError(
+            locale.t("reactionroles-invalid-emoji", &[("emoji", input.into())]),
+        ));
+    };
+
+    Ok(input.to_string())
+}
+
+async fn reactionroles(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let panels = ctx.state.fetch_reaction_role_panels(&server.id).await?;
+
+    if panels.is_empty() {
+        ctx.send()
+            .content(locale.t("reactionroles-none", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut lines = vec![locale.t("reactionroles-list-header", &[])];
+
+    for panel in panels {
+        let Some(menu) = ctx.state.get_reaction_role_menu(&panel.message_id).await? else {
+            continue;
+        };
+
+        lines.push(format!(
+            "- `{}` in <#{}> ({}): {}",
+            panel.message_id,
+            panel.channel_id,
+            panel.mode,
+            menu.roles
+                .iter()
+                .map(|role| format!("{} <%{}>", format_emoji(&role.emoji), role.role_id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+async fn create(ctx: CmdCtx, channel: Channel, ConsumeRest(rest): ConsumeRest) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    if channel.server() != Some(server.id.as_str()) {
+        return Err(Error::UserError(
+            locale.t("error-channel-not-in-server", &[]),
+        ));
+    };
+
+    let mut words = rest.split_whitespace().peekable();
+
+    let mode = match words.peek().map(|word| word.to_lowercase()) {
+        Some(word) if word == "unique" || word == "multi" => {
+            words.next();
+            ReactionRoleMode::parse(&locale, &word)?
+        }
+        _ => ReactionRoleMode::Multi,
+    };
+
+    let words = words.collect::<Vec<_>>();
+
+    if words.is_empty() || words.len() % 2 != 0 {
+        return Err(Error::UserError(
+            locale.t("reactionroles-expected-pairs", &[]),
+        ));
+    };
+
+    if words.len() / 2 > MAX_REACTION_ROLES {
+        return Err(Error::UserError(locale.t(
+            "reactionroles-max-roles",
+            &[("max", MAX_REACTION_ROLES.into())],
+        )));
+    };
+
+    let mut roles = Vec::new();
+
+    for pair in words.chunks(2) {
+        let emoji = parse_emoji(&locale, pair[0])?;
+        let role = Role::convert(&ctx, pair[1].to_string()).await?;
+
+        check_role_hierarchy(&ctx, &role).await?;
+
+        if roles
+            .iter()
+            .any(|existing: &ReactionRole| existing.emoji == emoji)
+        {
+            return Err(Error::UserError(locale.t(
+                "reactionroles-duplicate-emoji",
+                &[("emoji", format_emoji(&emoji).into())],
+            )));
+        };
+
+        roles.push(ReactionRole {
+            emoji,
+            role_id: role.id,
+        });
+    }
+
+    let content = [
+        locale.t(
+            if mode == ReactionRoleMode::Unique {
+                "reactionroles-panel-title-unique"
+            } else {
+                "reactionroles-panel-title"
+            },
+            &[],
+        ),
+        roles
+            .iter()
+            .map(|role| format!("{} - <%{}>", format_emoji(&role.emoji), role.role_id))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    ]
+    .join("\n");
+
+    let message = SendMessageBuilder::new(ctx.http.clone(), channel.id().to_string())
+        .content(content)
+        .interactions(Interactions {
+            reactions: Some(roles.iter().map(|role| role.emoji.clone()).collect()),
+            restrict_reactions: true,
+        })
+        .build()
+        .await?;
+
+    ctx.state
+        .create_reaction_role_panel(
+            &ReactionRolePanel {
+                message_id: message.id.clone(),
+                server_id: server.id.clone(),
+                channel_id: channel.id().to_string(),
+                mode,
+            },
+            &roles,
+        )
+        .await?;
+
+    ctx.send()
+        .content(locale.t(
+            "reactionroles-created",
+            &[
+                ("id", message.id.as_str().into()),
+                ("channel", channel.mention().into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+async fn delete(ctx: CmdCtx, message_id: String) -> Result<()> {
+    let locale = ctx.locale().await?;
+    let server = ctx.get_current_server()?;
+
+    let Some(panel) = ctx
+        .state
+        .delete_reaction_role_panel(&server.id, &message_id)
+        .await?
+    else {
+        return Err(Error::UserError(locale.t(
+            "reactionroles-not-found",
+            &[("id", message_id.as_str().into())],
+        )));
+    };
+
+    let _ = ctx
+        .http
+        .delete_message(&panel.channel_id, &panel.message_id)
+        .await;
+
+    ctx.send()
+        .content(locale.t("reactionroles-deleted", &[]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("reactionroles", reactionroles)
+        .alias("rr")
+        .description("Manage reaction role panels.")
+        .check(server_only)
+        .check(HasServerPermissions::new(vec![
+            ChannelPermission::ManageRole,
+        ]))
+        .child(
+            Command::new("create", create)
+                .description(
+                    "Posts a reaction role panel, mode is either `multi` (default) or `unique`.",
+                )
+                .signature("<channel> [mode] <emoji> <role> [<emoji> <role>...]")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageRole,
+                ])),
+        )
+        .child(
+            Command::new("delete", delete)
+                .description("Deletes a reaction role panel.")
+                .signature("<message id>")
+                .check(server_only)
+                .check(HasServerPermissions::new(vec![
+                    ChannelPermission::ManageRole,
+                ])),
+        )
+}
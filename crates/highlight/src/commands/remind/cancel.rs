@@ -0,0 +1,25 @@
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Result};
+
+async fn cancel(ctx: CmdCtx, id: i32) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if !ctx.state.cancel_reminder(&ctx.message.author, id).await? {
+        return Err(Error::UserError(
+            locale.t("remind-not-found", &[("id", id.into())]),
+        ));
+    };
+
+    ctx.send()
+        .content(locale.t("remind-cancelled", &[("id", id.into())]))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("cancel", cancel)
+        .alias("delete")
+        .description("Cancels one of your reminders.")
+        .signature("<id>")
+}
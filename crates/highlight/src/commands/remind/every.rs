@@ -0,0 +1,46 @@
+use std::time::Duration;
+
+use stoat::commands::ConsumeRest;
+
+use crate::{CmdCtx, Command, ContextLocaleExt, DurationConverter, Error, Result};
+
+use super::create_reminder;
+
+const MIN_INTERVAL: Duration = Duration::from_secs(60 * 10);
+
+async fn every(
+    ctx: CmdCtx,
+    DurationConverter(interval): DurationConverter,
+    ConsumeRest(content): ConsumeRest,
+) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    if interval < MIN_INTERVAL {
+        return Err(Error::UserError(locale.t(
+            "remind-min-interval",
+            &[("min", locale.format_duration(MIN_INTERVAL).into())],
+        )));
+    };
+
+    let reminder = create_reminder(&ctx, interval, content, Some(interval)).await?;
+
+    ctx.send()
+        .content(locale.t(
+            "remind-set-recurring",
+            &[
+                ("duration", locale.format_duration(interval).into()),
+                ("id", reminder.id.into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("every", every)
+        .alias("repeat")
+        .description("Sets a recurring reminder.")
+        .signature("<interval> <text>")
+}
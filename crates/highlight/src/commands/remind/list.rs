@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use crate::{CmdCtx, Command, ContextLocaleExt, Result, truncate_content};
+
+use super::format_due;
+
+async fn list(ctx: CmdCtx) -> Result<()> {
+    let locale = ctx.locale().await?;
+
+    let reminders = ctx
+        .state
+        .fetch_reminders_for_user(&ctx.message.author)
+        .await?;
+
+    if reminders.is_empty() {
+        ctx.send()
+            .content(locale.t("remind-none", &[]))
+            .build()
+            .await?;
+
+        return Ok(());
+    };
+
+    let mut lines = vec![locale.t("remind-list-header", &[])];
+
+    for reminder in reminders {
+        let content = truncate_content(&reminder.content, 100);
+
+        lines.push(match reminder.interval {
+            Some(interval) => locale.t(
+                "remind-list-entry-recurring",
+                &[
+                    ("id", reminder.id.into()),
+                    ("due", format_due(&locale, &reminder).into()),
+                    (
+                        "interval",
+                        locale
+                            .format_duration(Duration::from_secs(interval as u64))
+                            .into(),
+                    ),
+                    ("content", content.into()),
+                ],
+            ),
+            None => locale.t(
+                "remind-list-entry",
+                &[
+                    ("id", reminder.id.into()),
+                    ("due", format_due(&locale, &reminder).into()),
+                    ("content", content.into()),
+                ],
+            ),
+        });
+    }
+
+    ctx.send().content(lines.join("\n")).build().await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("list", list).description("Lists your reminders.")
+}
@@ -0,0 +1,106 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use stoat::commands::ConsumeRest;
+
+use crate::{
+    CmdCtx, Command, ContextLocaleExt, DurationConverter, Error, Locale, Reminder, Result,
+};
+
+mod cancel;
+mod every;
+mod list;
+
+const MAX_CONTENT_LENGTH: usize = 1000;
+const MAX_DURATION: Duration = Duration::from_secs(60 * 60 * 24 * 365 * 2);
+
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
+}
+
+pub fn format_due(locale: &Locale, reminder: &Reminder) -> String {
+    let remaining = (reminder.due_at - now()).max(0) as u64;
+
+    locale.format_duration(Duration::from_secs(remaining))
+}
+
+pub async fn create_reminder(
+    ctx: &CmdCtx,
+    duration: Duration,
+    content: String,
+    interval: Option<Duration>,
+) -> Result<Reminder> {
+    let locale = ctx.locale().await?;
+
+    if duration.is_zero() || duration > MAX_DURATION {
+        return Err(Error::UserError(locale.t(
+            "remind-invalid-duration",
+            &[("max", locale.format_duration(MAX_DURATION).into())],
+        )));
+    };
+
+    if content.is_empty() || content.chars().count() > MAX_CONTENT_LENGTH {
+        return Err(Error::UserError(locale.t(
+            "remind-invalid-content",
+            &[("max", MAX_CONTENT_LENGTH.into())],
+        )));
+    };
+
+    let max_reminders = ctx.state.config().limits.max_reminders;
+
+    if ctx
+        .state
+        .count_reminders_for_user(&ctx.message.author)
+        .await?
+        >= max_reminders as i64
+    {
+        return Err(Error::UserError(
+            locale.t("remind-max-reminders", &[("max", max_reminders.into())]),
+        ));
+    };
+
+    ctx.state
+        .create_reminder(
+            &ctx.message.author,
+            &ctx.message.channel,
+            &ctx.message.id,
+            &content,
+            now() + duration.as_secs().max(1) as i64,
+            interval.map(|interval| interval.as_secs() as i64),
+        )
+        .await
+}
+
+async fn remind(
+    ctx: CmdCtx,
+    DurationConverter(duration): DurationConverter,
+    ConsumeRest(content): ConsumeRest,
+) -> Result<()> {
+    let reminder = create_reminder(&ctx, duration, content, None).await?;
+    let locale = ctx.locale().await?;
+
+    ctx.send()
+        .content(locale.t(
+            "remind-set",
+            &[
+                ("duration", locale.format_duration(duration).into()),
+                ("id", reminder.id.into()),
+            ],
+        ))
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub fn command() -> Command {
+    Command::new("remind", remind)
+        .alias("reminder")
+        .description("Sets a reminder.")
+        .signature("<duration> <text>")
+        .child(every::command())
+        .child(list::command())
+        .child(cancel::command())
+}
@@ -7,10 +7,11 @@ use stoat::{
     types::{Channel, ChannelPermission},
 };
 
-use crate::{Error, State};
+use crate::{ContextLocaleExt, Error, State};
 
 async fn channel(ctx: Context<Error, State>, channel: Option<Channel>) -> Result<(), Error> {
     if let Some(channel) = channel {
+        let locale = ctx.locale().await?;
         let server = ctx.get_current_server()?;
 
         if match &channel {
@@ -20,7 +21,7 @@ async fn channel(ctx: Context<Error, State>, channel: Option<Channel>) -> Result
             _ => true,
         } {
             ctx.send()
-                .content("Invalid channel".to_string())
+                .content(locale.t("error-invalid-channel", &[]))
                 .build()
                 .await?;
 
@@ -40,7 +41,10 @@ async fn channel(ctx: Context<Error, State>, channel: Option<Channel>) -> Result
 
         if !permissions.has_channel_permission(ChannelPermission::ManageChannel) {
             ctx.send()
-                .content("You do not `ManageChannel` permission in that channel.".to_string())
+                .content(locale.t(
+                    "error-missing-permission",
+                    &[("permission", "ManageChannel".into())],
+                ))
                 .build()
                 .await?;
 
@@ -52,7 +56,10 @@ async fn channel(ctx: Context<Error, State>, channel: Option<Channel>) -> Result
             .await?;
 
         ctx.send()
-            .content(format!("Starboard channel set to {}.", channel.mention()))
+            .content(locale.t(
+                "starboard-channel-set",
+                &[("channel", channel.mention().into())],
+            ))
             .build()
             .await?;
     } else {
@@ -4,14 +4,16 @@ use stoat::{
     types::ChannelPermission,
 };
 
-use crate::{Error, State};
+use crate::{ContextLocaleExt, Error, State};
 
 async fn channel(ctx: Context<Error, State>, limit: Option<u32>) -> Result<(), Error> {
     if let Some(limit) = limit {
+        let locale = ctx.locale().await?;
+
         if limit == 0 {
             ctx.get_current_channel()?
                 .send(&ctx)
-                .content("Invalid limit".to_string())
+                .content(locale.t("starboard-invalid-limit", &[]))
                 .build()
                 .await?;
 
@@ -25,7 +27,7 @@ async fn channel(ctx: Context<Error, State>, limit: Option<u32>) -> Result<(), E
 
         ctx.get_current_channel()?
             .send(&ctx)
-            .content(format!("Starboard star limit set to {limit}."))
+            .content(locale.t("starboard-limit-set", &[("count", limit.into())]))
             .build()
             .await?;
     } else {
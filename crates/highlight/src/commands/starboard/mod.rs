@@ -3,25 +3,28 @@ use stoat::{
     commands::{Command, Context, server_only},
 };
 
-use crate::{Error, State};
+use crate::{ContextLocaleExt, Error, State};
 
 mod channel;
 mod limit;
 
 async fn starboard(ctx: Context<Error, State>) -> Result<(), Error> {
+    let locale = ctx.locale().await?;
     let server_id = ctx.get_current_server()?.id;
 
     let config = ctx.state.fetch_server_config(&server_id).await?;
 
     ctx.get_current_channel()?
         .send(&ctx)
-        .content(if let Some(channel) = &config.starboard_channel {
-            format!(
-                "Starboard channel is set to <#{channel}>, with a minimum star count of {}.",
-                config.star_count
-            )
-        } else {
-            "No starboard channel configured.".to_string()
+        .content(match &config.starboard_channel {
+            Some(channel) => locale.t(
+                "starboard-current",
+                &[
+                    ("channel", format!("<#{channel}>").into()),
+                    ("count", config.star_count.into()),
+                ],
+            ),
+            None => locale.t("starboard-none", &[]),
         })
         .build()
         .await?;
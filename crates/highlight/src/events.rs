@@ -1,18 +1,24 @@
-use std::{borrow::Cow, time::Duration};
+use std::{sync::atomic::Ordering, time::Duration};
 
 use stoat::{
     ChannelExt, Context, EmbedExt, EventHandler, InteractionsExt, MessageExt, StoatExt, UserExt,
     async_trait,
     builders::{FetchMessagesBuilder, SendMessageBuilder},
     commands::CommandHandler,
-    permissions::{ChannelPermission, calculate_channel_permissions, user_permissions_query},
     types::{
-        DataEditUser, EventV1, Interactions, Member, Message, RemovalIntention, SendableEmbed,
-        UserStatus,
+        Channel, DataEditUser, EventV1, FieldsChannel, FieldsMessage, FieldsRole, FieldsServer,
+        Interactions, Member, Message, PartialChannel, PartialMessage, PartialRole, PartialServer,
+        RemovalIntention, Role, SendableEmbed, Server, UserStatus,
     },
 };
 
-use crate::{Error, State, commands::CommandEvents};
+use crate::{
+    Cluster, Error, ErrorReport, Metrics, OriginalMessage, State, commands::CommandEvents,
+    filter_can_view_channel, handle_member_join, handle_member_leave, handle_reaction_role,
+    invalidate_channel_permissions, invalidate_server_permissions, log_bulk_message_delete,
+    log_message_delete, log_message_edit, record_snipe, report_error, run_automod, run_slowmode,
+    spawn_cache_warmer, spawn_scheduler,
+};
 
 #[derive(Clone)]
 pub struct Events {
@@ -25,17 +31,37 @@ impl EventHandler for Events {
     type Error = Error;
 
     async fn message(&self, ctx: Context, message: Message) -> Result<(), Error> {
-        if message.user.as_ref().is_none_or(|user| user.bot.is_some()) {
+        if self.state.shutdown.is_stopping()
+            || message.user.as_ref().is_none_or(|user| user.bot.is_some())
+        {
             return Ok(());
         };
 
-        tokio::spawn({
-            let commands = self.commands.clone();
-            let ctx = ctx.clone();
-            let message = message.clone();
+        let blacklisted_server = match ctx.cache.get_channel(&message.channel) {
+            Some(channel) => match channel.server() {
+                Some(server_id) => self.state.is_blacklisted(server_id).await,
+                None => false,
+            },
+            None => false,
+        };
+
+        let blacklisted = blacklisted_server || self.state.is_blacklisted(&message.author).await;
+
+        if !blacklisted {
+            self.state.shutdown.spawn({
+                let commands = self.commands.clone();
+                let ctx = ctx.clone();
+                let message = message.clone();
 
-            async move { commands.process_commands(ctx, message).await }
-        });
+                let state = self.state.clone();
+
+                async move {
+                    if let Err(e) = commands.process_commands(ctx, message).await {
+                        report_error(&state, ErrorReport::new(format!("{e:?}")));
+                    };
+                }
+            });
+        };
 
         if !message.content.is_some() {
             return Ok(());
@@ -44,6 +70,10 @@ impl EventHandler for Events {
         if message.content.as_ref()
             == Some(&format!("<@{}>", ctx.cache.get_current_user_id().unwrap()))
         {
+            if blacklisted {
+                return Ok(());
+            };
+
             let mut message = message.clone();
             message.content.as_mut().unwrap().push_str(" help");
             self.commands.process_commands(ctx, message).await?;
@@ -59,6 +89,26 @@ impl EventHandler for Events {
 
         let server = ctx.cache.get_server(&server_id).unwrap();
 
+        match run_automod(&ctx, &self.state, &message, &server).await {
+            Ok(true) => return Ok(()),
+            Ok(false) => {}
+            Err(e) => {
+                report_error(&self.state, ErrorReport::new(format!("{e:?}")));
+            }
+        };
+
+        match run_slowmode(&ctx, &self.state, &message, &server).await {
+            Ok(true) => return Ok(()),
+            Ok(false) => {}
+            Err(e) => {
+                report_error(&self.state, ErrorReport::new(format!("{e:?}")));
+            }
+        };
+
+        if blacklisted {
+            return Ok(());
+        };
+
         let regexes = self.state.get_keywords(server.id.clone()).await?;
         let known_not_in_server = self
             .state
@@ -69,62 +119,30 @@ impl EventHandler for Events {
             .cloned()
             .unwrap_or_default();
 
-        for (user_id, (_, regex)) in regexes {
-            if known_not_in_server.contains(&user_id) || &user_id == &message.author {
+        let content = message.content.as_deref().unwrap_or_default();
+        let mut candidates = Vec::new();
+
+        for (user_id, (_, regex)) in &regexes {
+            if known_not_in_server.contains(user_id)
+                || user_id == &message.author
+                || !regex.is_match(content)
+                || self.state.is_blacklisted(user_id).await
+            {
                 continue;
             };
 
-            let permissions = {
-                let user = if let Some(user) = ctx.cache.get_user(&user_id) {
-                    user
-                } else if let Ok(user) = ctx.http.fetch_user(&user_id).await {
-                    ctx.cache.insert_user(user.clone());
-
-                    user
-                } else {
-                    self.state
-                        .known_not_in_server
-                        .write()
-                        .await
-                        .entry(server.id.clone())
-                        .or_default()
-                        .insert(user_id.clone());
-
-                    continue;
-                };
-
-                let member = if let Some(member) = ctx.cache.get_member(&server.id, &user_id) {
-                    member.clone()
-                } else if let Ok(member) = ctx.http.fetch_member(&server.id, &user_id).await {
-                    ctx.cache.insert_member(member.clone());
-
-                    member
-                } else {
-                    self.state
-                        .known_not_in_server
-                        .write()
-                        .await
-                        .entry(server.id.clone())
-                        .or_default()
-                        .insert(user_id.clone());
-
-                    continue;
-                };
-
-                let mut query =
-                    user_permissions_query(ctx.cache.clone(), ctx.http.clone(), Cow::Owned(user))
-                        .channel(Cow::Borrowed(&channel))
-                        .server(Cow::Borrowed(&server))
-                        .member(Cow::Borrowed(&member));
-
-                calculate_channel_permissions(&mut query).await
-            };
+            candidates.push(user_id.clone());
+        }
 
-            if !permissions.has(ChannelPermission::ViewChannel as u64) {
+        let visible =
+            filter_can_view_channel(&ctx, &self.state, &server, &channel, &candidates).await?;
+
+        for (user_id, (_, regex)) in regexes {
+            if !visible.contains(&user_id) {
                 continue;
             };
 
-            tokio::spawn({
+            self.state.shutdown.spawn({
                 let server = server.clone();
                 let channel = channel.clone();
                 let message = message.clone();
@@ -143,6 +161,7 @@ impl EventHandler for Events {
                             .unwrap()
                             .contains(&message.author)
                         {
+                            Metrics::increment(&state.metrics.highlights_suppressed);
                             return;
                         };
 
@@ -174,6 +193,7 @@ impl EventHandler for Events {
                         };
 
                         if should_cancel {
+                            Metrics::increment(&state.metrics.highlights_suppressed);
                             return;
                         };
 
@@ -230,22 +250,34 @@ impl EventHandler for Events {
 
                         let dm_channel = http.open_dm(&user_id).await.unwrap();
 
+                        let locale = state
+                            .resolve_locale(&user_id, Some(&server.id))
+                            .await
+                            .unwrap_or_default();
+
                         SendMessageBuilder::new(http.clone(), dm_channel.id().to_string())
-                            .content(format!(
-                                "In [{} › {}]({jump_link}), you where mentioned with **{keyword}**",
-                                &server.name,
-                                channel.name().unwrap()
+                            .content(locale.t(
+                                "highlight-notification",
+                                &[
+                                    ("server", server.name.as_str().into()),
+                                    ("channel", channel.name().unwrap().into()),
+                                    ("link", jump_link.as_str().into()),
+                                    ("keyword", (*keyword).into()),
+                                ],
                             ))
                             .embed(SendableEmbed {
                                 title: Some(keyword.to_string()),
                                 description: Some(format!(
-                                    "{built_messages}\n\n[Jump to]({jump_link})"
+                                    "{built_messages}\n\n[{}]({jump_link})",
+                                    locale.t("jump-to", &[])
                                 )),
                                 ..Default::default()
                             })
                             .build()
                             .await
                             .unwrap();
+
+                        Metrics::increment(&state.metrics.highlights_sent);
                     }
                 }
             });
@@ -254,230 +286,160 @@ impl EventHandler for Events {
         Ok(())
     }
 
-    async fn server_member_join(&self, _ctx: Context, member: Member) -> Result<(), Error> {
-        if let Some(set) = self
-            .state
-            .known_not_in_server
-            .write()
-            .await
-            .get_mut(&member.id.server)
-        {
-            set.remove(&member.id.user);
-        };
+    async fn server_member_join(&self, ctx: Context, member: Member) -> Result<(), Error> {
+        self.state
+            .mark_in_server(&member.id.server, std::slice::from_ref(&member.id.user))
+            .await?;
 
-        Ok(())
+        handle_member_join(&ctx, &self.state, &member).await
     }
 
     async fn server_member_leave(
         &self,
-        _ctx: Context,
+        ctx: Context,
         member: Member,
-        _reason: RemovalIntention,
+        reason: RemovalIntention,
     ) -> Result<(), Error> {
-        if let Some(set) = self
+        if self
             .state
-            .known_not_in_server
-            .write()
-            .await
-            .get_mut(&member.id.server)
+            .get_keywords(member.id.server.clone())
+            .await?
+            .contains_key(&member.id.user)
         {
-            set.insert(member.id.user);
+            self.state
+                .mark_not_in_server(&member.id.server, std::slice::from_ref(&member.id.user))
+                .await?;
         };
 
+        handle_member_leave(&ctx, &self.state, &member, &reason).await
+    }
+
+    async fn server_update(
+        &self,
+        _ctx: Context,
+        _before: Server,
+        after: Server,
+        _partial: PartialServer,
+        _clear: Vec<FieldsServer>,
+    ) -> Result<(), Error> {
+        invalidate_server_permissions(&self.state, &after.id).await;
+
+        Ok(())
+    }
+
+    async fn channel_update(
+        &self,
+        _ctx: Context,
+        _before: Channel,
+        after: Channel,
+        _partial: PartialChannel,
+        _clear: Vec<FieldsChannel>,
+    ) -> Result<(), Error> {
+        invalidate_channel_permissions(&self.state, after.id()).await;
+
+        Ok(())
+    }
+
+    async fn channel_delete(&self, _ctx: Context, channel: Channel) -> Result<(), Error> {
+        invalidate_channel_permissions(&self.state, channel.id()).await;
+
+        Ok(())
+    }
+
+    async fn server_role_update(
+        &self,
+        _ctx: Context,
+        server_id: String,
+        _before: Role,
+        _after: Role,
+        _partial: PartialRole,
+        _clear: Vec<FieldsRole>,
+    ) -> Result<(), Error> {
+        invalidate_server_permissions(&self.state, &server_id).await;
+
         Ok(())
     }
 
+    async fn server_role_delete(
+        &self,
+        _ctx: Context,
+        server_id: String,
+        _role: Role,
+    ) -> Result<(), Error> {
+        invalidate_server_permissions(&self.state, &server_id).await;
+
+        Ok(())
+    }
+
+    async fn server_role_ranks_update(
+        &self,
+        _ctx: Context,
+        server_id: String,
+        _before: Vec<Role>,
+        _after: Vec<Role>,
+    ) -> Result<(), Error> {
+        invalidate_server_permissions(&self.state, &server_id).await;
+
+        Ok(())
+    }
+
+    async fn message_update(
+        &self,
+        ctx: Context,
+        before: Message,
+        after: Message,
+        _partial: PartialMessage,
+        _clear: Vec<FieldsMessage>,
+    ) -> Result<(), Error> {
+        log_message_edit(&ctx, &self.state, &before, &after).await
+    }
+
+    async fn message_delete(&self, ctx: Context, message: Message) -> Result<(), Error> {
+        if ctx.cache.get_current_user_id().as_ref() != Some(&message.author) {
+            record_snipe(&self.state, &message).await;
+        };
+
+        log_message_delete(&ctx, &self.state, &message).await
+    }
+
+    async fn bulk_message_delete(
+        &self,
+        ctx: Context,
+        channel_id: String,
+        message_ids: Vec<String>,
+        found: Vec<Message>,
+    ) -> Result<(), Error> {
+        log_bulk_message_delete(&ctx, &self.state, &channel_id, &message_ids, &found).await
+    }
+
     async fn event(&self, ctx: Context, event: EventV1) -> Result<(), Self::Error> {
-        match event {
+        Metrics::increment(&self.state.metrics.events_received);
+
+        let (channel_id, message_id, user_id, emoji_id, added) = match event {
             EventV1::MessageReact {
-                id: message_id,
+                id,
                 channel_id,
-                user_id: _,
+                user_id,
                 emoji_id,
-            } => {
-                if emoji_id != "⭐" {
-                    return Ok(());
-                };
-
-                let channel = ctx.cache.get_channel(&channel_id).unwrap();
-
-                let Some(server_id) = channel.server() else {
-                    return Ok(());
-                };
-                let config = self.state.fetch_server_config(&server_id).await?;
-
-                let Some(starboard_channel_id) = &config.starboard_channel else {
-                    return Ok(());
-                };
-
-                let (original_message, starboard_message) = if &channel_id == starboard_channel_id {
-                    let original_message = self
-                        .state
-                        .get_starboard_original_message(&message_id)
-                        .await?;
-
-                    (
-                        ctx.http
-                            .fetch_message(&original_message.channel, &original_message.id)
-                            .await?,
-                        Some(
-                            ctx.http
-                                .fetch_message(&starboard_channel_id, &message_id)
-                                .await?,
-                        ),
-                    )
-                } else {
-                    (
-                        ctx.http.fetch_message(&channel_id, &message_id).await?,
-                        if let Some(starboard_message_id) =
-                            self.state.get_starboard_message(&message_id).await?
-                        {
-                            Some(
-                                ctx.http
-                                    .fetch_message(&starboard_channel_id, &starboard_message_id)
-                                    .await?,
-                            )
-                        } else {
-                            None
-                        },
-                    )
-                };
-
-                let star_count = get_star_count(&original_message, starboard_message.as_ref());
-
-                if let Some(starboard_message) = &starboard_message {
-                    self.state
-                        .update_starboard_message_star_count(&starboard_message.id, star_count)
-                        .await?;
-
-                    let content = starboard_message.content.clone().unwrap();
-                    let mut parts = content.split(' ').collect::<Vec<_>>();
-                    let star_count = star_count.to_string();
-                    parts[1] = &star_count;
-
-                    starboard_message
-                        .edit(&ctx)
-                        .content(parts.join(" "))
-                        .build()
-                        .await?;
-                } else if star_count >= config.star_count {
-                    let content = format!(
-                        "⭐ {} {} ID: {}",
-                        star_count,
-                        channel.mention(),
-                        channel.id()
-                    );
-
-                    let author = ctx.fetch_user(&original_message.author).await?;
-
-                    let starboard_message =
-                        SendMessageBuilder::new(ctx.http.clone(), starboard_channel_id.clone())
-                            .content(content)
-                            .embed(
-                                SendableEmbed::default()
-                                    .icon_url(author.avatar_url(&ctx))
-                                    .title(author.name().to_string())
-                                    .description(format!(
-                                        "{}\n\nOriginal: [Jump!]({})",
-                                        original_message.content.clone().unwrap_or_default(),
-                                        original_message.jump_link(&ctx)
-                                    ))
-                                    .colour("#FFC71E".to_string()),
-                            )
-                            .interactions(Interactions::default().reactions(["⭐".to_string()]))
-                            .build()
-                            .await?;
-
-                    self.state
-                        .add_starboard_message(
-                            &starboard_message.id,
-                            &original_message.id,
-                            &original_message.author,
-                            &original_message.channel,
-                            &server_id,
-                            star_count,
-                        )
-                        .await?;
-                }
-            }
+            } => (channel_id, id, user_id, emoji_id, true),
             EventV1::MessageUnreact {
-                id: message_id,
+                id,
                 channel_id,
-                user_id: _,
+                user_id,
                 emoji_id,
-            } => {
-                if emoji_id != "⭐" {
-                    return Ok(());
-                };
-
-                let channel = ctx.cache.get_channel(&channel_id).unwrap();
-
-                let Some(server_id) = channel.server() else {
-                    return Ok(());
-                };
-                let config = self.state.fetch_server_config(&server_id).await?;
-
-                let Some(starboard_channel_id) = &config.starboard_channel else {
-                    return Ok(());
-                };
-
-                let (original_message, starboard_message) = if &channel_id == starboard_channel_id {
-                    let original_message = self
-                        .state
-                        .get_starboard_original_message(&message_id)
-                        .await?;
-
-                    (
-                        ctx.http
-                            .fetch_message(&original_message.channel, &original_message.id)
-                            .await?,
-                        Some(
-                            ctx.http
-                                .fetch_message(&starboard_channel_id, &message_id)
-                                .await?,
-                        ),
-                    )
-                } else {
-                    (
-                        ctx.http.fetch_message(&channel_id, &message_id).await?,
-                        if let Some(starboard_message_id) =
-                            self.state.get_starboard_message(&message_id).await?
-                        {
-                            Some(
-                                ctx.http
-                                    .fetch_message(&starboard_channel_id, &starboard_message_id)
-                                    .await?,
-                            )
-                        } else {
-                            None
-                        },
-                    )
-                };
-
-                if let Some(starboard_message) = starboard_message {
-                    let star_count = get_star_count(&original_message, Some(&starboard_message));
-
-                    if star_count < config.star_count {
-                        starboard_message.delete(&ctx).await?;
-
-                        self.state
-                            .remove_starboard_message(&starboard_message.id)
-                            .await?;
-                    } else {
-                        let content = starboard_message.content.clone().unwrap();
-                        let mut parts = content.split(' ').collect::<Vec<_>>();
-                        let star_count = star_count.to_string();
-                        parts[1] = &star_count;
-
-                        starboard_message
-                            .edit(&ctx)
-                            .content(parts.join(" "))
-                            .build()
-                            .await?;
-                    }
-                }
-            }
-            _ => {}
+            } => (channel_id, id, user_id, emoji_id, false),
+            _ => return Ok(()),
+        };
+
+        if let Err(e) =
+            handle_reaction_role(&ctx, &self.state, &message_id, &user_id, &emoji_id, added).await
+        {
+            report_error(&self.state, ErrorReport::new(format!("{e:?}")));
+        };
+
+        if emoji_id == "⭐" {
+            self.update_starboard(&ctx, &channel_id, &message_id)
+                .await?;
         };
 
         Ok(())
@@ -486,15 +448,22 @@ impl EventHandler for Events {
     async fn ready(&self, ctx: Context) -> Result<(), Error> {
         log::info!("Ready!");
 
+        self.state.metrics.ready.store(true, Ordering::Relaxed);
+
+        self.state.error_reporter.set_http(ctx.http.clone());
+
+        if self.state.cluster.as_ref().is_none_or(Cluster::is_primary) {
+            spawn_scheduler(ctx.http.clone(), self.state.clone());
+        };
+
+        spawn_cache_warmer(ctx.clone(), self.state.clone());
+
         ctx.http
             .edit_user(
                 "@me",
                 &DataEditUser {
                     status: Some(UserStatus {
-                        text: Some(format!(
-                            "{}help",
-                            self.state.config.bot.prefix.first().unwrap()
-                        )),
+                        text: Some(self.state.status_text().await),
                         presence: None,
                     }),
                     display_name: None,
@@ -509,6 +478,143 @@ impl EventHandler for Events {
 
         Ok(())
     }
+
+    async fn error(&self, _ctx: Context, error: Error) {
+        report_error(&self.state, ErrorReport::new(format!("{error:?}")));
+    }
+}
+
+impl Events {
+    async fn update_starboard(
+        &self,
+        ctx: &Context,
+        channel_id: &str,
+        message_id: &str,
+    ) -> Result<(), Error> {
+        let channel = ctx.cache.get_channel(channel_id).unwrap();
+
+        let Some(server_id) = channel.server() else {
+            return Ok(());
+        };
+        let config = self.state.fetch_server_config(server_id).await?;
+
+        let Some(starboard_channel_id) = &config.starboard_channel else {
+            return Ok(());
+        };
+
+        let original_message = if channel_id == starboard_channel_id {
+            let Some(original_message) = self
+                .state
+                .get_starboard_original_message(message_id)
+                .await?
+            else {
+                return Ok(());
+            };
+
+            original_message
+        } else {
+            OriginalMessage {
+                id: message_id.to_string(),
+                channel: channel_id.to_string(),
+            }
+        };
+
+        // reactions are handled concurrently, so serialise per original message to avoid posting duplicates
+        let _guard = self.state.starboard_locks.lock(&original_message.id).await;
+
+        let original_message = ctx
+            .http
+            .fetch_message(&original_message.channel, &original_message.id)
+            .await?;
+
+        let starboard_message = if let Some(starboard_message_id) = self
+            .state
+            .get_starboard_message(&original_message.id)
+            .await?
+        {
+            Some(
+                ctx.http
+                    .fetch_message(starboard_channel_id, &starboard_message_id)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        let star_count = get_star_count(&original_message, starboard_message.as_ref());
+
+        if let Some(starboard_message) = &starboard_message {
+            if star_count < config.star_count {
+                starboard_message.delete(ctx).await?;
+
+                self.state
+                    .remove_starboard_message(&starboard_message.id)
+                    .await?;
+            } else {
+                self.state
+                    .update_starboard_message_star_count(&starboard_message.id, star_count)
+                    .await?;
+
+                let content = starboard_message.content.clone().unwrap();
+                let mut parts = content.split(' ').collect::<Vec<_>>();
+                let star_count = star_count.to_string();
+                parts[1] = &star_count;
+
+                starboard_message
+                    .edit(ctx)
+                    .content(parts.join(" "))
+                    .build()
+                    .await?;
+            }
+        } else if star_count >= config.star_count {
+            let original_channel = ctx.cache.get_channel(&original_message.channel).unwrap();
+
+            let content = format!(
+                "⭐ {} {} ID: {}",
+                star_count,
+                original_channel.mention(),
+                original_channel.id()
+            );
+
+            let author = ctx.fetch_user(&original_message.author).await?;
+
+            let starboard_message =
+                SendMessageBuilder::new(ctx.http.clone(), starboard_channel_id.clone())
+                    .content(content)
+                    .embed(
+                        SendableEmbed::default()
+                            .icon_url(author.avatar_url(ctx))
+                            .title(author.name().to_string())
+                            .description(format!(
+                                "{}\n\nOriginal: [Jump!]({})",
+                                original_message.content.clone().unwrap_or_default(),
+                                original_message.jump_link(ctx)
+                            ))
+                            .colour("#FFC71E".to_string()),
+                    )
+                    .interactions(Interactions::default().reactions(["⭐".to_string()]))
+                    .build()
+                    .await?;
+
+            let existing_starboard_message_id = self
+                .state
+                .add_starboard_message(
+                    &starboard_message.id,
+                    &original_message.id,
+                    &original_message.author,
+                    &original_message.channel,
+                    server_id,
+                    star_count,
+                )
+                .await?;
+
+            if existing_starboard_message_id != starboard_message.id {
+                starboard_message.delete(ctx).await?;
+            };
+        };
+
+        Ok(())
+    }
 }
 
 fn get_star_count(original: &Message, starboard: Option<&Message>) -> i32 {
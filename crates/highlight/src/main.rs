@@ -1,4 +1,8 @@
-use stoat::{CacheConfig, Client, commands::CommandHandler};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use stoat::{CacheConfig, Client, EventHandler, commands::CommandHandler, types::EventV1};
+use tokio::sync::mpsc::{UnboundedReceiver, unbounded_channel};
 
 mod commands;
 mod events;
@@ -10,21 +14,156 @@ pub use utils::*;
 async fn main() -> Result<(), Error> {
     pretty_env_logger::init();
 
-    let state = State::new().await;
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Invalid configuration: {e}");
+            std::process::exit(1);
+        }
+    };
+
+    let state = State::new(config).await?;
 
     state.ensure_db().await;
+    state.load_blacklist().await?;
+    state.load_membership_hints(MEMBERSHIP_HINT_TTL).await?;
+
+    spawn_invalidation_listener(state.clone()).await?;
+
+    spawn_config_watcher(state.clone());
+    install_panic_reporter(state.clone());
+
+    let res = match state.cluster.clone() {
+        None => {
+            let mut client = Client::new_with_api_url(
+                build_events(&state),
+                CacheConfig::default(),
+                &state.config().stoat.api,
+            )
+            .await?
+            .spawner(tracked_spawner(&state));
+
+            spawn_metrics_server(state.clone(), client.state.clone(), client.http.clone());
+
+            let res = client.run(&state.config().bot.token).await;
+
+            drain(&state, &mut client).await;
+
+            res
+        }
+        Some(cluster) if cluster.config.role == ClusterRole::Gateway => {
+            if cluster.config.bus == BusKind::Local {
+                for worker_id in 0..cluster.config.workers {
+                    let (mut client, receiver) = create_worker(&state, &cluster, worker_id).await?;
+                    let token = state.config().bot.token.clone();
+
+                    // the gateway drains the shared shutdown tracker, local workers only stop taking events
+                    tokio::spawn(async move {
+                        if let Err(e) = client.consume(token, receiver).await {
+                            log::error!("Worker {worker_id} stopped: {e:?}");
+                        };
+                    });
+                }
+            };
+
+            log::info!(
+                "Starting gateway for {} workers over the {:?} bus.",
+                cluster.config.workers,
+                cluster.config.bus
+            );
+
+            let mut client = Client::new_with_api_url(
+                GatewayEvents { cluster },
+                CacheConfig::default(),
+                &state.config().stoat.api,
+            )
+            .await?;
+
+            spawn_metrics_server(state.clone(), client.state.clone(), client.http.clone());
+
+            let res = client.run(&state.config().bot.token).await;
+
+            drain(&state, &mut client).await;
+
+            res
+        }
+        Some(cluster) => {
+            let worker_id = cluster.config.worker_id;
+
+            log::info!("Starting worker {worker_id} of {}.", cluster.config.workers);
+
+            let (mut client, receiver) = create_worker(&state, &cluster, worker_id).await?;
+
+            spawn_metrics_server(state.clone(), client.state.clone(), client.http.clone());
 
+            let res = client.consume(&state.config().bot.token, receiver).await;
+
+            drain(&state, &mut client).await;
+
+            res
+        }
+    };
+
+    state.storage.close().await;
+
+    res
+}
+
+async fn drain<H: EventHandler + Clone + Send + Sync + 'static>(
+    state: &State,
+    client: &mut Client<H>,
+) {
+    log::info!("Shutting down.");
+
+    state
+        .shutdown
+        .drain(Duration::from_secs(state.config().bot.shutdown_timeout))
+        .await;
+
+    client.cleanup().await;
+}
+
+fn tracked_spawner(state: &State) -> impl Fn(BoxFuture<'static, ()>) + Send + Sync + 'static {
+    let shutdown = state.shutdown.clone();
+
+    move |fut| shutdown.spawn(fut)
+}
+
+fn build_events(state: &State) -> events::Events {
     let commands = CommandHandler::new(commands::CommandEvents, state.clone())
         .help_command(Some(HighlightHelpCommand))
+        .check(CommandPolicyCheck)
         .register(commands::commands());
 
-    let events = events::Events {
+    events::Events {
         commands,
         state: state.clone(),
-    };
+    }
+}
+
+async fn create_worker(
+    state: &State,
+    cluster: &Cluster,
+    worker_id: usize,
+) -> Result<(Client<events::Events>, UnboundedReceiver<EventV1>), Error> {
+    let mut messages = cluster.bus.subscribe(worker_id).await?;
+    let (sender, receiver) = unbounded_channel();
+
+    tokio::spawn(async move {
+        while let Some(BusMessage::Event(event)) = messages.recv().await {
+            if sender.send(*event).is_err() {
+                return;
+            };
+        }
+    });
+
+    let client = Client::new_with_api_url(
+        build_events(state),
+        CacheConfig::default(),
+        &state.config().stoat.api,
+    )
+    .await?
+    .spawner(tracked_spawner(state));
 
-    Client::new_with_api_url(events, CacheConfig::default(), &state.config.stoat.api)
-        .await?
-        .run(&state.config.bot.token)
-        .await
+    Ok((client, receiver))
 }
@@ -0,0 +1,421 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt,
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use regex::Regex;
+use stoat::{
+    Context,
+    types::{Invite, Message, Server},
+};
+
+use crate::{
+    AutomodRule, CaseAction, Error, Locale, NewModCase, Result, State, apply_mod_action,
+    is_not_found, warn_member,
+};
+
+static INVITE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?:https?://)?(?:stt\.gg|rvlt\.gg|(?:app\.)?(?:stoat\.chat|revolt\.chat)/invite)/([a-z0-9]+)",
+    )
+    .unwrap()
+});
+
+static CUSTOM_EMOJI_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r":[0123456789ABCDEFGHJKMNPQRSTVWXYZ]{26}:").unwrap());
+
+const SPAM_WINDOW: Duration = Duration::from_secs(15);
+const MIN_CAPS_LENGTH: usize = 10;
+const MAX_INVITE_LOOKUPS: usize = 3;
+const INVITE_CACHE_TTL: Duration = Duration::from_secs(60 * 10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AutomodRuleKind {
+    Spam,
+    Mentions,
+    Caps,
+    Emoji,
+    Invites,
+    Phrases,
+}
+
+impl AutomodRuleKind {
+    pub const ALL: [AutomodRuleKind; 6] = [
+        AutomodRuleKind::Spam,
+        AutomodRuleKind::Mentions,
+        AutomodRuleKind::Caps,
+        AutomodRuleKind::Emoji,
+        AutomodRuleKind::Invites,
+        AutomodRuleKind::Phrases,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutomodRuleKind::Spam => "spam",
+            AutomodRuleKind::Mentions => "mentions",
+            AutomodRuleKind::Caps => "caps",
+            AutomodRuleKind::Emoji => "emoji",
+            AutomodRuleKind::Invites => "invites",
+            AutomodRuleKind::Phrases => "phrases",
+        }
+    }
+
+    pub fn default_threshold(&self) -> Option<i32> {
+        match self {
+            AutomodRuleKind::Spam => Some(3),
+            AutomodRuleKind::Mentions => Some(5),
+            AutomodRuleKind::Caps => Some(70),
+            AutomodRuleKind::Emoji => Some(10),
+            AutomodRuleKind::Invites | AutomodRuleKind::Phrases => None,
+        }
+    }
+
+    pub fn parse(locale: &Locale, value: &str) -> Result<Self> {
+        AutomodRuleKind::ALL
+            .into_iter()
+            .find(|rule| rule.as_str() == value)
+            .ok_or_else(|| {
+                Error::UserError(
+                    locale.t(
+                        "automod-unknown-rule",
+                        &[
+                            ("rule", value.into()),
+                            (
+                                "rules",
+                                AutomodRuleKind::ALL
+                                    .iter()
+                                    .map(|rule| format!("`{rule}`"))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                                    .into(),
+                            ),
+                        ],
+                    ),
+                )
+            })
+    }
+
+    pub fn describe(&self, locale: &Locale, threshold: Option<i32>) -> String {
+        let threshold = threshold.or(self.default_threshold()).unwrap_or_default();
+
+        locale.t(
+            &format!("automod-rule-{}", self.as_str()),
+            &[
+                ("threshold", threshold.into()),
+                ("window", locale.format_duration(SPAM_WINDOW).into()),
+            ],
+        )
+    }
+}
+
+impl fmt::Display for AutomodRuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<String> for AutomodRuleKind {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&Locale::default(), &value)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutomodAction {
+    Delete,
+    Warn,
+    Timeout,
+}
+
+impl AutomodAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AutomodAction::Delete => "delete",
+            AutomodAction::Warn => "warn",
+            AutomodAction::Timeout => "timeout",
+        }
+    }
+
+    pub fn parse(locale: &Locale, value: &str) -> Result<Self> {
+        match value {
+            "delete" => Ok(AutomodAction::Delete),
+            "warn" => Ok(AutomodAction::Warn),
+            "timeout" => Ok(AutomodAction::Timeout),
+            _ => Err(Error::UserError(
+                locale.t("automod-unknown-action", &[("action", value.into())]),
+            )),
+        }
+    }
+}
+
+impl TryFrom<String> for AutomodAction {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&Locale::default(), &value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AutomodActions(pub Vec<AutomodAction>);
+
+impl TryFrom<Vec<String>> for AutomodActions {
+    type Error = Error;
+
+    fn try_from(value: Vec<String>) -> Result<Self> {
+        value
+            .into_iter()
+            .map(AutomodAction::try_from)
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AutomodConfig {
+    pub rules: Vec<AutomodRule>,
+    pub exemptions: HashSet<String>,
+    pub phrases: Vec<String>,
+}
+
+pub type AutomodHistory = LruCache<(String, String), VecDeque<(Instant, String)>>;
+
+// invite code to the server it points at, none for group or unknown invites
+pub type InviteCache = LruCache<String, (Instant, Option<String>)>;
+
+async fn is_duplicate_spam(
+    state: &State,
+    message: &Message,
+    server_id: &str,
+    threshold: i32,
+) -> bool {
+    let Some(content) = message.content.clone() else {
+        return false;
+    };
+
+    let mut history = state.automod_history.lock().await;
+
+    let entries = history.get_or_insert_mut(
+        (server_id.to_string(), message.author.clone()),
+        VecDeque::new,
+    );
+
+    let now = Instant::now();
+
+    while entries
+        .front()
+        .is_some_and(|(sent, _)| now.duration_since(*sent) > SPAM_WINDOW)
+    {
+        entries.pop_front();
+    }
+
+    entries.push_back((now, content.to_lowercase()));
+
+    let duplicates = entries
+        .iter()
+        .filter(|(_, previous)| previous == &content.to_lowercase())
+        .count();
+
+    duplicates >= threshold.max(2) as usize
+}
+
+fn count_emojis(content: &str) -> usize {
+    let unicode = content
+        .chars()
+        .filter(|c| matches!(*c as u32, 0x1F000..=0x1FAFF | 0x2600..=0x27BF))
+        .count();
+
+    unicode + CUSTOM_EMOJI_REGEX.find_iter(content).count()
+}
+
+// transient failures aren't cached so the next message retries them
+async fn resolve_invite(ctx: &Context, state: &State, code: &str) -> Option<String> {
+    let server = match ctx.http.fetch_invite(code).await {
+        Ok(Invite::Server { server, .. }) => Some(server),
+        Ok(_) => None,
+        Err(e) if is_not_found(&e) => None,
+        Err(e) => {
+            log::warn!("Failed to resolve invite {code}: {e:?}");
+
+            return None;
+        }
+    };
+
+    state
+        .invite_cache
+        .lock()
+        .await
+        .put(code.to_string(), (Instant::now(), server.clone()));
+
+    server
+}
+
+async fn has_external_invite(ctx: &Context, state: &State, content: &str, server_id: &str) -> bool {
+    let codes = INVITE_REGEX
+        .captures_iter(content)
+        .map(|captures| captures.get(1).unwrap().as_str().to_string())
+        .collect::<HashSet<_>>();
+
+    let mut lookups = 0;
+
+    for code in codes {
+        let cached = state
+            .invite_cache
+            .lock()
+            .await
+            .get(&code)
+            .filter(|(resolved, _)| resolved.elapsed() < INVITE_CACHE_TTL)
+            .map(|(_, server)| server.clone());
+
+        let server = match cached {
+            Some(server) => server,
+            // a message full of invites shouldn't turn into a burst of api calls
+            None if lookups >= MAX_INVITE_LOOKUPS => continue,
+            None => {
+                lookups += 1;
+
+                resolve_invite(ctx, state, &code).await
+            }
+        };
+
+        if server.is_some_and(|server| server != server_id) {
+            return true;
+        };
+    }
+
+    false
+}
+
+async fn find_violation(
+    ctx: &Context,
+    state: &State,
+    config: &AutomodConfig,
+    message: &Message,
+    server_id: &str,
+) -> Option<AutomodRule> {
+    let content = message.content.as_deref().unwrap_or_default();
+
+    for rule in &config.rules {
+        let threshold = rule
+            .threshold
+            .or(rule.rule.default_threshold())
+            .unwrap_or_default();
+
+        let violated = match rule.rule {
+            AutomodRuleKind::Spam => is_duplicate_spam(state, message, server_id, threshold).await,
+            AutomodRuleKind::Mentions => {
+                let mentions = message.mentions.as_ref().map_or(0, |m| m.len())
+                    + message.role_mentions.as_ref().map_or(0, |m| m.len());
+
+                mentions >= threshold as usize
+            }
+            AutomodRuleKind::Caps => {
+                let letters = content
+                    .chars()
+                    .filter(|c| c.is_alphabetic())
+                    .collect::<Vec<_>>();
+
+                letters.len() >= MIN_CAPS_LENGTH
+                    && letters.iter().filter(|c| c.is_uppercase()).count() * 100
+                        >= letters.len() * threshold as usize
+            }
+            AutomodRuleKind::Emoji => count_emojis(content) >= threshold as usize,
+            AutomodRuleKind::Invites => has_external_invite(ctx, state, content, server_id).await,
+            AutomodRuleKind::Phrases => {
+                let content = content.to_lowercase();
+
+                config.phrases.iter().any(|phrase| content.contains(phrase))
+            }
+        };
+
+        if violated {
+            return Some(rule.clone());
+        };
+    }
+
+    None
+}
+
+pub async fn run_automod(
+    ctx: &Context,
+    state: &State,
+    message: &Message,
+    server: &Server,
+) -> Result<bool> {
+    let config = state.get_automod_config(&server.id).await?;
+
+    if config.rules.is_empty()
+        || message.author == server.owner
+        || config.exemptions.contains(&message.channel)
+    {
+        return Ok(false);
+    };
+
+    let roles = message
+        .member
+        .clone()
+        .or_else(|| ctx.cache.get_member(&server.id, &message.author))
+        .map(|member| member.roles)
+        .unwrap_or_default();
+
+    if roles.iter().any(|role| config.exemptions.contains(role)) {
+        return Ok(false);
+    };
+
+    let Some(rule) = find_violation(ctx, state, &config, message, &server.id).await else {
+        return Ok(false);
+    };
+
+    let moderator_id = ctx.cache.get_current_user_id().unwrap_or_default();
+    // case reasons are stored once for the whole server, so use the server's locale
+    let locale = state.server_locale(&server.id).await?;
+    let reason = locale.t(
+        "automod-reason",
+        &[("rule", rule.rule.describe(&locale, rule.threshold).into())],
+    );
+
+    for action in &rule.actions.0 {
+        match action {
+            AutomodAction::Delete => {
+                ctx.http
+                    .delete_message(&message.channel, &message.id)
+                    .await?;
+            }
+            AutomodAction::Warn => {
+                warn_member(
+                    &ctx.http,
+                    state,
+                    &server.id,
+                    &message.author,
+                    &moderator_id,
+                    Some(reason.clone()),
+                )
+                .await?;
+            }
+            AutomodAction::Timeout => {
+                apply_mod_action(
+                    &ctx.http,
+                    state,
+                    NewModCase {
+                        server_id: server.id.clone(),
+                        action: CaseAction::Timeout,
+                        target_id: Some(message.author.clone()),
+                        moderator_id: moderator_id.clone(),
+                        reason: Some(reason.clone()),
+                        duration: Some(Duration::from_secs(
+                            rule.timeout_duration.unwrap_or(300) as u64
+                        )),
+                    },
+                )
+                .await?;
+            }
+        };
+    }
+
+    Ok(rule.actions.0.contains(&AutomodAction::Delete))
+}
@@ -0,0 +1,212 @@
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{PgPool, postgres::PgListener};
+use stoat::{async_trait, types::EventV1};
+use tokio::sync::{
+    Mutex,
+    mpsc::{UnboundedReceiver, UnboundedSender, unbounded_channel},
+};
+
+use crate::{BusKind, Result};
+
+const DRAIN_BATCH: i64 = 500;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum BusMessage {
+    Event(Box<EventV1>),
+}
+
+#[async_trait]
+pub trait EventBus: Debug + Send + Sync {
+    async fn publish(&self, worker: usize, message: &BusMessage) -> Result<()>;
+    async fn subscribe(&self, worker: usize) -> Result<UnboundedReceiver<BusMessage>>;
+}
+
+#[derive(Debug, Default)]
+pub struct LocalBus {
+    subscribers: Mutex<HashMap<usize, Vec<UnboundedSender<BusMessage>>>>,
+}
+
+#[async_trait]
+impl EventBus for LocalBus {
+    async fn publish(&self, worker: usize, message: &BusMessage) -> Result<()> {
+        if let Some(senders) = self.subscribers.lock().await.get_mut(&worker) {
+            senders.retain(|sender| sender.send(message.clone()).is_ok());
+        };
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, worker: usize) -> Result<UnboundedReceiver<BusMessage>> {
+        let (sender, receiver) = unbounded_channel();
+
+        self.subscribers
+            .lock()
+            .await
+            .entry(worker)
+            .or_default()
+            .push(sender);
+
+        Ok(receiver)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostgresBus {
+    pool: PgPool,
+}
+
+impl PostgresBus {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    fn channel(worker: usize) -> String {
+        format!("highlight_bus_{worker}")
+    }
+
+    async fn drain(&self, worker: usize, sender: &UnboundedSender<BusMessage>) -> Result<()> {
+        loop {
+            let mut rows = sqlx::query_as::<_, (i64, String)>(
+                "delete from bus_messages where id in (select id from bus_messages where worker=$1 order by id limit $2 for update skip locked) returning id, payload",
+            )
+            .bind(worker as i32)
+            .bind(DRAIN_BATCH)
+            .fetch_all(&self.pool)
+            .await?;
+
+            rows.sort_by_key(|(id, _)| *id);
+
+            let count = rows.len() as i64;
+
+            for (id, payload) in rows {
+                match serde_json::from_str(&payload) {
+                    Ok(message) => {
+                        if sender.send(message).is_err() {
+                            return Ok(());
+                        };
+                    }
+                    Err(e) => log::error!("Dropping undecodable bus message {id}: {e}"),
+                };
+            }
+
+            if count < DRAIN_BATCH {
+                return Ok(());
+            };
+        }
+    }
+}
+
+#[async_trait]
+impl EventBus for PostgresBus {
+    async fn publish(&self, worker: usize, message: &BusMessage) -> Result<()> {
+        let payload = serde_json::to_string(message)?;
+
+        sqlx::query("with inserted as (insert into bus_messages (worker, payload) values ($1, $2) returning id) select pg_notify($3, id::text) from inserted")
+            .bind(worker as i32)
+            .bind(payload)
+            .bind(Self::channel(worker))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, worker: usize) -> Result<UnboundedReceiver<BusMessage>> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(&Self::channel(worker)).await?;
+
+        let (sender, receiver) = unbounded_channel();
+        let bus = self.clone();
+
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = bus.drain(worker, &sender).await {
+                    log::error!("Failed to drain bus messages: {e:?}");
+                };
+
+                if sender.is_closed() {
+                    return;
+                };
+
+                if let Err(e) = listener.recv().await {
+                    log::error!("Bus listener error: {e:?}");
+                };
+            }
+        });
+
+        Ok(receiver)
+    }
+}
+
+pub fn create_bus(kind: BusKind, pool: Option<&PgPool>) -> Arc<dyn EventBus> {
+    match (kind, pool) {
+        (BusKind::Postgres, Some(pool)) => Arc::new(PostgresBus::new(pool.clone())),
+        _ => Arc::new(LocalBus::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn typing(user: &str) -> BusMessage {
+        BusMessage::Event(Box::new(
+            serde_json::from_value(
+                json!({ "type": "ChannelStartTyping", "id": "channel", "user": user }),
+            )
+            .unwrap(),
+        ))
+    }
+
+    fn typing_user(message: BusMessage) -> String {
+        match message {
+            BusMessage::Event(event) => match *event {
+                EventV1::ChannelStartTyping { user, .. } => user,
+                event => panic!("unexpected event: {event:?}"),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn local_bus_delivers_in_order() {
+        let bus = LocalBus::default();
+        let mut receiver = bus.subscribe(1).await.unwrap();
+
+        for i in 0..100 {
+            bus.publish(1, &typing(&i.to_string())).await.unwrap();
+        }
+
+        for i in 0..100 {
+            assert_eq!(typing_user(receiver.recv().await.unwrap()), i.to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn local_bus_only_delivers_to_the_target_worker() {
+        let bus = LocalBus::default();
+        let mut first = bus.subscribe(0).await.unwrap();
+        let mut second = bus.subscribe(1).await.unwrap();
+
+        bus.publish(1, &typing("user")).await.unwrap();
+
+        assert_eq!(typing_user(second.recv().await.unwrap()), "user");
+        assert!(first.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn local_bus_drops_closed_subscribers() {
+        let bus = LocalBus::default();
+        drop(bus.subscribe(0).await.unwrap());
+        let mut receiver = bus.subscribe(0).await.unwrap();
+
+        bus.publish(0, &typing("user")).await.unwrap();
+
+        assert_eq!(typing_user(receiver.recv().await.unwrap()), "user");
+        assert_eq!(bus.subscribers.lock().await[&0].len(), 1);
+    }
+}
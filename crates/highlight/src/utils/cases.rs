@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use stoat::{
+    EmbedExt, HttpClient,
+    builders::{EditMessageBuilder, SendMessageBuilder},
+    types::SendableEmbed,
+};
+
+use crate::{Error, Locale, ModCase, Result, State};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseAction {
+    Ban,
+    Kick,
+    Purge,
+    Softban,
+    Tempban,
+    Timeout,
+    Unban,
+    Untimeout,
+    Warn,
+}
+
+impl CaseAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CaseAction::Ban => "ban",
+            CaseAction::Kick => "kick",
+            CaseAction::Purge => "purge",
+            CaseAction::Softban => "softban",
+            CaseAction::Tempban => "tempban",
+            CaseAction::Timeout => "timeout",
+            CaseAction::Unban => "unban",
+            CaseAction::Untimeout => "untimeout",
+            CaseAction::Warn => "warn",
+        }
+    }
+
+    pub fn parse(locale: &Locale, value: &str) -> Result<Self> {
+        match value {
+            "ban" => Ok(CaseAction::Ban),
+            "kick" => Ok(CaseAction::Kick),
+            "purge" => Ok(CaseAction::Purge),
+            "softban" => Ok(CaseAction::Softban),
+            "tempban" => Ok(CaseAction::Tempban),
+            "timeout" => Ok(CaseAction::Timeout),
+            "unban" => Ok(CaseAction::Unban),
+            "untimeout" => Ok(CaseAction::Untimeout),
+            "warn" => Ok(CaseAction::Warn),
+            _ => Err(Error::UserError(
+                locale.t("case-unknown-action", &[("action", value.into())]),
+            )),
+        }
+    }
+
+    pub fn name(&self, locale: &Locale) -> String {
+        locale.t(&format!("case-action-{}", self.as_str()), &[])
+    }
+
+    pub fn colour(&self) -> &'static str {
+        match self {
+            CaseAction::Ban | CaseAction::Tempban => "#E74C3C",
+            CaseAction::Kick | CaseAction::Softban => "#E67E22",
+            CaseAction::Purge => "#3498DB",
+            CaseAction::Timeout | CaseAction::Warn => "#F1C40F",
+            CaseAction::Unban | CaseAction::Untimeout => "#2ECC71",
+        }
+    }
+}
+
+impl TryFrom<String> for CaseAction {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&Locale::default(), &value)
+    }
+}
+
+pub struct NewModCase {
+    pub server_id: String,
+    pub action: CaseAction,
+    pub target_id: Option<String>,
+    pub moderator_id: String,
+    pub reason: Option<String>,
+    pub duration: Option<Duration>,
+}
+
+impl ModCase {
+    pub fn embed(&self, locale: &Locale) -> SendableEmbed {
+        let mut lines = Vec::new();
+
+        if let Some(target_id) = &self.target_id {
+            lines.push(locale.t("case-embed-user", &[("user", target_id.as_str().into())]));
+        };
+
+        lines.push(locale.t(
+            "case-embed-moderator",
+            &[("moderator", self.moderator_id.as_str().into())],
+        ));
+
+        if let Some(duration) = self.duration {
+            lines.push(
+                locale.t(
+                    "case-embed-duration",
+                    &[(
+                        "duration",
+                        locale
+                            .format_duration(Duration::from_secs(duration as u64))
+                            .into(),
+                    )],
+                ),
+            );
+        };
+
+        lines.push(
+            locale.t(
+                "case-embed-reason",
+                &[(
+                    "reason",
+                    self.reason
+                        .clone()
+                        .unwrap_or_else(|| locale.t("case-no-reason", &[]))
+                        .into(),
+                )],
+            ),
+        );
+
+        lines.push(locale.t(
+            "case-embed-date",
+            &[("date", self.created_at.to_string().into())],
+        ));
+
+        if let Some(updated_at) = self.updated_at {
+            lines.push(locale.t(
+                "case-embed-updated",
+                &[("date", updated_at.to_string().into())],
+            ));
+        };
+
+        SendableEmbed::default()
+            .title(locale.t(
+                "case-embed-title",
+                &[
+                    ("case", self.id.into()),
+                    ("action", self.action.name(locale).into()),
+                ],
+            ))
+            .description(lines.join("\n"))
+            .colour(self.action.colour().to_string())
+    }
+}
+
+pub async fn log_mod_case(http: &HttpClient, state: &State, case: NewModCase) -> Result<ModCase> {
+    let mut case = state.create_mod_case(&case).await?;
+
+    let config = state.fetch_server_config(&case.server_id).await?;
+
+    if let Some(channel_id) = config.mod_log_channel {
+        let locale = state.server_locale(&case.server_id).await?;
+
+        match SendMessageBuilder::new(http.clone(), channel_id)
+            .embed(case.embed(&locale))
+            .build()
+            .await
+        {
+            Ok(message) => {
+                state
+                    .set_mod_case_log_message(&case.server_id, case.id, &message.id)
+                    .await?;
+
+                case.log_message_id = Some(message.id);
+            }
+            Err(e) => log::warn!("Failed to post case {} to mod log: {e:?}", case.id),
+        }
+    };
+
+    Ok(case)
+}
+
+pub async fn update_mod_case_log(http: &HttpClient, state: &State, case: &ModCase) -> Result<()> {
+    let config = state.fetch_server_config(&case.server_id).await?;
+
+    if let (Some(channel_id), Some(message_id)) = (config.mod_log_channel, &case.log_message_id) {
+        let locale = state.server_locale(&case.server_id).await?;
+
+        EditMessageBuilder::new(http.clone(), channel_id, message_id.clone())
+            .embed(case.embed(&locale))
+            .build()
+            .await?;
+    };
+
+    Ok(())
+}
@@ -0,0 +1,363 @@
+use std::sync::Arc;
+
+use stoat::{ChannelExt, Context, EventHandler, GlobalCache, async_trait, types::EventV1};
+
+use crate::{BusMessage, ClusterConfig, ClusterRole, Error, EventBus, Result, Storage, create_bus};
+
+const VIRTUAL_NODES: usize = 64;
+
+// fnv1a barely touches the high bits for short, similar keys like ulids, so
+// finish with the murmur3 mixer to spread them evenly around the ring
+fn hash(bytes: &[u8]) -> u64 {
+    let mut hash = bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+#[derive(Debug, Clone)]
+pub struct HashRing {
+    nodes: Vec<(u64, usize)>,
+}
+
+impl HashRing {
+    pub fn new(workers: usize) -> Self {
+        let mut nodes = (0..workers)
+            .flat_map(|worker| {
+                (0..VIRTUAL_NODES)
+                    .map(move |node| (hash(format!("{worker}-{node}").as_bytes()), worker))
+            })
+            .collect::<Vec<_>>();
+
+        nodes.sort_unstable();
+
+        Self { nodes }
+    }
+
+    pub fn worker_for(&self, server_id: &str) -> usize {
+        let hash = hash(server_id.as_bytes());
+        let index = self.nodes.partition_point(|(node, _)| *node < hash);
+
+        self.nodes[index % self.nodes.len()].1
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Route {
+    All,
+    Server(String),
+    Direct,
+    Skip,
+}
+
+fn route_channel(cache: &GlobalCache, channel_id: &str) -> Route {
+    match cache
+        .get_channel(channel_id)
+        .and_then(|channel| channel.server().map(str::to_string))
+    {
+        Some(server_id) => Route::Server(server_id),
+        None => Route::Direct,
+    }
+}
+
+pub fn route_event(cache: &GlobalCache, event: &EventV1) -> Route {
+    match event {
+        EventV1::Bulk { .. }
+        | EventV1::Pong { .. }
+        | EventV1::Authenticated
+        | EventV1::Logout
+        | EventV1::Error { .. } => Route::Skip,
+
+        EventV1::ServerCreate { id, .. }
+        | EventV1::ServerUpdate { id, .. }
+        | EventV1::ServerDelete { id }
+        | EventV1::ServerMemberJoin { id, .. }
+        | EventV1::ServerMemberLeave { id, .. }
+        | EventV1::ServerRoleUpdate { id, .. }
+        | EventV1::ServerRoleDelete { id, .. }
+        | EventV1::ServerRoleRanksUpdate { id, .. } => Route::Server(id.clone()),
+        EventV1::ServerMemberUpdate { id, .. } => Route::Server(id.server.clone()),
+
+        EventV1::ChannelCreate(channel) => match channel.server() {
+            Some(server_id) => Route::Server(server_id.to_string()),
+            None => Route::Direct,
+        },
+
+        EventV1::Message(message) => route_channel(cache, &message.channel),
+        EventV1::MessageUpdate { channel, .. }
+        | EventV1::MessageAppend { channel, .. }
+        | EventV1::MessageDelete { channel, .. }
+        | EventV1::BulkMessageDelete { channel, .. }
+        | EventV1::MessageReact {
+            channel_id: channel,
+            ..
+        }
+        | EventV1::MessageUnreact {
+            channel_id: channel,
+            ..
+        }
+        | EventV1::MessageRemoveReaction {
+            channel_id: channel,
+            ..
+        }
+        | EventV1::UserVoiceStateUpdate {
+            channel_id: channel,
+            ..
+        }
+        | EventV1::ChannelUpdate { id: channel, .. }
+        | EventV1::ChannelDelete { id: channel }
+        | EventV1::ChannelStartTyping { id: channel, .. }
+        | EventV1::ChannelStopTyping { id: channel, .. }
+        | EventV1::VoiceChannelJoin { id: channel, .. }
+        | EventV1::VoiceChannelLeave { id: channel, .. } => route_channel(cache, channel),
+
+        _ => Route::All,
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cluster {
+    pub bus: Arc<dyn EventBus>,
+    pub ring: HashRing,
+    pub config: ClusterConfig,
+}
+
+impl Cluster {
+    pub fn new(config: ClusterConfig, storage: &dyn Storage) -> Self {
+        Self {
+            bus: create_bus(config.bus, storage.postgres()),
+            ring: HashRing::new(config.workers),
+            config,
+        }
+    }
+
+    pub fn is_primary(&self) -> bool {
+        self.config.role == ClusterRole::Gateway || self.config.worker_id == 0
+    }
+
+    pub fn owns_server(&self, server_id: &str) -> bool {
+        self.config.role == ClusterRole::Gateway
+            || self.ring.worker_for(server_id) == self.config.worker_id
+    }
+
+    pub async fn publish(&self, route: Route, message: BusMessage) -> Result<()> {
+        match route {
+            Route::Skip => Ok(()),
+            Route::Direct => self.bus.publish(0, &message).await,
+            Route::Server(server_id) => {
+                self.bus
+                    .publish(self.ring.worker_for(&server_id), &message)
+                    .await
+            }
+            Route::All => {
+                for worker in 0..self.config.workers {
+                    self.bus.publish(worker, &message).await?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GatewayEvents {
+    pub cluster: Cluster,
+}
+
+#[async_trait]
+impl EventHandler for GatewayEvents {
+    type Error = Error;
+
+    async fn event(&self, ctx: Context, event: EventV1) -> Result<(), Error> {
+        let route = route_event(&ctx.cache, &event);
+
+        self.cluster
+            .publish(route, BusMessage::Event(Box::new(event)))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{Value, json};
+    use stoat::{
+        CacheConfig,
+        types::{Channel, StoatConfig},
+    };
+
+    use super::*;
+
+    fn cache() -> GlobalCache {
+        let feature = json!({ "enabled": false, "url": "" });
+        let config: StoatConfig = serde_json::from_value(json!({
+            "revolt": "",
+            "features": {
+                "captcha": { "enabled": false, "key": "" },
+                "email": false,
+                "invite_only": false,
+                "autumn": feature,
+                "january": feature,
+                "livekit": { "enabled": false, "nodes": [] },
+            },
+            "ws": "",
+            "app": "",
+            "vapid": "",
+            "build": {
+                "commit_sha": "",
+                "commit_timestamp": "",
+                "semver": "",
+                "origin_url": "",
+                "timestamp": "",
+            },
+        }))
+        .unwrap();
+
+        GlobalCache::new(config, CacheConfig::default())
+    }
+
+    fn channel(value: Value) -> Channel {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn event(value: Value) -> EventV1 {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn text_channel(id: &str, server: &str) -> Channel {
+        channel(
+            json!({ "channel_type": "TextChannel", "_id": id, "server": server, "name": "general" }),
+        )
+    }
+
+    fn direct_message(id: &str) -> Channel {
+        channel(
+            json!({ "channel_type": "DirectMessage", "_id": id, "active": true, "recipients": ["a", "b"] }),
+        )
+    }
+
+    // ulid-shaped ids sharing a timestamp prefix, like servers created close together
+    fn server_id(i: usize) -> String {
+        format!("01HZX4{i:020}")
+    }
+
+    fn typing(channel_id: &str) -> EventV1 {
+        event(json!({ "type": "ChannelStartTyping", "id": channel_id, "user": "user" }))
+    }
+
+    #[test]
+    fn ring_is_stable() {
+        let first = HashRing::new(4);
+        let second = HashRing::new(4);
+
+        for i in 0..1000 {
+            let server_id = server_id(i);
+            assert_eq!(first.worker_for(&server_id), second.worker_for(&server_id));
+        }
+    }
+
+    #[test]
+    fn ring_spreads_servers_across_workers() {
+        let workers = 4;
+        let ring = HashRing::new(workers);
+        let mut counts = vec![0; workers];
+
+        for i in 0..10000 {
+            counts[ring.worker_for(&server_id(i))] += 1;
+        }
+
+        // each worker should own roughly a quarter of the servers
+        for &count in &counts {
+            assert!(
+                (1500..=3500).contains(&count),
+                "uneven distribution: {counts:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn growing_ring_moves_few_servers() {
+        let before = HashRing::new(4);
+        let after = HashRing::new(5);
+
+        let moved = (0..10000)
+            .map(server_id)
+            .filter(|server_id| before.worker_for(server_id) != after.worker_for(server_id))
+            .count();
+
+        // ideally a fifth of the servers move to the new worker
+        assert!(moved < 3500, "too many servers moved: {moved}");
+    }
+
+    #[test]
+    fn single_worker_owns_everything() {
+        let ring = HashRing::new(1);
+
+        for i in 0..100 {
+            assert_eq!(ring.worker_for(&server_id(i)), 0);
+        }
+    }
+
+    #[test]
+    fn routes_server_events_to_their_server() {
+        let cache = cache();
+
+        assert_eq!(
+            route_event(
+                &cache,
+                &event(json!({ "type": "ServerDelete", "id": "server" }))
+            ),
+            Route::Server("server".to_string())
+        );
+    }
+
+    #[test]
+    fn routes_channel_events_through_the_cache() {
+        let cache = cache();
+        cache.insert_channel(text_channel("channel", "server"));
+
+        assert_eq!(
+            route_event(&cache, &typing("channel")),
+            Route::Server("server".to_string())
+        );
+    }
+
+    #[test]
+    fn routes_dm_and_unknown_channels_directly() {
+        let cache = cache();
+        cache.insert_channel(direct_message("dm"));
+
+        assert_eq!(route_event(&cache, &typing("dm")), Route::Direct);
+        assert_eq!(route_event(&cache, &typing("unknown")), Route::Direct);
+    }
+
+    #[test]
+    fn routes_created_channels_by_their_server() {
+        let cache = cache();
+
+        assert_eq!(
+            route_event(
+                &cache,
+                &EventV1::ChannelCreate(text_channel("channel", "server"))
+            ),
+            Route::Server("server".to_string())
+        );
+        assert_eq!(
+            route_event(&cache, &EventV1::ChannelCreate(direct_message("dm"))),
+            Route::Direct
+        );
+    }
+
+    #[test]
+    fn skips_connection_events() {
+        let cache = cache();
+
+        assert_eq!(route_event(&cache, &EventV1::Authenticated), Route::Skip);
+        assert_eq!(route_event(&cache, &EventV1::Logout), Route::Skip);
+    }
+}
@@ -1,31 +1,832 @@
-use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Deserializer, Serialize};
+use toml::{Table, Value};
+
+use crate::{DEFAULT_LOCALE, available_locales, is_sqlite_url, normalize_locale};
+
+const DEFAULT_CONFIG_PATH: &str = "Highlight.toml";
+const ENV_PREFIX: &str = "HIGHLIGHT_";
+const ENV_CONFIG_PATH: &str = "HIGHLIGHT_CONFIG";
+const REDACTED_KEYS: [&str; 3] = ["bot.token", "database.url", "errors.webhook"];
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
+    #[serde(default)]
     pub stoat: StoatConfig,
+    #[serde(default)]
     pub bot: BotConfig,
+    #[serde(default)]
     pub database: DatabaseConfig,
+    #[serde(default)]
     pub limits: LimitsConfig,
+    pub metrics: Option<MetricsConfig>,
+    pub errors: Option<ErrorsConfig>,
+    pub cluster: Option<ClusterConfig>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct StoatConfig {
+    #[serde(default = "default_api")]
     pub api: String,
 }
 
+impl Default for StoatConfig {
+    fn default() -> Self {
+        Self { api: default_api() }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct BotConfig {
+    #[serde(default = "default_prefix", deserialize_with = "one_or_many")]
     pub prefix: Vec<String>,
+    #[serde(default)]
     pub token: String,
+    pub token_file: Option<PathBuf>,
+    #[serde(default = "default_locale")]
+    pub locale: String,
+    #[serde(default = "default_shutdown_timeout")]
+    pub shutdown_timeout: u64,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+impl Default for BotConfig {
+    fn default() -> Self {
+        Self {
+            prefix: default_prefix(),
+            token: String::new(),
+            token_file: None,
+            locale: default_locale(),
+            shutdown_timeout: default_shutdown_timeout(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
 pub struct DatabaseConfig {
+    #[serde(default)]
     pub url: String,
+    pub url_file: Option<PathBuf>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
 pub struct LimitsConfig {
+    #[serde(default = "default_max_keywords")]
     pub max_keywords: usize,
+    #[serde(default = "default_min_stars")]
     pub min_stars: i32,
+    #[serde(default = "default_max_reminders")]
+    pub max_reminders: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsConfig {
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ErrorsConfig {
+    pub channel: Option<String>,
+    pub webhook: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ClusterRole {
+    Gateway,
+    Worker,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum BusKind {
+    #[default]
+    Postgres,
+    Local,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ClusterConfig {
+    pub role: ClusterRole,
+    pub workers: usize,
+    #[serde(default)]
+    pub worker_id: usize,
+    #[serde(default)]
+    pub bus: BusKind,
+}
+
+impl ErrorsConfig {
+    pub fn webhook(&self) -> Option<(String, String)> {
+        let mut segments = self.webhook.as_deref()?.trim_end_matches('/').rsplit('/');
+
+        let token = segments.next()?;
+        let id = segments.next()?;
+
+        Some((id.to_string(), token.to_string()))
+    }
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_keywords: default_max_keywords(),
+            min_stars: default_min_stars(),
+            max_reminders: default_max_reminders(),
+        }
+    }
+}
+
+fn default_api() -> String {
+    "https://api.stoat.chat".to_string()
+}
+
+fn default_prefix() -> Vec<String> {
+    vec!["h!".to_string()]
+}
+
+fn default_locale() -> String {
+    DEFAULT_LOCALE.to_string()
+}
+
+fn default_shutdown_timeout() -> u64 {
+    8
+}
+
+fn default_max_keywords() -> usize {
+    10
+}
+
+fn default_min_stars() -> i32 {
+    3
+}
+
+fn default_max_reminders() -> usize {
+    25
+}
+
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(value) => vec![value],
+        OneOrMany::Many(values) => values,
+    })
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        error: toml::de::Error,
+    },
+    Env {
+        key: String,
+        error: String,
+    },
+    Invalid {
+        key: &'static str,
+        message: String,
+    },
+    Immutable {
+        key: &'static str,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Read { path, error } => {
+                write!(f, "failed to read `{}`: {error}", path.display())
+            }
+            ConfigError::Parse { path, error } => {
+                write!(f, "failed to parse `{}`: {error}", path.display())
+            }
+            ConfigError::Env { key, error } => write!(f, "invalid value for `{key}`: {error}"),
+            ConfigError::Invalid { key, message } => write!(f, "`{key}` {message}"),
+            ConfigError::Immutable { key } => {
+                write!(f, "`{key}` cannot be changed without restarting the bot")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    pub fn path() -> PathBuf {
+        let mut args = std::env::args().skip(1);
+
+        while let Some(arg) = args.next() {
+            if arg == "--config" || arg == "-c" {
+                if let Some(path) = args.next() {
+                    return path.into();
+                };
+            } else if let Some(path) = arg.strip_prefix("--config=") {
+                return path.into();
+            };
+        }
+
+        std::env::var_os(ENV_CONFIG_PATH)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| DEFAULT_CONFIG_PATH.into())
+    }
+
+    pub fn load() -> Result<Self, ConfigError> {
+        Self::load_from(Self::path())
+    }
+
+    pub fn load_from(path: PathBuf) -> Result<Self, ConfigError> {
+        Self::load_with(path, std::env::vars())
+    }
+
+    fn load_with(
+        path: PathBuf,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<Self, ConfigError> {
+        let table = match std::fs::read_to_string(&path) {
+            Ok(content) => {
+                toml::from_str::<Table>(&content).map_err(|error| ConfigError::Parse {
+                    path: path.clone(),
+                    error,
+                })?
+            }
+            Err(error)
+                if error.kind() == std::io::ErrorKind::NotFound
+                    && path.as_os_str() == DEFAULT_CONFIG_PATH =>
+            {
+                Table::new()
+            }
+            Err(error) => return Err(ConfigError::Read { path, error }),
+        };
+
+        let overrides = env_overrides(vars);
+        let mut config = Self::from_layers(&path, &table, &overrides)?;
+
+        if let Some(token_file) = &config.bot.token_file {
+            config.bot.token = read_secret(token_file)?;
+        };
+
+        if let Some(url_file) = &config.database.url_file {
+            config.database.url = read_secret(url_file)?;
+        };
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn from_layers(
+        path: &Path,
+        file: &Table,
+        overrides: &[EnvOverride],
+    ) -> Result<Self, ConfigError> {
+        let error = match Config::deserialize(Value::Table(apply_env_overrides(file, overrides)?)) {
+            Ok(config) => return Ok(config),
+            Err(error) => error,
+        };
+
+        // toml only exposes the failing key through its display output, unknown fields are
+        // reported against the table containing them
+        let failed = error
+            .to_string()
+            .lines()
+            .find_map(|line| line.strip_prefix("in `")?.strip_suffix('`'))
+            .unwrap_or_default()
+            .to_string();
+
+        let culprit = overrides.iter().find(|env| {
+            let (field, sections) = env.path.split_last().unwrap();
+
+            env.path.join(".") == failed
+                || sections.join(".") == failed
+                    && error
+                        .message()
+                        .starts_with(&format!("unknown field `{field}`"))
+        });
+
+        if let Some(env) = culprit {
+            return Err(ConfigError::Env {
+                key: env.key.clone(),
+                error: error.message().to_string(),
+            });
+        };
+
+        Err(ConfigError::Parse {
+            path: path.to_path_buf(),
+            error,
+        })
+    }
+
+    fn validate(&self) -> Result<(), ConfigError> {
+        if self.bot.token.is_empty() {
+            return Err(ConfigError::Invalid {
+                key: "bot.token",
+                message: "must be set, either directly, with `bot.token_file` or with `HIGHLIGHT_BOT__TOKEN`".to_string(),
+            });
+        };
+
+        if self.bot.prefix.is_empty() || self.bot.prefix.iter().any(|prefix| prefix.is_empty()) {
+            return Err(ConfigError::Invalid {
+                key: "bot.prefix",
+                message: "must contain at least one non-empty prefix".to_string(),
+            });
+        };
+
+        if normalize_locale(&self.bot.locale).is_none() {
+            return Err(ConfigError::Invalid {
+                key: "bot.locale",
+                message: format!(
+                    "must be one of {}, got `{}`",
+                    available_locales().join(", "),
+                    self.bot.locale
+                ),
+            });
+        };
+
+        if self.database.url.is_empty() {
+            return Err(ConfigError::Invalid {
+                key: "database.url",
+                message: "must be set, either directly, with `database.url_file` or with `HIGHLIGHT_DATABASE__URL`".to_string(),
+            });
+        };
+
+        if !self.stoat.api.starts_with("http://") && !self.stoat.api.starts_with("https://") {
+            return Err(ConfigError::Invalid {
+                key: "stoat.api",
+                message: format!("must be a http(s) URL, got `{}`", self.stoat.api),
+            });
+        };
+
+        if let Some(metrics) = &self.metrics
+            && metrics.address.parse::<SocketAddr>().is_err()
+        {
+            return Err(ConfigError::Invalid {
+                key: "metrics.address",
+                message: format!("must be a socket address, got `{}`", metrics.address),
+            });
+        };
+
+        if let Some(errors) = &self.errors
+            && errors.channel.is_none()
+            && errors.webhook().is_none()
+        {
+            return Err(ConfigError::Invalid {
+                key: "errors",
+                message: "must set either `channel` or a `webhook` URL".to_string(),
+            });
+        };
+
+        if let Some(cluster) = &self.cluster {
+            if cluster.workers == 0 {
+                return Err(ConfigError::Invalid {
+                    key: "cluster.workers",
+                    message: "must be at least 1".to_string(),
+                });
+            };
+
+            if cluster.worker_id >= cluster.workers {
+                return Err(ConfigError::Invalid {
+                    key: "cluster.worker_id",
+                    message: format!("must be less than `cluster.workers` ({})", cluster.workers),
+                });
+            };
+
+            if cluster.role == ClusterRole::Worker && cluster.bus == BusKind::Local {
+                return Err(ConfigError::Invalid {
+                    key: "cluster.bus",
+                    message: "must be `postgres` for workers, the local bus only works within the gateway process".to_string(),
+                });
+            };
+
+            if cluster.bus == BusKind::Postgres && is_sqlite_url(&self.database.url) {
+                return Err(ConfigError::Invalid {
+                    key: "cluster.bus",
+                    message: "must be `local` when `database.url` is a SQLite database".to_string(),
+                });
+            };
+        };
+
+        if self.limits.max_keywords == 0 {
+            return Err(ConfigError::Invalid {
+                key: "limits.max_keywords",
+                message: "must be at least 1".to_string(),
+            });
+        };
+
+        if self.limits.max_reminders == 0 {
+            return Err(ConfigError::Invalid {
+                key: "limits.max_reminders",
+                message: "must be at least 1".to_string(),
+            });
+        };
+
+        if self.limits.min_stars < 1 {
+            return Err(ConfigError::Invalid {
+                key: "limits.min_stars",
+                message: "must be at least 1".to_string(),
+            });
+        };
+
+        Ok(())
+    }
+}
+
+impl Config {
+    pub fn check_reload(&self, new: &Config) -> Result<(), ConfigError> {
+        if self.bot.token != new.bot.token {
+            return Err(ConfigError::Immutable { key: "bot.token" });
+        };
+
+        if self.database.url != new.database.url {
+            return Err(ConfigError::Immutable {
+                key: "database.url",
+            });
+        };
+
+        if self.stoat.api != new.stoat.api {
+            return Err(ConfigError::Immutable { key: "stoat.api" });
+        };
+
+        if self.metrics.as_ref().map(|m| &m.address) != new.metrics.as_ref().map(|m| &m.address) {
+            return Err(ConfigError::Immutable {
+                key: "metrics.address",
+            });
+        };
+
+        if self.cluster != new.cluster {
+            return Err(ConfigError::Immutable { key: "cluster" });
+        };
+
+        Ok(())
+    }
+
+    pub fn diff(&self, new: &Config) -> Vec<String> {
+        let mut old_values = Vec::new();
+        let mut new_values = Vec::new();
+
+        flatten(
+            "",
+            &Value::try_from(self).unwrap_or(Value::Table(Table::new())),
+            &mut old_values,
+        );
+        flatten(
+            "",
+            &Value::try_from(new).unwrap_or(Value::Table(Table::new())),
+            &mut new_values,
+        );
+
+        let mut changes = Vec::new();
+
+        for (key, new_value) in &new_values {
+            let old_value = old_values
+                .iter()
+                .find(|(old_key, _)| old_key == key)
+                .map(|(_, value)| value.to_string());
+
+            if old_value.as_ref() != Some(&new_value.to_string()) {
+                changes.push(format!(
+                    "{key}: {} -> {new_value}",
+                    old_value.as_deref().unwrap_or("unset")
+                ));
+            };
+        }
+
+        for (key, old_value) in &old_values {
+            if !new_values.iter().any(|(new_key, _)| new_key == key) {
+                changes.push(format!("{key}: {old_value} -> unset"));
+            };
+        }
+
+        changes
+    }
+}
+
+fn flatten(prefix: &str, value: &Value, output: &mut Vec<(String, Value)>) {
+    match value {
+        Value::Table(table) => {
+            for (key, value) in table {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+
+                flatten(&key, value, output);
+            }
+        }
+        _ if REDACTED_KEYS.contains(&prefix) => {
+            output.push((prefix.to_string(), Value::String("<redacted>".to_string())))
+        }
+        value => output.push((prefix.to_string(), value.clone())),
+    }
+}
+
+fn read_secret(path: &PathBuf) -> Result<String, ConfigError> {
+    std::fs::read_to_string(path)
+        .map(|content| content.trim().to_string())
+        .map_err(|error| ConfigError::Read {
+            path: path.clone(),
+            error,
+        })
+}
+
+fn parse_env_value(value: &str) -> Value {
+    toml::from_str::<Table>(&format!("value = {value}"))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| Value::String(value.to_string()))
+}
+
+struct EnvOverride {
+    key: String,
+    path: Vec<String>,
+    value: Value,
+}
+
+fn env_overrides(vars: impl IntoIterator<Item = (String, String)>) -> Vec<EnvOverride> {
+    let mut overrides = vars
+        .into_iter()
+        .filter(|(key, _)| key != ENV_CONFIG_PATH)
+        .filter_map(|(key, value)| {
+            let path = key
+                .strip_prefix(ENV_PREFIX)?
+                .split("__")
+                .map(|part| part.to_lowercase())
+                .collect();
+
+            Some(EnvOverride {
+                path,
+                value: parse_env_value(&value),
+                key,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    overrides.sort_by(|a, b| a.key.cmp(&b.key));
+
+    overrides
+}
+
+fn apply_env_overrides<'a>(
+    file: &Table,
+    overrides: impl IntoIterator<Item = &'a EnvOverride>,
+) -> Result<Table, ConfigError> {
+    let mut table = file.clone();
+
+    for env in overrides {
+        let (field, sections) = env.path.split_last().unwrap();
+
+        let mut current = &mut table;
+
+        for section in sections {
+            current = match current
+                .entry(section.clone())
+                .or_insert_with(|| Value::Table(Table::new()))
+            {
+                Value::Table(table) => table,
+                _ => {
+                    return Err(ConfigError::Env {
+                        key: env.key.clone(),
+                        error: format!("`{section}` is not a section"),
+                    });
+                }
+            };
+        }
+
+        current.insert(field.clone(), env.value.clone());
+    }
+
+    Ok(table)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("highlight-{}-{name}", std::process::id()));
+        std::fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn valid() -> Config {
+        Config::deserialize(Value::Table(
+            toml::from_str(
+                r#"
+                [bot]
+                token = "token"
+
+                [database]
+                url = "postgres://localhost/highlight"
+                "#,
+            )
+            .unwrap(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn env_overrides_file_and_secret_files_override_both() {
+        let secret = temp_file("layering-secret", "secret-url\n");
+        let path = temp_file(
+            "layering.toml",
+            r#"
+            [bot]
+            token = "file-token"
+            prefix = "file!"
+
+            [database]
+            url = "file-url"
+
+            [limits]
+            max_keywords = 5
+            "#,
+        );
+
+        let config = Config::load_with(
+            path,
+            vars(&[
+                ("HIGHLIGHT_BOT__PREFIX", r#"["env!", "e!"]"#),
+                ("HIGHLIGHT_DATABASE__URL", "env-url"),
+                ("HIGHLIGHT_DATABASE__URL_FILE", secret.to_str().unwrap()),
+                ("HIGHLIGHT_LIMITS__MAX_KEYWORDS", "20"),
+                ("OTHER_VARIABLE", "ignored"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(config.bot.token, "file-token");
+        assert_eq!(config.bot.prefix, ["env!", "e!"]);
+        assert_eq!(config.database.url, "secret-url");
+        assert_eq!(config.limits.max_keywords, 20);
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let path = temp_file(
+            "unknown-file.toml",
+            r#"
+            [bot]
+            tokne = "token"
+            "#,
+        );
+
+        let error = Config::load_with(path, []).unwrap_err();
+
+        assert!(matches!(error, ConfigError::Parse { .. }));
+        assert!(error.to_string().contains("tokne"), "{error}");
+
+        let path = temp_file("unknown-env.toml", "");
+
+        let error = Config::load_with(
+            path,
+            vars(&[
+                ("HIGHLIGHT_BOT__TOKEN", "token"),
+                ("HIGHLIGHT_BOT__TOKNE", "token"),
+            ]),
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(&error, ConfigError::Env { key, .. } if key == "HIGHLIGHT_BOT__TOKNE"),
+            "{error}"
+        );
+
+        let error = Config::load_with(
+            temp_file("unknown-section.toml", ""),
+            vars(&[("HIGHLIGHT_TOKEN", "token")]),
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(&error, ConfigError::Env { key, .. } if key == "HIGHLIGHT_TOKEN"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn env_type_errors_blame_the_variable() {
+        let path = temp_file(
+            "env-type.toml",
+            r#"
+            [bot]
+            token = "token"
+
+            [cluster]
+            role = "gateway"
+            "#,
+        );
+
+        let error = Config::load_with(
+            path.clone(),
+            vars(&[
+                ("HIGHLIGHT_CLUSTER__WORKERS", "4"),
+                ("HIGHLIGHT_LIMITS__MAX_KEYWORDS", "many"),
+            ]),
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(&error, ConfigError::Env { key, .. } if key == "HIGHLIGHT_LIMITS__MAX_KEYWORDS"),
+            "{error}"
+        );
+
+        let error = Config::load_with(path, []).unwrap_err();
+
+        assert!(matches!(error, ConfigError::Parse { .. }), "{error}");
+    }
+
+    #[test]
+    fn validate_rejects_invalid_values() {
+        assert!(valid().validate().is_ok());
+
+        let mut config = valid();
+        config.bot.token.clear();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                key: "bot.token",
+                ..
+            })
+        ));
+
+        let mut config = valid();
+        config.bot.locale = "xx".to_string();
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                key: "bot.locale",
+                ..
+            })
+        ));
+
+        let mut config = valid();
+        config.limits.min_stars = 0;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                key: "limits.min_stars",
+                ..
+            })
+        ));
+
+        let mut config = valid();
+        config.cluster = Some(ClusterConfig {
+            role: ClusterRole::Worker,
+            workers: 2,
+            worker_id: 2,
+            bus: BusKind::Postgres,
+        });
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                key: "cluster.worker_id",
+                ..
+            })
+        ));
+
+        let mut config = valid();
+        config.database.url = "sqlite://highlight.db".to_string();
+        config.cluster = Some(ClusterConfig {
+            role: ClusterRole::Gateway,
+            workers: 2,
+            worker_id: 0,
+            bus: BusKind::Postgres,
+        });
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid {
+                key: "cluster.bus",
+                ..
+            })
+        ));
+    }
 }
@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
+use stoat::error::{StoatHttpError, StoatHttpErrorType};
+
 #[derive(Debug, Clone)]
 pub enum Error {
     StoatError(stoat::Error),
     PgError(Arc<sqlx::Error>),
     UserError(String),
+    JsonError(Arc<serde_json::Error>),
 }
 
 impl From<stoat::Error> for Error {
@@ -19,5 +22,24 @@ impl From<sqlx::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(value: serde_json::Error) -> Self {
+        Self::JsonError(Arc::new(value))
+    }
+}
+
+pub fn is_not_found(error: &stoat::Error) -> bool {
+    matches!(
+        error,
+        stoat::Error::HttpError(StoatHttpError {
+            error_type: StoatHttpErrorType::NotFound
+                | StoatHttpErrorType::UnknownUser
+                | StoatHttpErrorType::UnknownServer
+                | StoatHttpErrorType::UnknownChannel,
+            ..
+        })
+    )
+}
+
 #[derive(Copy, Clone)]
-pub struct Handled;
\ No newline at end of file
+pub struct Handled;
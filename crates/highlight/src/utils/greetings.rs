@@ -0,0 +1,300 @@
+use std::time::{Duration, SystemTime};
+
+use stoat::{
+    Context, EmbedExt, MemberExt, UserExt,
+    builders::SendMessageBuilder,
+    types::{Member, RemovalIntention, SendableEmbed, User},
+};
+
+use crate::{Locale, Result, ServerConfig, State};
+
+pub const DEFAULT_WELCOME_MESSAGE: &str = "Welcome {user} to **{server}**!";
+pub const DEFAULT_GOODBYE_MESSAGE: &str = "**{username}** has left **{server}**.";
+pub const MAX_AUTO_ROLES: usize = 5;
+
+// substitute in a single pass so placeholders inside names are left as is
+pub fn render_template(template: &str, values: &[(&str, String)]) -> String {
+    let mut content = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        content.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let placeholder = rest.find('}').and_then(|end| {
+            values
+                .iter()
+                .find(|(name, _)| *name == &rest[1..end])
+                .map(|(_, value)| (value, end))
+        });
+
+        match placeholder {
+            Some((value, end)) => {
+                content.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                content.push('{');
+                rest = &rest[1..];
+            }
+        };
+    }
+
+    content.push_str(rest);
+
+    content
+}
+
+// counts seeded from a member fetch are kept up to date by join and leave events, otherwise
+// fall back to the members the cache has seen rather than fetching the whole list per join
+async fn member_count(ctx: &Context, state: &State, server_id: &str) -> usize {
+    match state.get_member_count(server_id).await {
+        Some(count) => count,
+        None => ctx
+            .cache
+            .members
+            .get_sync(server_id)
+            .map(|members| members.len())
+            .unwrap_or_default(),
+    }
+}
+
+pub async fn render_greeting(
+    ctx: &Context,
+    state: &State,
+    template: &str,
+    server_id: &str,
+    user: &User,
+) -> String {
+    let server_name = ctx
+        .cache
+        .get_server(server_id)
+        .map(|server| server.name)
+        .unwrap_or_default();
+
+    let count = if template.contains("{count}") {
+        member_count(ctx, state, server_id).await.to_string()
+    } else {
+        String::new()
+    };
+
+    render_template(
+        template,
+        &[
+            ("user", user.mention()),
+            ("username", user.name().to_string()),
+            ("server", server_name),
+            ("count", count),
+        ],
+    )
+}
+
+async fn fetch_user(ctx: &Context, user_id: &str) -> Result<User> {
+    if let Some(user) = ctx.cache.get_user(user_id) {
+        return Ok(user);
+    };
+
+    let user = ctx.http.fetch_user(user_id).await?;
+    ctx.cache.insert_user(user.clone());
+
+    Ok(user)
+}
+
+fn account_age(locale: &Locale, user_id: &str) -> Option<String> {
+    let created = ulid::Ulid::from_string(user_id).ok()?.datetime();
+    let age = SystemTime::now().duration_since(created).ok()?;
+
+    Some(locale.format_duration(Duration::from_secs(age.as_secs() / 3600 * 3600)))
+}
+
+async fn log_member_event(
+    ctx: &Context,
+    state: &State,
+    server_id: &str,
+    config: &ServerConfig,
+    user: &User,
+    event: &str,
+    colour: &str,
+) -> Result<()> {
+    let Some(channel) = &config.member_log_channel else {
+        return Ok(());
+    };
+
+    let locale = state.server_locale(server_id).await?;
+    let mut description = format!("{} ({})", user.mention(), user.id);
+
+    if let Some(age) = account_age(&locale, &user.id) {
+        description.push_str(&format!(
+            "\n{}",
+            locale.t("memberlog-account-age", &[("age", age.into())])
+        ));
+    };
+
+    SendMessageBuilder::new(ctx.http.clone(), channel.clone())
+        .embed(
+            SendableEmbed::default()
+                .icon_url(user.avatar_url(ctx))
+                .title(locale.t(
+                    &format!("memberlog-{event}"),
+                    &[("user", user.name().into())],
+                ))
+                .description(description)
+                .colour(colour.to_string()),
+        )
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub async fn handle_member_join(ctx: &Context, state: &State, member: &Member) -> Result<()> {
+    if ctx.cache.get_current_user_id().as_ref() == Some(&member.id.user) {
+        return Ok(());
+    };
+
+    state.adjust_member_count(&member.id.server, true).await;
+
+    let config = state.fetch_server_config(&member.id.server).await?;
+
+    if !config.auto_roles.is_empty()
+        && let Some(server) = ctx.cache.get_server(&member.id.server)
+    {
+        let roles = config
+            .auto_roles
+            .iter()
+            .filter_map(|role_id| server.roles.get(role_id).cloned())
+            .collect::<Vec<_>>();
+
+        if !roles.is_empty() {
+            member.add_roles(&ctx.http, &roles).await?;
+        };
+    };
+
+    let user = fetch_user(ctx, &member.id.user).await?;
+
+    if let Some(channel) = &config.welcome_channel {
+        let content = render_greeting(
+            ctx,
+            state,
+            config
+                .welcome_message
+                .as_deref()
+                .unwrap_or(DEFAULT_WELCOME_MESSAGE),
+            &member.id.server,
+            &user,
+        )
+        .await;
+
+        SendMessageBuilder::new(ctx.http.clone(), channel.clone())
+            .content(content)
+            .build()
+            .await?;
+    };
+
+    log_member_event(
+        ctx,
+        state,
+        &member.id.server,
+        &config,
+        &user,
+        "joined",
+        "#2ECC71",
+    )
+    .await
+}
+
+pub async fn handle_member_leave(
+    ctx: &Context,
+    state: &State,
+    member: &Member,
+    reason: &RemovalIntention,
+) -> Result<()> {
+    if ctx.cache.get_current_user_id().as_ref() == Some(&member.id.user) {
+        return Ok(());
+    };
+
+    state.adjust_member_count(&member.id.server, false).await;
+
+    let config = state.fetch_server_config(&member.id.server).await?;
+
+    if config.goodbye_channel.is_none() && config.member_log_channel.is_none() {
+        return Ok(());
+    };
+
+    let user = fetch_user(ctx, &member.id.user).await?;
+
+    if let Some(channel) = &config.goodbye_channel {
+        let content = render_greeting(
+            ctx,
+            state,
+            config
+                .goodbye_message
+                .as_deref()
+                .unwrap_or(DEFAULT_GOODBYE_MESSAGE),
+            &member.id.server,
+            &user,
+        )
+        .await;
+
+        SendMessageBuilder::new(ctx.http.clone(), channel.clone())
+            .content(content)
+            .build()
+            .await?;
+    };
+
+    let (event, colour) = match reason {
+        RemovalIntention::Leave => ("left", "#95A5A6"),
+        RemovalIntention::Kick => ("kicked", "#E67E22"),
+        RemovalIntention::Ban => ("banned", "#E74C3C"),
+    };
+
+    log_member_event(ctx, state, &member.id.server, &config, &user, event, colour).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Vec<(&'static str, String)> {
+        vec![
+            ("user", "<@user>".to_string()),
+            ("username", "{server}".to_string()),
+            ("server", "Stoat".to_string()),
+            ("count", "42".to_string()),
+        ]
+    }
+
+    #[test]
+    fn substitutes_placeholders() {
+        assert_eq!(
+            render_template(
+                "Welcome {user} to **{server}**, member #{count}!",
+                &values()
+            ),
+            "Welcome <@user> to **Stoat**, member #42!"
+        );
+    }
+
+    #[test]
+    fn placeholders_inside_names_are_left_alone() {
+        assert_eq!(
+            render_template("**{username}** has left {server}.", &values()),
+            "**{server}** has left Stoat."
+        );
+    }
+
+    #[test]
+    fn unknown_placeholders_are_kept() {
+        assert_eq!(
+            render_template("{x} {user} {}", &values()),
+            "{x} <@user> {}"
+        );
+    }
+
+    #[test]
+    fn unclosed_braces_are_kept() {
+        assert_eq!(render_template("{user", &values()), "{user");
+        assert_eq!(render_template("{{user}", &values()), "{<@user>");
+        assert_eq!(render_template("{server} {", &values()), "Stoat {");
+    }
+}
@@ -1,34 +1,70 @@
 use std::fmt::Debug;
-use stoat::{async_trait, builders::SendMessageBuilder, commands::HelpCommand};
+use stoat::{async_trait, builders::SendMessageBuilder, commands::HelpCommand, types::Message};
 
-use crate::{CmdCtx, Command, Error, State};
+use crate::{CmdCtx, Command, ContextLocaleExt, Error, Locale, Paginator, State};
+
+const COMMANDS_PER_PAGE: usize = 10;
 
 #[derive(Debug)]
 pub struct HighlightHelpCommand;
 
+struct HelpPages(Vec<String>);
+
+fn paginate(
+    context: &CmdCtx,
+    builder: &mut SendMessageBuilder,
+    header: String,
+    lines: Vec<String>,
+) {
+    builder.content(format!("{header}\n{}", lines.join("\n")));
+
+    context.local_cache(|| {
+        HelpPages(
+            lines
+                .chunks(COMMANDS_PER_PAGE)
+                .map(|chunk| format!("{header}\n{}", chunk.join("\n")))
+                .collect(),
+        )
+    });
+}
+
+fn describe(locale: &Locale, command: &Command) -> Option<String> {
+    let mut path = command.parents.clone();
+    path.push(command.name.clone());
+
+    locale
+        .get(&format!("help-{}", path.join("-")), &[])
+        .or_else(|| command.description.clone())
+}
+
+fn short_description(locale: &Locale, command: &Command) -> String {
+    describe(locale, command)
+        .map(|desc| desc.split('\n').next().unwrap().to_string())
+        .unwrap_or_else(|| locale.t("help-no-description", &[]))
+}
+
 #[async_trait]
 impl HelpCommand<Error, State> for HighlightHelpCommand {
     async fn create_global_help(
         &self,
-        _context: CmdCtx,
+        context: CmdCtx,
         commands: Vec<Command>,
         builder: &mut SendMessageBuilder,
     ) -> Result<(), Error> {
-        let mut lines = vec!["### All commands:".to_string()];
+        let locale = context.locale().await?;
 
-        for command in commands {
-            lines.push(format!(
-                "- {} - {}",
-                &command.name,
-                command
-                    .description
-                    .as_ref()
-                    .map(|desc| desc.split('\n').next().unwrap())
-                    .unwrap_or("No description")
-            ));
-        }
+        let lines = commands
+            .iter()
+            .map(|command| {
+                format!(
+                    "- {} - {}",
+                    &command.name,
+                    short_description(&locale, command)
+                )
+            })
+            .collect();
 
-        builder.content(lines.join("\n"));
+        paginate(&context, builder, locale.t("help-all-commands", &[]), lines);
 
         Ok(())
     }
@@ -39,6 +75,8 @@ impl HelpCommand<Error, State> for HighlightHelpCommand {
         command: Command,
         builder: &mut SendMessageBuilder,
     ) -> Result<(), Error> {
+        let locale = context.locale().await?;
+
         let mut lines = vec![format!("### {}:", &command.name)];
 
         let mut usage = command.parents.clone();
@@ -46,16 +84,27 @@ impl HelpCommand<Error, State> for HighlightHelpCommand {
         usage.push(command.signature.clone().unwrap_or_default());
 
         lines.push(format!(
-            "    Usage: {}{}",
-            context.clean_prefix(),
-            usage.join(" ")
+            "    {}",
+            locale.t(
+                "help-usage",
+                &[(
+                    "usage",
+                    format!("{}{}", context.clean_prefix(), usage.join(" ")).into()
+                )]
+            )
         ));
 
         if !command.aliases.is_empty() {
-            lines.push(format!("    Aliases: {}", command.aliases.join(", ")));
+            lines.push(format!(
+                "    {}",
+                locale.t(
+                    "help-aliases",
+                    &[("aliases", command.aliases.join(", ").into())]
+                )
+            ));
         }
 
-        if let Some(description) = command.description.clone() {
+        if let Some(description) = describe(&locale, &command) {
             lines.push("".to_string());
             lines.push(description);
         }
@@ -71,6 +120,8 @@ impl HelpCommand<Error, State> for HighlightHelpCommand {
         command: Command,
         builder: &mut SendMessageBuilder,
     ) -> Result<(), Error> {
+        let locale = context.locale().await?;
+
         let mut lines = vec![format!("### {}:", &command.name)];
 
         let mut usage = command.parents.clone();
@@ -78,16 +129,27 @@ impl HelpCommand<Error, State> for HighlightHelpCommand {
         usage.push(command.signature.clone().unwrap_or_default());
 
         lines.push(format!(
-            "    Usage: {}{}",
-            context.clean_prefix(),
-            usage.join(" ")
+            "    {}",
+            locale.t(
+                "help-usage",
+                &[(
+                    "usage",
+                    format!("{}{}", context.clean_prefix(), usage.join(" ")).into()
+                )]
+            )
         ));
 
         if !command.aliases.is_empty() {
-            lines.push(format!("    Aliases: {}", command.aliases.join(", ")));
+            lines.push(format!(
+                "    {}",
+                locale.t(
+                    "help-aliases",
+                    &[("aliases", command.aliases.join(", ").into())]
+                )
+            ));
         }
 
-        if let Some(description) = command.description.clone() {
+        if let Some(description) = describe(&locale, &command) {
             lines.push("".to_string());
             lines.push(description);
             lines.push("".to_string());
@@ -97,34 +159,52 @@ impl HelpCommand<Error, State> for HighlightHelpCommand {
             .filter_commands(context.clone(), command.children())
             .await?;
 
-        if !children.is_empty() {
-            lines.push("Commands:".to_string());
+        if children.is_empty() {
+            builder.content(lines.join("\n"));
+
+            return Ok(());
         };
 
-        for command in children {
-            lines.push(format!(
-                "    {} - {}",
-                &command.name,
-                command
-                    .description
-                    .as_ref()
-                    .map(|desc| desc.split('\n').next().unwrap())
-                    .unwrap_or("No description")
-            ));
-        }
+        lines.push(locale.t("help-subcommands", &[]));
 
-        builder.content(lines.join("\n"));
+        let children = children
+            .iter()
+            .map(|command| {
+                format!(
+                    "    {} - {}",
+                    &command.name,
+                    short_description(&locale, command)
+                )
+            })
+            .collect();
+
+        paginate(&context, builder, lines.join("\n"), children);
 
         Ok(())
     }
 
+    async fn send_help_command(
+        &self,
+        context: CmdCtx,
+        builder: SendMessageBuilder,
+    ) -> Result<Message, Error> {
+        match context.local_cache(|| HelpPages(Vec::new())) {
+            HelpPages(pages) if !pages.is_empty() => {
+                Paginator::new(pages.clone()).run(&context).await
+            }
+            _ => Ok(builder.build().await?),
+        }
+    }
+
     async fn no_command_found(
         &self,
-        _context: CmdCtx,
+        context: CmdCtx,
         name: String,
         builder: &mut SendMessageBuilder,
     ) -> Result<(), Error> {
-        builder.content(format!("Command `{name}` not found."));
+        let locale = context.locale().await?;
+
+        builder.content(locale.t("help-command-not-found", &[("name", name.into())]));
 
         Ok(())
     }
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use serde::Deserialize;
+use sqlx::postgres::PgListener;
+
+use crate::{Result, State};
+
+pub const INVALIDATION_CHANNEL: &str = "highlight_invalidate";
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Invalidation {
+    Keywords { server_id: String },
+    Blocked { user_id: String },
+    ServerConfig { server_id: String },
+}
+
+async fn clear_invalidated_caches(state: &State) {
+    state.cached_keywords.lock().await.clear();
+    state.cached_blocked.lock().await.clear();
+    state.cached_prefixes.lock().await.clear();
+    state.cached_locales.lock().await.clear();
+}
+
+pub async fn spawn_invalidation_listener(state: State) -> Result<()> {
+    let Some(pool) = state.storage.postgres() else {
+        return Ok(());
+    };
+
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen(INVALIDATION_CHANNEL).await?;
+
+    tokio::spawn(async move {
+        loop {
+            let notification = tokio::select! {
+                _ = state.shutdown.stopped() => return,
+                notification = listener.try_recv() => notification,
+            };
+
+            match notification {
+                Ok(Some(notification)) => {
+                    match serde_json::from_str::<Invalidation>(notification.payload()) {
+                        Ok(invalidation) => state.apply_invalidation(invalidation).await,
+                        Err(e) => log::warn!(
+                            "Ignoring invalid cache invalidation {:?}: {e}",
+                            notification.payload()
+                        ),
+                    };
+                }
+                Ok(None) => {
+                    log::warn!("Lost the invalidation listener connection, clearing caches.");
+
+                    clear_invalidated_caches(&state).await;
+                }
+                Err(e) => {
+                    log::error!("Invalidation listener error: {e:?}");
+
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                }
+            };
+        }
+    });
+
+    Ok(())
+}
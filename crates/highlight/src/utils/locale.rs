@@ -0,0 +1,292 @@
+use std::{collections::HashMap, sync::LazyLock, time::Duration};
+
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue, concurrent::FluentBundle};
+use stoat::async_trait;
+use unic_langid::LanguageIdentifier;
+
+use crate::{CmdCtx, Result};
+
+pub const DEFAULT_LOCALE: &str = "en";
+
+const LOCALE_FILES: [(&str, &str); 3] = [
+    ("en", include_str!("../../locales/en.ftl")),
+    ("es", include_str!("../../locales/es.ftl")),
+    ("de", include_str!("../../locales/de.ftl")),
+];
+
+static CATALOG: LazyLock<HashMap<&'static str, FluentBundle<FluentResource>>> =
+    LazyLock::new(|| {
+        LOCALE_FILES
+            .iter()
+            .map(|(locale, source)| (*locale, load_bundle(locale, source)))
+            .collect()
+    });
+
+// the catalogs are compiled in, so a broken file is a bug rather than something to recover from
+fn load_bundle(locale: &str, source: &str) -> FluentBundle<FluentResource> {
+    let resource = FluentResource::try_new(source.to_string())
+        .unwrap_or_else(|(_, errors)| panic!("Invalid locale file {locale}: {errors:?}"));
+
+    let language = locale
+        .parse::<LanguageIdentifier>()
+        .unwrap_or_else(|e| panic!("Invalid locale {locale}: {e}"));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![language]);
+
+    // bidi isolation marks end up as stray characters in chat clients
+    bundle.set_use_isolating(false);
+
+    bundle
+        .add_resource(resource)
+        .unwrap_or_else(|errors| panic!("Duplicate messages in locale {locale}: {errors:?}"));
+
+    bundle
+}
+
+pub fn available_locales() -> Vec<&'static str> {
+    LOCALE_FILES.iter().map(|(locale, _)| *locale).collect()
+}
+
+pub fn normalize_locale(input: &str) -> Option<String> {
+    let locale = input.trim().to_lowercase().replace('_', "-");
+    let language = locale.split('-').next().unwrap_or_default();
+
+    (CATALOG.contains_key(locale.as_str()) || CATALOG.contains_key(language)).then_some(locale)
+}
+
+#[derive(Debug, Clone)]
+pub struct Locale {
+    chain: Vec<String>,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl Locale {
+    pub fn new(preferred: &[Option<&str>]) -> Self {
+        let mut chain = Vec::new();
+
+        for locale in preferred.iter().flatten().chain([&DEFAULT_LOCALE]) {
+            let locale = locale.to_lowercase();
+
+            if let Some((language, _)) = locale.split_once('-') {
+                let language = language.to_string();
+
+                if !chain.contains(&locale) {
+                    chain.push(locale);
+                };
+
+                if !chain.contains(&language) {
+                    chain.push(language);
+                };
+            } else if !chain.contains(&locale) {
+                chain.push(locale);
+            };
+        }
+
+        chain.retain(|locale| CATALOG.contains_key(locale.as_str()));
+
+        Self { chain }
+    }
+
+    pub fn code(&self) -> &str {
+        self.chain
+            .first()
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_LOCALE)
+    }
+
+    pub fn get(&self, key: &str, args: &[(&str, FluentValue)]) -> Option<String> {
+        let (bundle, pattern) = self.chain.iter().find_map(|locale| {
+            let bundle = CATALOG.get(locale.as_str())?;
+            let pattern = bundle.get_message(key)?.value()?;
+
+            Some((bundle, pattern))
+        })?;
+
+        let args = args
+            .iter()
+            .map(|(name, value)| (*name, value.clone()))
+            .collect::<FluentArgs>();
+
+        let mut errors = Vec::new();
+        let message = bundle.format_pattern(pattern, Some(&args), &mut errors);
+
+        if !errors.is_empty() {
+            log::warn!("Failed to format message {key}: {errors:?}");
+        };
+
+        Some(message.into_owned())
+    }
+
+    pub fn t(&self, key: &str, args: &[(&str, FluentValue)]) -> String {
+        self.get(key, args).unwrap_or_else(|| key.to_string())
+    }
+
+    pub fn format_duration(&self, duration: Duration) -> String {
+        let mut remaining = duration.as_secs();
+        let mut parts = Vec::new();
+
+        for (unit, size) in [
+            ("day", 60 * 60 * 24),
+            ("hour", 60 * 60),
+            ("minute", 60),
+            ("second", 1),
+        ] {
+            let count = remaining / size;
+            remaining %= size;
+
+            if count == 0 || parts.len() == 2 {
+                continue;
+            };
+
+            parts.push(self.t(&format!("duration-{unit}"), &[("count", count.into())]));
+        }
+
+        if parts.is_empty() {
+            return self.t("duration-second", &[("count", 0.into())]);
+        };
+
+        parts.join(" ")
+    }
+}
+
+#[async_trait]
+pub trait ContextLocaleExt {
+    async fn locale(&self) -> Result<Locale>;
+}
+
+#[async_trait]
+impl ContextLocaleExt for CmdCtx {
+    async fn locale(&self) -> Result<Locale> {
+        let server_id = self.get_current_server().ok().map(|server| server.id);
+
+        self.state
+            .resolve_locale(&self.message.author, server_id.as_deref())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use fluent_syntax::ast;
+
+    use super::*;
+
+    #[test]
+    fn catalogs_load() {
+        for locale in available_locales() {
+            assert!(CATALOG[locale].has_message("language-name"), "{locale}");
+        }
+    }
+
+    #[test]
+    fn catalogs_define_the_same_messages() {
+        let messages = |source: &str| {
+            let resource = FluentResource::try_new(source.to_string()).unwrap();
+
+            resource
+                .entries()
+                .filter_map(|entry| match entry {
+                    ast::Entry::Message(message) => Some(message.id.name.to_string()),
+                    _ => None,
+                })
+                .collect::<BTreeSet<_>>()
+        };
+
+        let (_, english) = LOCALE_FILES[0];
+        let english = messages(english);
+
+        for (locale, source) in &LOCALE_FILES[1..] {
+            let messages = messages(source);
+
+            assert_eq!(
+                english.difference(&messages).collect::<Vec<_>>(),
+                Vec::<&String>::new(),
+                "missing from {locale}"
+            );
+            assert_eq!(
+                messages.difference(&english).collect::<Vec<_>>(),
+                Vec::<&String>::new(),
+                "missing from en"
+            );
+        }
+    }
+
+    #[test]
+    fn durations_are_pluralised() {
+        let en = Locale::new(&[Some("en")]);
+        let de = Locale::new(&[Some("de")]);
+
+        assert_eq!(
+            en.format_duration(Duration::from_secs(60 * 60 * 24 + 1)),
+            "1 day 1 second"
+        );
+        assert_eq!(
+            en.format_duration(Duration::from_secs(2 * 60 * 60 + 120)),
+            "2 hours 2 minutes"
+        );
+        assert_eq!(en.format_duration(Duration::ZERO), "0 seconds");
+        assert_eq!(de.format_duration(Duration::from_secs(60)), "1 Minute");
+        assert_eq!(
+            de.format_duration(Duration::from_secs(3 * 60 * 60 * 24)),
+            "3 Tage"
+        );
+    }
+
+    #[test]
+    fn string_arguments_are_not_treated_as_numbers() {
+        let locale = Locale::default();
+
+        assert_eq!(
+            locale.t("highlight-blocked", &[("user", "007".into())]),
+            "Blocked 007"
+        );
+        assert_eq!(
+            locale.t("highlight-cleared", &[("count", 1.into())]),
+            "Cleared 1 keyword"
+        );
+    }
+
+    #[test]
+    fn string_selectors() {
+        let en = Locale::default();
+        let es = Locale::new(&[Some("es")]);
+
+        assert_eq!(
+            en.t("greeting-none", &[("kind", "welcome".into())]),
+            "Welcome messages are disabled."
+        );
+        assert_eq!(
+            es.t("greeting-disabled", &[("kind", "goodbye".into())]),
+            "Mensajes de despedida desactivados."
+        );
+        assert_eq!(
+            es.t(
+                "lockdown-server-duration",
+                &[
+                    ("count", 2.into()),
+                    (
+                        "duration",
+                        es.format_duration(Duration::from_secs(60)).into()
+                    ),
+                ],
+            ),
+            "2 canales bloqueados durante 1 minuto."
+        );
+    }
+
+    #[test]
+    fn falls_back_through_the_chain() {
+        let locale = Locale::new(&[Some("de-AT")]);
+
+        assert_eq!(locale.code(), "de");
+        assert_eq!(locale.t("language-name", &[]), "Deutsch");
+        assert_eq!(locale.t("missing-key", &[]), "missing-key");
+    }
+}
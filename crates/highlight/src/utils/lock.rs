@@ -0,0 +1,54 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+type Locks = Arc<StdMutex<HashMap<String, Arc<Mutex<()>>>>>;
+
+#[derive(Clone, Debug, Default)]
+pub struct KeyedMutex {
+    locks: Locks,
+}
+
+impl KeyedMutex {
+    pub async fn lock(&self, key: impl Into<String>) -> KeyedMutexGuard {
+        let key = key.into();
+
+        let mutex = self
+            .locks
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        KeyedMutexGuard {
+            guard: Some(mutex.lock_owned().await),
+            key,
+            locks: self.locks.clone(),
+        }
+    }
+}
+
+pub struct KeyedMutexGuard {
+    guard: Option<OwnedMutexGuard<()>>,
+    key: String,
+    locks: Locks,
+}
+
+impl Drop for KeyedMutexGuard {
+    fn drop(&mut self) {
+        let mut locks = self.locks.lock().unwrap();
+
+        drop(self.guard.take());
+
+        if locks
+            .get(&self.key)
+            .is_some_and(|mutex| Arc::strong_count(mutex) == 1)
+        {
+            locks.remove(&self.key);
+        };
+    }
+}
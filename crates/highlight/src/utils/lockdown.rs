@@ -0,0 +1,83 @@
+use stoat::{
+    ChannelExt, HttpClient,
+    types::{Channel, ChannelPermission, DataDefaultChannelPermissions, Override},
+};
+
+use crate::{Lockdown, Result, State, is_not_found};
+
+const SEND_MESSAGE: u64 = ChannelPermission::SendMessage as u64;
+
+pub fn is_locked(channel: &Channel) -> bool {
+    channel
+        .default_permissions()
+        .is_some_and(|field| field.d as u64 & SEND_MESSAGE != 0)
+}
+
+pub async fn lock_channel(
+    http: &HttpClient,
+    state: &State,
+    channel: &Channel,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let Some(server_id) = channel.server() else {
+        return Ok(());
+    };
+
+    let previous = match state.fetch_lockdown(channel.id()).await? {
+        Some(lockdown) => Lockdown {
+            expires_at,
+            ..lockdown
+        },
+        None => Lockdown {
+            channel_id: channel.id().to_string(),
+            server_id: server_id.to_string(),
+            previous_allow: channel.default_permissions().map(|field| field.a),
+            previous_deny: channel.default_permissions().map(|field| field.d),
+            expires_at,
+        },
+    };
+
+    let current = channel.default_permissions();
+
+    http.set_default_channel_permissions(
+        channel.id(),
+        &DataDefaultChannelPermissions::Field {
+            permissions: Override {
+                allow: current.map_or(0, |field| field.a as u64) & !SEND_MESSAGE,
+                deny: current.map_or(0, |field| field.d as u64) | SEND_MESSAGE,
+            },
+        },
+    )
+    .await?;
+
+    state.save_lockdown(&previous).await?;
+
+    Ok(())
+}
+
+pub async fn unlock_channel(http: &HttpClient, state: &State, lockdown: &Lockdown) -> Result<()> {
+    let res = http
+        .set_default_channel_permissions(
+            &lockdown.channel_id,
+            &DataDefaultChannelPermissions::Field {
+                permissions: Override {
+                    allow: lockdown.previous_allow.unwrap_or_default() as u64,
+                    deny: lockdown.previous_deny.unwrap_or_default() as u64,
+                },
+            },
+        )
+        .await;
+
+    // keep the lockdown on transient failures so the scheduler or a later unlock can retry
+    if let Err(e) = res {
+        if is_not_found(&e) {
+            state.remove_lockdown(&lockdown.channel_id).await?;
+        };
+
+        return Err(e.into());
+    };
+
+    state.remove_lockdown(&lockdown.channel_id).await?;
+
+    Ok(())
+}
@@ -1,21 +1,28 @@
 use std::time::Duration;
 
-use stoat::{HttpClient, types::Message};
-use tokio::time::sleep;
+use stoat::types::Message;
+use tokio::{select, time::sleep};
+
+use crate::CmdCtx;
 
 pub trait MessageExt {
-    fn delete_after(self, http: impl AsRef<HttpClient> + Send, duration: Duration) -> Self;
+    fn delete_after(self, ctx: &CmdCtx, duration: Duration) -> Self;
 }
 
 impl MessageExt for &Message {
-    fn delete_after(self, http: impl AsRef<HttpClient> + Send, duration: Duration) -> Self {
-        tokio::spawn({
-            let http = http.as_ref().clone();
+    fn delete_after(self, ctx: &CmdCtx, duration: Duration) -> Self {
+        ctx.state.shutdown.spawn({
+            let http = ctx.http.clone();
+            let shutdown = ctx.state.shutdown.clone();
             let id = self.id.clone();
             let channel = self.channel.clone();
 
             async move {
-                sleep(duration).await;
+                select! {
+                    _ = sleep(duration) => {},
+                    _ = shutdown.stopped() => {},
+                };
+
                 let _ = http.delete_message(&channel, &id).await;
             }
         });
@@ -0,0 +1,282 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use stoat::{
+    ChannelExt, Context, EmbedExt, FileExt, MessageExt, UserExt,
+    builders::SendMessageBuilder,
+    types::{Message, SendableEmbed},
+};
+
+use crate::{Locale, Result, State};
+
+pub const SNIPE_LIMIT: usize = 10;
+pub const SNIPE_MAX_AGE: Duration = Duration::from_secs(60 * 30);
+const MAX_LOGGED_CONTENT: usize = 900;
+
+pub type SnipeBuffer = LruCache<String, VecDeque<(Instant, Message)>>;
+
+pub fn truncate_content(content: &str, max: usize) -> String {
+    if content.chars().count() > max {
+        format!("{}...", content.chars().take(max).collect::<String>())
+    } else {
+        content.to_string()
+    }
+}
+
+pub fn author_name(ctx: &Context, message: &Message) -> String {
+    message
+        .user
+        .clone()
+        .or_else(|| ctx.cache.get_user(&message.author))
+        .map(|user| user.name().to_string())
+        .unwrap_or_else(|| message.author.clone())
+}
+
+pub fn attachment_links(ctx: &Context, message: &Message) -> Vec<String> {
+    message
+        .attachments
+        .iter()
+        .flatten()
+        .map(|file| format!("[{}]({})", file.filename, file.url(ctx, false)))
+        .collect()
+}
+
+pub fn line_diff(before: &str, after: &str) -> String {
+    let before = before.lines().collect::<Vec<_>>();
+    let after = after.lines().collect::<Vec<_>>();
+
+    let prefix = before
+        .iter()
+        .zip(&after)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut lines = Vec::new();
+
+    for line in &before[..prefix] {
+        lines.push(format!("  {line}"));
+    }
+
+    for line in &before[prefix..before.len() - suffix] {
+        lines.push(format!("- {line}"));
+    }
+
+    for line in &after[prefix..after.len() - suffix] {
+        lines.push(format!("+ {line}"));
+    }
+
+    for line in &before[before.len() - suffix..] {
+        lines.push(format!("  {line}"));
+    }
+
+    lines.join("\n")
+}
+
+pub async fn record_snipe(state: &State, message: &Message) {
+    let mut snipes = state.snipes.lock().await;
+
+    let entries = snipes.get_or_insert_mut(message.channel.clone(), VecDeque::new);
+
+    entries.push_front((Instant::now(), message.clone()));
+    entries.truncate(SNIPE_LIMIT);
+}
+
+pub async fn fetch_snipes(state: &State, channel_id: &str, count: usize) -> Vec<Message> {
+    let mut snipes = state.snipes.lock().await;
+
+    let Some(entries) = snipes.get_mut(channel_id) else {
+        return Vec::new();
+    };
+
+    entries.retain(|(deleted, _)| deleted.elapsed() < SNIPE_MAX_AGE);
+
+    entries
+        .iter()
+        .take(count)
+        .map(|(_, message)| message.clone())
+        .collect()
+}
+
+async fn get_log_channel(
+    ctx: &Context,
+    state: &State,
+    message: &Message,
+) -> Result<Option<(String, Locale)>> {
+    if ctx.cache.get_current_user_id().as_ref() == Some(&message.author) {
+        return Ok(None);
+    };
+
+    let Some(server_id) = ctx
+        .cache
+        .get_channel(&message.channel)
+        .and_then(|channel| channel.server().map(str::to_string))
+    else {
+        return Ok(None);
+    };
+
+    let config = state.fetch_server_config(&server_id).await?;
+
+    let Some(channel_id) = config
+        .message_log_channel
+        .filter(|channel_id| channel_id != &message.channel)
+    else {
+        return Ok(None);
+    };
+
+    Ok(Some((channel_id, state.server_locale(&server_id).await?)))
+}
+
+pub async fn log_message_delete(ctx: &Context, state: &State, message: &Message) -> Result<()> {
+    let Some((log_channel, locale)) = get_log_channel(ctx, state, message).await? else {
+        return Ok(());
+    };
+
+    let mut description = format!(
+        "{}\n\n{}",
+        locale.t(
+            "messagelog-deleted",
+            &[
+                ("author", message.author.as_str().into()),
+                ("channel", message.channel.as_str().into()),
+            ],
+        ),
+        truncate_content(
+            message.content.as_deref().unwrap_or_default(),
+            MAX_LOGGED_CONTENT
+        )
+    );
+
+    let attachments = attachment_links(ctx, message);
+
+    if !attachments.is_empty() {
+        description.push_str(&format!(
+            "\n\n{}\n{}",
+            locale.t("messagelog-attachments", &[]),
+            attachments.join("\n")
+        ));
+    };
+
+    SendMessageBuilder::new(ctx.http.clone(), log_channel)
+        .embed(
+            SendableEmbed::default()
+                .title(author_name(ctx, message))
+                .description(description)
+                .colour("#E74C3C".to_string()),
+        )
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub async fn log_message_edit(
+    ctx: &Context,
+    state: &State,
+    before: &Message,
+    after: &Message,
+) -> Result<()> {
+    if before.content == after.content {
+        return Ok(());
+    };
+
+    let Some((log_channel, locale)) = get_log_channel(ctx, state, after).await? else {
+        return Ok(());
+    };
+
+    let diff = line_diff(
+        &truncate_content(
+            before.content.as_deref().unwrap_or_default(),
+            MAX_LOGGED_CONTENT / 2,
+        ),
+        &truncate_content(
+            after.content.as_deref().unwrap_or_default(),
+            MAX_LOGGED_CONTENT / 2,
+        ),
+    );
+
+    SendMessageBuilder::new(ctx.http.clone(), log_channel)
+        .embed(
+            SendableEmbed::default()
+                .title(author_name(ctx, after))
+                .description(format!(
+                    "{}\n\n```diff\n{diff}\n```",
+                    locale.t(
+                        "messagelog-edited",
+                        &[
+                            ("author", after.author.as_str().into()),
+                            ("channel", after.channel.as_str().into()),
+                            ("link", after.jump_link(ctx).into()),
+                        ],
+                    )
+                ))
+                .colour("#F1C40F".to_string()),
+        )
+        .build()
+        .await?;
+
+    Ok(())
+}
+
+pub async fn log_bulk_message_delete(
+    ctx: &Context,
+    state: &State,
+    channel_id: &str,
+    message_ids: &[String],
+    found: &[Message],
+) -> Result<()> {
+    let Some(first) = found.first() else {
+        return Ok(());
+    };
+
+    let Some((log_channel, locale)) = get_log_channel(ctx, state, first).await? else {
+        return Ok(());
+    };
+
+    let mut lines = vec![format!(
+        "{}\n",
+        locale.t(
+            "messagelog-bulk-deleted",
+            &[
+                ("count", message_ids.len().into()),
+                ("channel", channel_id.into()),
+            ],
+        )
+    )];
+
+    for message in found.iter().take(SNIPE_LIMIT) {
+        lines.push(format!(
+            "**{}**: {}",
+            author_name(ctx, message),
+            truncate_content(message.content.as_deref().unwrap_or_default(), 100)
+        ));
+    }
+
+    if found.len() > SNIPE_LIMIT {
+        lines.push(locale.t(
+            "messagelog-bulk-more",
+            &[("count", (found.len() - SNIPE_LIMIT).into())],
+        ));
+    };
+
+    SendMessageBuilder::new(ctx.http.clone(), log_channel)
+        .embed(
+            SendableEmbed::default()
+                .title(locale.t("messagelog-bulk-title", &[]))
+                .description(lines.join("\n"))
+                .colour("#E74C3C".to_string()),
+        )
+        .build()
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,209 @@
+use std::{
+    fmt::Write,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
+
+use stoat::{GlobalCache, HttpClient};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    time::timeout,
+};
+
+use crate::State;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default)]
+pub struct Metrics {
+    pub ready: AtomicBool,
+    pub events_received: AtomicU64,
+    pub highlights_sent: AtomicU64,
+    pub highlights_suppressed: AtomicU64,
+    pub commands_invoked: AtomicU64,
+    pub command_errors: AtomicU64,
+}
+
+impl Metrics {
+    pub fn increment(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn render(&self, cache: &GlobalCache, http: &HttpClient) -> String {
+        let mut output = String::new();
+
+        let counters = [
+            (
+                "highlight_events_received_total",
+                "Websocket events received.",
+                &self.events_received,
+            ),
+            (
+                "highlight_highlights_sent_total",
+                "Highlight notifications sent.",
+                &self.highlights_sent,
+            ),
+            (
+                "highlight_highlights_suppressed_total",
+                "Highlight notifications suppressed because the user was active or blocked the author.",
+                &self.highlights_suppressed,
+            ),
+            (
+                "highlight_commands_invoked_total",
+                "Commands invoked.",
+                &self.commands_invoked,
+            ),
+            (
+                "highlight_command_errors_total",
+                "Commands which returned an error.",
+                &self.command_errors,
+            ),
+        ];
+
+        for (name, help, counter) in counters {
+            let _ = writeln!(output, "# HELP {name} {help}");
+            let _ = writeln!(output, "# TYPE {name} counter");
+            let _ = writeln!(output, "{name} {}", counter.load(Ordering::Relaxed));
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP highlight_http_requests_total HTTP requests made per ratelimit bucket."
+        );
+        let _ = writeln!(output, "# TYPE highlight_http_requests_total counter");
+
+        for (bucket, count) in http.request_counts() {
+            let _ = writeln!(
+                output,
+                "highlight_http_requests_total{{bucket=\"{bucket}\"}} {count}"
+            );
+        }
+
+        let _ = writeln!(
+            output,
+            "# HELP highlight_cache_size Objects held in the cache."
+        );
+        let _ = writeln!(output, "# TYPE highlight_cache_size gauge");
+
+        for (kind, size) in [
+            ("servers", cache.servers.len()),
+            ("users", cache.users.len()),
+            ("members", cache.member_count()),
+            ("channels", cache.channels.len()),
+            ("messages", cache.message_count()),
+            ("emojis", cache.emojis.len()),
+        ] {
+            let _ = writeln!(output, "highlight_cache_size{{kind=\"{kind}\"}} {size}");
+        }
+
+        output
+    }
+}
+
+async fn handle_connection(
+    mut stream: TcpStream,
+    state: State,
+    cache: GlobalCache,
+    http: HttpClient,
+) -> std::io::Result<()> {
+    let mut buf = vec![0; 4096];
+    let mut len = 0;
+
+    // a client that never finishes its headers would otherwise hold the connection open forever
+    timeout(REQUEST_TIMEOUT, async {
+        while !buf[..len].windows(4).any(|w| w == b"\r\n\r\n") && len < buf.len() {
+            let read = stream.read(&mut buf[len..]).await?;
+
+            if read == 0 {
+                break;
+            };
+
+            len += read;
+        }
+
+        Ok::<_, std::io::Error>(())
+    })
+    .await
+    .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let mut parts = request.split_ascii_whitespace();
+
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/healthz")) => {
+            let database = timeout(Duration::from_secs(5), state.storage.ping())
+                .await
+                .is_ok_and(|res| res.is_ok());
+
+            match (cache.is_connected(), database) {
+                (true, true) => ("200 OK", "ok\n".to_string()),
+                (connected, database) => (
+                    "503 Service Unavailable",
+                    format!("websocket: {connected}\ndatabase: {database}\n"),
+                ),
+            }
+        }
+        (Some("GET"), Some("/readyz")) => {
+            if state.metrics.ready.load(Ordering::Relaxed) {
+                ("200 OK", "ready\n".to_string())
+            } else {
+                ("503 Service Unavailable", "not ready\n".to_string())
+            }
+        }
+        (Some("GET"), Some("/metrics")) => ("200 OK", state.metrics.render(&cache, &http)),
+        (Some("GET"), _) => ("404 Not Found", "not found\n".to_string()),
+        _ => ("405 Method Not Allowed", "method not allowed\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+pub fn spawn_metrics_server(state: State, cache: GlobalCache, http: HttpClient) {
+    let Some(metrics) = state.config().metrics.clone() else {
+        return;
+    };
+
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(&metrics.address).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                log::error!(
+                    "Failed to bind metrics server to {}: {e:?}",
+                    metrics.address
+                );
+                return;
+            }
+        };
+
+        log::info!("Metrics server listening on {}", metrics.address);
+
+        loop {
+            let stream = match listener.accept().await {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    log::warn!("Failed to accept metrics connection: {e:?}");
+                    continue;
+                }
+            };
+
+            tokio::spawn({
+                let state = state.clone();
+                let cache = cache.clone();
+                let http = http.clone();
+
+                async move {
+                    if let Err(e) = handle_connection(stream, state, cache, http).await {
+                        log::debug!("Metrics connection error: {e:?}");
+                    };
+                }
+            });
+        }
+    });
+}
@@ -1,20 +1,66 @@
+mod automod;
+mod bus;
+mod cases;
+mod cluster;
 mod config;
 mod duration;
 mod error;
+mod greetings;
 mod help;
+mod invalidation;
+mod locale;
+mod lock;
+mod lockdown;
 mod message;
+mod message_log;
+mod metrics;
 mod models;
+mod moderation;
+mod paginator;
+mod permissions;
+mod policy;
+mod reaction_roles;
 mod regex;
+mod reload;
+mod reporter;
+mod scheduler;
+mod shutdown;
+mod slowmode;
 mod state;
+mod storage;
+mod warmup;
 
+pub use automod::*;
+pub use bus::*;
+pub use cases::*;
+pub use cluster::*;
 pub use config::*;
 pub use duration::*;
 pub use error::*;
+pub use greetings::*;
 pub use help::*;
+pub use invalidation::*;
+pub use locale::*;
+pub use lock::*;
+pub use lockdown::*;
 pub use message::*;
+pub use message_log::*;
+pub use metrics::*;
 pub use models::*;
+pub use moderation::*;
+pub use paginator::*;
+pub use permissions::*;
+pub use policy::*;
+pub use reaction_roles::*;
 pub use regex::*;
+pub use reload::*;
+pub use reporter::*;
+pub use scheduler::*;
+pub use shutdown::*;
+pub use slowmode::*;
 pub use state::*;
+pub use storage::*;
+pub use warmup::*;
 
 pub type Command = stoat::commands::Command<Error, State>;
 pub type CmdCtx = stoat::commands::Context<Error, State>;
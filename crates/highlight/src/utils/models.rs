@@ -1,9 +1,20 @@
 use sqlx::FromRow;
 
+use crate::{AutomodActions, AutomodRuleKind, CaseAction, ReactionRoleMode};
+
 #[derive(FromRow)]
 pub struct ServerConfig {
     pub star_count: i32,
     pub starboard_channel: Option<String>,
+    pub mod_log_channel: Option<String>,
+    pub message_log_channel: Option<String>,
+    pub welcome_channel: Option<String>,
+    pub welcome_message: Option<String>,
+    pub goodbye_channel: Option<String>,
+    pub goodbye_message: Option<String>,
+    pub member_log_channel: Option<String>,
+    pub auto_roles: Vec<String>,
+    pub locale: Option<String>,
 }
 
 #[derive(FromRow)]
@@ -13,3 +24,101 @@ pub struct OriginalMessage {
     #[sqlx(rename = "channel_id")]
     pub channel: String,
 }
+
+#[derive(FromRow, Debug, Clone)]
+pub struct ModCase {
+    pub server_id: String,
+    #[sqlx(rename = "case_id")]
+    pub id: i32,
+    #[sqlx(try_from = "String")]
+    pub action: CaseAction,
+    pub target_id: Option<String>,
+    pub moderator_id: String,
+    pub reason: Option<String>,
+    pub duration: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: Option<i64>,
+    pub log_message_id: Option<String>,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct WarnThreshold {
+    pub warn_count: i32,
+    #[sqlx(try_from = "String")]
+    pub action: CaseAction,
+    pub duration: Option<i64>,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct ScheduledUnban {
+    pub server_id: String,
+    pub user_id: String,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct AutomodRule {
+    #[sqlx(try_from = "String")]
+    pub rule: AutomodRuleKind,
+    pub threshold: Option<i32>,
+    #[sqlx(try_from = "Vec<String>")]
+    pub actions: AutomodActions,
+    pub timeout_duration: Option<i64>,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct AutomodExemption {
+    pub target_id: String,
+    pub kind: String,
+}
+
+#[derive(FromRow, Debug, Clone, Default)]
+pub struct CommandPolicy {
+    pub command: String,
+    pub disabled: bool,
+    pub channels: Vec<String>,
+    pub allowed_roles: Vec<String>,
+    pub denied_roles: Vec<String>,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct BlacklistEntry {
+    pub target_id: String,
+    pub kind: String,
+    pub reason: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct Reminder {
+    pub id: i32,
+    pub user_id: String,
+    pub channel_id: String,
+    pub message_id: Option<String>,
+    pub content: String,
+    pub due_at: i64,
+    pub interval: Option<i64>,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct Lockdown {
+    pub channel_id: String,
+    pub server_id: String,
+    pub previous_allow: Option<i64>,
+    pub previous_deny: Option<i64>,
+    pub expires_at: Option<i64>,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct ReactionRolePanel {
+    pub message_id: String,
+    pub server_id: String,
+    pub channel_id: String,
+    #[sqlx(try_from = "String")]
+    pub mode: ReactionRoleMode,
+}
+
+#[derive(FromRow, Debug, Clone)]
+pub struct ReactionRole {
+    pub emoji: String,
+    pub role_id: String,
+}
@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use iso8601_timestamp::Timestamp;
+use stoat::{
+    HttpClient,
+    builders::EditMemberBuilder,
+    types::{DataBanCreate, Role},
+};
+
+use crate::{
+    CaseAction, CmdCtx, ContextLocaleExt, Error, Locale, ModCase, NewModCase, Result, State,
+    Storage, log_mod_case,
+};
+
+pub async fn check_role_hierarchy(ctx: &CmdCtx, role: &Role) -> Result<()> {
+    let server = ctx.get_current_server()?;
+
+    if ctx.message.author == server.owner {
+        return Ok(());
+    };
+
+    let highest_rank = ctx
+        .get_member()
+        .await?
+        .roles
+        .iter()
+        .filter_map(|id| server.roles.get(id))
+        .map(|role| role.rank)
+        .min()
+        .unwrap_or(i64::MAX);
+
+    if role.rank <= highest_rank {
+        return Err(Error::UserError(ctx.locale().await?.t(
+            "error-role-too-high",
+            &[("role", format!("<%{}>", role.id).into())],
+        )));
+    };
+
+    Ok(())
+}
+
+pub async fn apply_mod_action(
+    http: &HttpClient,
+    state: &State,
+    case: NewModCase,
+) -> Result<ModCase> {
+    let locale = state.server_locale(&case.server_id).await?;
+
+    let Some(target_id) = case.target_id.as_deref() else {
+        return Err(Error::UserError(locale.t("case-no-target", &[])));
+    };
+
+    match case.action {
+        CaseAction::Ban | CaseAction::Tempban => {
+            http.ban_member(
+                &case.server_id,
+                target_id,
+                &DataBanCreate {
+                    reason: Some(
+                        case.reason
+                            .clone()
+                            .unwrap_or_else(|| locale.t("case-no-reason", &[])),
+                    ),
+                },
+            )
+            .await?;
+        }
+        CaseAction::Kick => {
+            http.kick_member(&case.server_id, target_id).await?;
+        }
+        CaseAction::Timeout => {
+            let Some(timestamp) = case
+                .duration
+                .and_then(|duration| duration.try_into().ok())
+                .and_then(|duration| Timestamp::now_utc().checked_add(duration))
+            else {
+                return Err(Error::UserError(locale.t("timeout-too-long", &[])));
+            };
+
+            EditMemberBuilder::new(http.clone(), case.server_id.clone(), target_id.to_string())
+                .timeout(Some(timestamp))
+                .build()
+                .await?;
+        }
+        CaseAction::Unban => {
+            http.unban_member(&case.server_id, target_id).await?;
+        }
+        _ => {}
+    };
+
+    sync_scheduled_unban(state.storage.as_ref(), &locale, &case, target_id).await?;
+
+    log_mod_case(http, state, case).await
+}
+
+// a permanent ban or unban supersedes any pending tempban, otherwise the scheduler would still lift it
+async fn sync_scheduled_unban(
+    storage: &dyn Storage,
+    locale: &Locale,
+    case: &NewModCase,
+    target_id: &str,
+) -> Result<()> {
+    match case.action {
+        CaseAction::Tempban => {
+            let Some(duration) = case.duration else {
+                return Err(Error::UserError(locale.t("case-no-duration", &[])));
+            };
+
+            storage
+                .schedule_unban(&case.server_id, target_id, duration)
+                .await
+        }
+        CaseAction::Ban | CaseAction::Unban => {
+            storage
+                .remove_scheduled_unban(&case.server_id, target_id)
+                .await
+        }
+        _ => Ok(()),
+    }
+}
+
+pub struct WarnOutcome {
+    pub case: ModCase,
+    pub warn_count: i64,
+    pub escalation: Option<ModCase>,
+}
+
+pub async fn warn_member(
+    http: &HttpClient,
+    state: &State,
+    server_id: &str,
+    user_id: &str,
+    moderator_id: &str,
+    reason: Option<String>,
+) -> Result<WarnOutcome> {
+    let case = log_mod_case(
+        http,
+        state,
+        NewModCase {
+            server_id: server_id.to_string(),
+            action: CaseAction::Warn,
+            target_id: Some(user_id.to_string()),
+            moderator_id: moderator_id.to_string(),
+            reason,
+            duration: None,
+        },
+    )
+    .await?;
+
+    let warn_count = state.count_warns(server_id, user_id).await?;
+    let locale = state.server_locale(server_id).await?;
+
+    let escalation = if let Some(threshold) = state
+        .fetch_warn_threshold(server_id, warn_count as i32)
+        .await?
+    {
+        Some(
+            apply_mod_action(
+                http,
+                state,
+                NewModCase {
+                    server_id: server_id.to_string(),
+                    action: threshold.action,
+                    target_id: Some(user_id.to_string()),
+                    moderator_id: moderator_id.to_string(),
+                    reason: Some(
+                        locale.t("warn-escalation-reason", &[("count", warn_count.into())]),
+                    ),
+                    duration: threshold
+                        .duration
+                        .map(|duration| Duration::from_secs(duration as u64)),
+                },
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
+    Ok(WarnOutcome {
+        case,
+        warn_count,
+        escalation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SqliteStorage;
+
+    fn case(action: CaseAction, duration: Option<Duration>) -> NewModCase {
+        NewModCase {
+            server_id: "server".to_string(),
+            action,
+            target_id: Some("user".to_string()),
+            moderator_id: "moderator".to_string(),
+            reason: None,
+            duration,
+        }
+    }
+
+    #[tokio::test]
+    async fn ban_clears_pending_tempban() {
+        let storage = SqliteStorage::connect("sqlite::memory:").await.unwrap();
+        storage.migrate().await.unwrap();
+
+        let locale = Locale::default();
+
+        sync_scheduled_unban(
+            &storage,
+            &locale,
+            &case(CaseAction::Tempban, Some(Duration::ZERO)),
+            "user",
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(storage.fetch_due_unbans().await.unwrap().len(), 1);
+
+        sync_scheduled_unban(&storage, &locale, &case(CaseAction::Ban, None), "user")
+            .await
+            .unwrap();
+
+        assert!(storage.fetch_due_unbans().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn tempban_requires_duration() {
+        let storage = SqliteStorage::connect("sqlite::memory:").await.unwrap();
+        storage.migrate().await.unwrap();
+
+        assert!(
+            sync_scheduled_unban(
+                &storage,
+                &Locale::default(),
+                &case(CaseAction::Tempban, None),
+                "user",
+            )
+            .await
+            .is_err()
+        );
+    }
+}
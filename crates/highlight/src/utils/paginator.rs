@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use stoat::{
+    Error as StoatError,
+    builders::EditMessageBuilder,
+    types::{Interactions, Message, OptionsUnreact},
+};
+
+use crate::{CmdCtx, ContextLocaleExt, Result, utils::MessageExt};
+
+const PREVIOUS: &str = "⬅️";
+const NEXT: &str = "➡️";
+const CLOSE: &str = "❌";
+
+pub struct Paginator {
+    pages: Vec<String>,
+    timeout: Duration,
+    delete_on_timeout: bool,
+}
+
+impl Paginator {
+    pub fn new(pages: Vec<String>) -> Self {
+        Self {
+            pages,
+            timeout: Duration::from_secs(60),
+            delete_on_timeout: false,
+        }
+    }
+
+    pub fn from_lines(header: String, lines: Vec<String>, per_page: usize) -> Self {
+        if lines.is_empty() {
+            return Self::new(vec![header]);
+        };
+
+        Self::new(
+            lines
+                .chunks(per_page.max(1))
+                .map(|chunk| format!("{header}\n{}", chunk.join("\n")))
+                .collect(),
+        )
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    pub fn delete_on_timeout(mut self) -> Self {
+        self.delete_on_timeout = true;
+
+        self
+    }
+
+    pub async fn run(self, ctx: &CmdCtx) -> Result<Message> {
+        if self.pages.len() <= 1 {
+            let message = ctx
+                .send()
+                .content(self.pages.into_iter().next().unwrap_or_default())
+                .build()
+                .await?;
+
+            if self.delete_on_timeout {
+                (&message).delete_after(ctx, self.timeout);
+            };
+
+            return Ok(message);
+        };
+
+        let locale = ctx.locale().await?;
+
+        let render = |page: usize| {
+            format!(
+                "{}\n\n*{}*",
+                self.pages[page],
+                locale.t(
+                    "paginator-page",
+                    &[
+                        ("page", (page + 1).into()),
+                        ("total", self.pages.len().into()),
+                    ],
+                )
+            )
+        };
+
+        let mut page = 0;
+
+        let mut message = ctx
+            .send()
+            .content(render(page))
+            .interactions(Interactions {
+                reactions: Some(
+                    [PREVIOUS, NEXT, CLOSE]
+                        .into_iter()
+                        .map(str::to_string)
+                        .collect(),
+                ),
+                restrict_reactions: true,
+            })
+            .build()
+            .await?;
+
+        loop {
+            let reaction = ctx
+                .notifiers
+                .wait_for_message_react(
+                    {
+                        let message_id = message.id.clone();
+                        let user_id = ctx.message.author.clone();
+
+                        move |(message, user, emoji)| {
+                            message.id == message_id
+                                && user == &user_id
+                                && [PREVIOUS, NEXT, CLOSE].contains(&emoji.as_str())
+                        }
+                    },
+                    Some(self.timeout),
+                )
+                .await;
+
+            let (_, user_id, emoji) = match reaction {
+                Ok(reaction) => reaction,
+                Err(StoatError::Timeout) => break,
+                Err(e) => return Err(e.into()),
+            };
+
+            if emoji == CLOSE {
+                let _ = ctx.http.delete_message(&message.channel, &message.id).await;
+
+                return Ok(message);
+            };
+
+            let _ = ctx
+                .http
+                .unreact_message(
+                    &message.channel,
+                    &message.id,
+                    &emoji,
+                    &OptionsUnreact {
+                        user_id: Some(user_id),
+                        remove_all: None,
+                    },
+                )
+                .await;
+
+            page = if emoji == PREVIOUS {
+                page.checked_sub(1).unwrap_or(self.pages.len() - 1)
+            } else {
+                (page + 1) % self.pages.len()
+            };
+
+            message = EditMessageBuilder::new(
+                ctx.http.clone(),
+                message.channel.clone(),
+                message.id.clone(),
+            )
+            .content(render(page))
+            .build()
+            .await?;
+        }
+
+        if self.delete_on_timeout {
+            let _ = ctx.http.delete_message(&message.channel, &message.id).await;
+        } else {
+            let _ = ctx
+                .http
+                .clear_reactions(&message.channel, &message.id)
+                .await;
+        };
+
+        Ok(message)
+    }
+}
@@ -0,0 +1,169 @@
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+};
+
+use futures::{StreamExt, stream};
+use lru::LruCache;
+use stoat::{
+    Context,
+    permissions::{ChannelPermission, calculate_channel_permissions, user_permissions_query},
+    types::{Channel, Member, Server},
+};
+
+use crate::{Result, State, is_not_found};
+
+const MAX_CONCURRENT_MEMBER_FETCHES: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PermissionKey {
+    server_id: String,
+    channel_id: String,
+    roles: Vec<String>,
+    timed_out: bool,
+}
+
+impl PermissionKey {
+    fn new(channel: &Channel, member: &Member) -> Self {
+        let mut roles = member.roles.clone();
+        roles.sort();
+
+        Self {
+            server_id: member.id.server.clone(),
+            channel_id: channel.id().to_string(),
+            roles,
+            timed_out: member.timeout.is_some(),
+        }
+    }
+}
+
+pub type PermissionCache = LruCache<PermissionKey, u64>;
+
+async fn resolve_members(
+    ctx: &Context,
+    state: &State,
+    server_id: &str,
+    user_ids: &[String],
+) -> Result<Vec<Member>> {
+    let mut members = Vec::new();
+    let mut missing = Vec::new();
+
+    for user_id in user_ids {
+        match ctx.cache.get_member(server_id, user_id) {
+            Some(member) => members.push(member),
+            None => missing.push(user_id.clone()),
+        };
+    }
+
+    let fetched = stream::iter(missing)
+        .map(|user_id| async move {
+            let result = ctx.http.fetch_member(server_id, &user_id).await;
+
+            (user_id, result)
+        })
+        .buffer_unordered(MAX_CONCURRENT_MEMBER_FETCHES)
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut not_in_server = Vec::new();
+
+    for (user_id, result) in fetched {
+        match result {
+            Ok(member) => {
+                ctx.cache.insert_member(member.clone());
+                members.push(member);
+            }
+            Err(e) if is_not_found(&e) => not_in_server.push(user_id),
+            Err(e) => log::warn!("Failed to fetch member {user_id} in {server_id}: {e:?}"),
+        };
+    }
+
+    state.mark_not_in_server(server_id, &not_in_server).await?;
+
+    Ok(members)
+}
+
+pub async fn filter_can_view_channel(
+    ctx: &Context,
+    state: &State,
+    server: &Server,
+    channel: &Channel,
+    user_ids: &[String],
+) -> Result<HashSet<String>> {
+    let members = resolve_members(ctx, state, &server.id, user_ids).await?;
+
+    let mut groups = HashMap::<PermissionKey, Vec<Member>>::new();
+
+    for member in members {
+        groups
+            .entry(PermissionKey::new(channel, &member))
+            .or_default()
+            .push(member);
+    }
+
+    let mut visible = HashSet::new();
+
+    for (key, members) in groups {
+        let cached = state.permission_cache.lock().await.get(&key).copied();
+
+        let permissions = match cached {
+            Some(permissions) => permissions,
+            None => {
+                let Some(user) = ctx.cache.get_current_user() else {
+                    continue;
+                };
+
+                let mut query =
+                    user_permissions_query(ctx.cache.clone(), ctx.http.clone(), Cow::Owned(user))
+                        .channel(Cow::Borrowed(channel))
+                        .server(Cow::Borrowed(server))
+                        .member(Cow::Borrowed(&members[0]));
+
+                let permissions = u64::from(calculate_channel_permissions(&mut query).await);
+
+                state.permission_cache.lock().await.put(key, permissions);
+
+                permissions
+            }
+        };
+
+        let can_view = permissions & ChannelPermission::ViewChannel as u64 != 0;
+
+        visible.extend(
+            members
+                .into_iter()
+                .filter(|member| can_view || member.id.user == server.owner)
+                .map(|member| member.id.user),
+        );
+    }
+
+    Ok(visible)
+}
+
+pub async fn invalidate_channel_permissions(state: &State, channel_id: &str) {
+    let mut cache = state.permission_cache.lock().await;
+
+    let keys = cache
+        .iter()
+        .filter(|(key, _)| key.channel_id == channel_id)
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+
+    for key in keys {
+        cache.pop(&key);
+    }
+}
+
+pub async fn invalidate_server_permissions(state: &State, server_id: &str) {
+    let mut cache = state.permission_cache.lock().await;
+
+    let keys = cache
+        .iter()
+        .filter(|(key, _)| key.server_id == server_id)
+        .map(|(key, _)| key.clone())
+        .collect::<Vec<_>>();
+
+    for key in keys {
+        cache.pop(&key);
+    }
+}
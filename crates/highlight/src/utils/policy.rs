@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use stoat::{async_trait, commands::Check, types::ChannelPermission};
+
+use crate::{CmdCtx, CommandPolicy, ContextLocaleExt, Error, Result, State};
+
+pub type CommandPolicies = HashMap<String, CommandPolicy>;
+
+pub const PROTECTED_COMMANDS: [&str; 2] = ["command", "help"];
+
+pub struct CommandPolicyCheck;
+
+#[async_trait]
+impl Check<Error, State> for CommandPolicyCheck {
+    async fn run(&self, ctx: CmdCtx) -> Result<bool> {
+        let (Some(command), Ok(server)) = (ctx.command.as_ref(), ctx.get_current_server()) else {
+            return Ok(true);
+        };
+
+        let name = command.parents.first().unwrap_or(&command.name);
+
+        if PROTECTED_COMMANDS.contains(&name.as_str()) {
+            return Ok(true);
+        };
+
+        let policies = ctx.state.get_command_policies(&server.id).await?;
+
+        let Some(policy) = policies.get(name) else {
+            return Ok(true);
+        };
+
+        if policy.disabled {
+            return Err(Error::UserError(
+                ctx.locale().await?.t("policy-disabled", &[]),
+            ));
+        };
+
+        if ctx
+            .get_author_server_permissions()
+            .await
+            .has(ChannelPermission::ManageServer as u64)
+        {
+            return Ok(true);
+        };
+
+        if !policy.channels.is_empty() && !policy.channels.contains(&ctx.message.channel) {
+            return Err(Error::UserError(
+                ctx.locale().await?.t(
+                    "policy-wrong-channel",
+                    &[(
+                        "channels",
+                        policy
+                            .channels
+                            .iter()
+                            .map(|channel| format!("<#{channel}>"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                            .into(),
+                    )],
+                ),
+            ));
+        };
+
+        let roles = ctx
+            .get_member()
+            .await
+            .map(|member| member.roles)
+            .unwrap_or_default();
+
+        if roles.iter().any(|role| policy.denied_roles.contains(role))
+            || (!policy.allowed_roles.is_empty()
+                && !roles.iter().any(|role| policy.allowed_roles.contains(role)))
+        {
+            return Err(Error::UserError(
+                ctx.locale().await?.t("policy-not-allowed", &[]),
+            ));
+        };
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,190 @@
+use std::fmt;
+
+use stoat::{Context, MemberExt, types::OptionsUnreact};
+
+use crate::{Error, Locale, ReactionRole, ReactionRolePanel, Result, State};
+
+pub const MAX_REACTION_ROLES: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReactionRoleMode {
+    Unique,
+    Multi,
+}
+
+impl ReactionRoleMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReactionRoleMode::Unique => "unique",
+            ReactionRoleMode::Multi => "multi",
+        }
+    }
+
+    pub fn parse(locale: &Locale, value: &str) -> Result<Self> {
+        match value {
+            "unique" => Ok(ReactionRoleMode::Unique),
+            "multi" => Ok(ReactionRoleMode::Multi),
+            _ => Err(Error::UserError(
+                locale.t("reactionroles-unknown-mode", &[("mode", value.into())]),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ReactionRoleMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl TryFrom<String> for ReactionRoleMode {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        Self::parse(&Locale::default(), &value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReactionRoleMenu {
+    pub panel: ReactionRolePanel,
+    pub roles: Vec<ReactionRole>,
+}
+
+pub fn format_emoji(emoji: &str) -> String {
+    if emoji.len() == 26 && emoji.chars().all(|c| c.is_ascii_alphanumeric()) {
+        format!(":{emoji}:")
+    } else {
+        emoji.to_string()
+    }
+}
+
+fn unreact_key(message_id: &str, user_id: &str, emoji_id: &str) -> String {
+    format!("{message_id}:{user_id}:{emoji_id}")
+}
+
+pub async fn handle_reaction_role(
+    ctx: &Context,
+    state: &State,
+    message_id: &str,
+    user_id: &str,
+    emoji_id: &str,
+    added: bool,
+) -> Result<()> {
+    if ctx.cache.get_current_user_id().as_deref() == Some(user_id) {
+        return Ok(());
+    };
+
+    if !added
+        && state
+            .pending_unreacts
+            .lock()
+            .await
+            .remove(&unreact_key(message_id, user_id, emoji_id))
+    {
+        return Ok(());
+    };
+
+    let Some(menu) = state.get_reaction_role_menu(message_id).await? else {
+        return Ok(());
+    };
+
+    let Some(reaction_role) = menu.roles.iter().find(|role| role.emoji == emoji_id) else {
+        return Ok(());
+    };
+
+    let Some(server) = ctx.cache.get_server(&menu.panel.server_id) else {
+        return Ok(());
+    };
+
+    let Some(role) = server.roles.get(&reaction_role.role_id).cloned() else {
+        return Ok(());
+    };
+
+    // reaction events run concurrently and role edits replace the whole list, so serialise per member
+    let _guard = state
+        .reaction_role_locks
+        .lock(format!("{}:{user_id}", server.id))
+        .await;
+
+    let member = ctx.http.fetch_member(&server.id, user_id).await?;
+    ctx.cache.insert_member(member.clone());
+
+    if !added {
+        if member.roles.contains(&role.id) {
+            let member = member.remove_roles(&ctx.http, &[role]).await?;
+            ctx.cache.insert_member(member);
+        };
+
+        return Ok(());
+    };
+
+    if menu.panel.mode == ReactionRoleMode::Unique {
+        let others = menu
+            .roles
+            .iter()
+            .filter(|other| other.emoji != emoji_id)
+            .collect::<Vec<_>>();
+
+        let remove = others
+            .iter()
+            .filter(|other| member.roles.contains(&other.role_id))
+            .filter_map(|other| server.roles.get(&other.role_id).cloned())
+            .collect::<Vec<_>>();
+
+        let member = if remove.is_empty() {
+            member
+        } else {
+            member.remove_roles(&ctx.http, &remove).await?
+        };
+
+        let member = if member.roles.contains(&role.id) {
+            member
+        } else {
+            member.add_roles(&ctx.http, &[role]).await?
+        };
+
+        ctx.cache.insert_member(member);
+
+        let message = ctx
+            .http
+            .fetch_message(&menu.panel.channel_id, message_id)
+            .await?;
+
+        for other in others {
+            if !message
+                .reactions
+                .get(&other.emoji)
+                .is_some_and(|users| users.contains(user_id))
+            {
+                continue;
+            };
+
+            let key = unreact_key(message_id, user_id, &other.emoji);
+
+            state.pending_unreacts.lock().await.insert(key.clone());
+
+            if ctx
+                .http
+                .unreact_message(
+                    &menu.panel.channel_id,
+                    message_id,
+                    &other.emoji,
+                    &OptionsUnreact {
+                        user_id: Some(user_id.to_string()),
+                        remove_all: None,
+                    },
+                )
+                .await
+                .is_err()
+            {
+                state.pending_unreacts.lock().await.remove(&key);
+            };
+        }
+    } else if !member.roles.contains(&role.id) {
+        let member = member.add_roles(&ctx.http, &[role]).await?;
+        ctx.cache.insert_member(member);
+    };
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use std::{
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use tokio::time::interval;
+
+use crate::{Config, State};
+
+fn modified_at(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+pub fn reload_config_logged(state: &State) {
+    match state.reload_config() {
+        Ok(changes) if changes.is_empty() => log::info!("Config reloaded, nothing changed"),
+        Ok(changes) => {
+            log::info!("Config reloaded:");
+
+            for change in changes {
+                log::info!("  {change}");
+            }
+        }
+        Err(e) => log::error!("Refusing to reload config: {e}"),
+    }
+}
+
+// SIGHUP is the usual way to ask a daemon to reload, other platforms rely on the file watcher
+#[cfg(unix)]
+fn spawn_hangup_listener(state: State) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    tokio::spawn(async move {
+        let mut hangup = match signal(SignalKind::hangup()) {
+            Ok(hangup) => hangup,
+            Err(e) => {
+                log::warn!("Failed to listen for SIGHUP: {e:?}");
+                return;
+            }
+        };
+
+        while hangup.recv().await.is_some() {
+            reload_config_logged(&state);
+        }
+    });
+}
+
+pub fn spawn_config_watcher(state: State) {
+    #[cfg(unix)]
+    spawn_hangup_listener(state.clone());
+
+    tokio::spawn(async move {
+        let path = Config::path();
+        let mut last_modified = modified_at(&path);
+
+        let mut interval = interval(Duration::from_secs(5));
+
+        loop {
+            interval.tick().await;
+
+            let modified = modified_at(&path);
+
+            if modified == last_modified {
+                continue;
+            };
+
+            last_modified = modified;
+
+            reload_config_logged(&state);
+        }
+    });
+}
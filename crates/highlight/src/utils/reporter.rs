@@ -0,0 +1,252 @@
+use std::{
+    backtrace::Backtrace,
+    collections::HashMap,
+    panic,
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use stoat::{
+    EmbedExt, HttpClient,
+    builders::{ExecuteWebhookBuilder, SendMessageBuilder},
+    types::SendableEmbed,
+};
+use tokio::sync::mpsc;
+
+use crate::{CmdCtx, Error, State};
+
+const DEDUP_WINDOW: Duration = Duration::from_secs(60 * 10);
+const SEEN_EXPIRY: Duration = Duration::from_secs(60 * 60 * 24);
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+const RATE_LIMIT: usize = 5;
+const MAX_BACKTRACE_LENGTH: usize = 1500;
+
+#[derive(Debug, Clone, Default)]
+pub struct ErrorReport {
+    pub error: String,
+    pub command: Option<String>,
+    pub invoker: Option<String>,
+    pub server: Option<String>,
+    pub backtrace: Option<String>,
+}
+
+impl ErrorReport {
+    pub fn new(error: impl Into<String>) -> Self {
+        Self {
+            error: error.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_context(ctx: &CmdCtx, error: &Error) -> Self {
+        Self {
+            error: format!("{error:?}"),
+            command: ctx.command.as_ref().map(|command| {
+                let mut parts = command.parents.clone();
+                parts.push(command.name.clone());
+                parts.join(" ")
+            }),
+            invoker: Some(ctx.message.author.clone()),
+            server: ctx.get_current_server().ok().map(|server| server.id),
+            backtrace: None,
+        }
+    }
+
+    fn fingerprint(&self) -> String {
+        format!(
+            "{}:{}",
+            self.command.as_deref().unwrap_or_default(),
+            self.error
+        )
+    }
+
+    fn embed(&self, id: &str, repeats: usize, suppressed: usize) -> SendableEmbed {
+        let mut lines = vec![format!("```\n{}\n```", self.error)];
+
+        if let Some(command) = &self.command {
+            lines.push(format!("**Command:** `{command}`"));
+        };
+
+        if let Some(invoker) = &self.invoker {
+            lines.push(format!("**Invoker:** <@{invoker}> ({invoker})"));
+        };
+
+        if let Some(server) = &self.server {
+            lines.push(format!("**Server:** {server}"));
+        };
+
+        if repeats > 0 {
+            lines.push(format!(
+                "**Repeats:** occurred {repeats} more times after the last report"
+            ));
+        };
+
+        if suppressed > 0 {
+            lines.push(format!(
+                "*{suppressed} other reports were dropped due to rate limiting.*"
+            ));
+        };
+
+        if let Some(backtrace) = &self.backtrace {
+            let backtrace = match backtrace.char_indices().nth(MAX_BACKTRACE_LENGTH) {
+                Some((i, _)) => format!("{}\n...", &backtrace[..i]),
+                None => backtrace.clone(),
+            };
+
+            lines.push(format!("**Backtrace:**\n```\n{backtrace}\n```"));
+        };
+
+        SendableEmbed::default()
+            .title(format!("Error {id}"))
+            .description(lines.join("\n"))
+            .colour("#E74C3C".to_string())
+    }
+}
+
+#[derive(Debug)]
+struct SeenError {
+    id: String,
+    last_reported: Instant,
+    repeats: usize,
+}
+
+#[derive(Debug, Default)]
+struct ReporterState {
+    seen: HashMap<String, SeenError>,
+    sent: Vec<Instant>,
+    suppressed: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ErrorReporter {
+    state: Arc<Mutex<ReporterState>>,
+    http: Arc<OnceLock<HttpClient>>,
+}
+
+impl ErrorReporter {
+    pub fn set_http(&self, http: HttpClient) {
+        let _ = self.http.set(http);
+    }
+}
+
+fn generate_error_id() -> String {
+    let id = ulid::Ulid::new().to_string();
+
+    id[id.len() - 8..].to_string()
+}
+
+pub fn report_error(state: &State, report: ErrorReport) -> String {
+    let now = Instant::now();
+
+    let (id, repeats, suppressed) = {
+        let mut reporter = state.error_reporter.state.lock().unwrap();
+
+        reporter
+            .seen
+            .retain(|_, seen| now.duration_since(seen.last_reported) < SEEN_EXPIRY);
+
+        let fingerprint = report.fingerprint();
+
+        let repeats = match reporter.seen.get_mut(&fingerprint) {
+            Some(seen) if now.duration_since(seen.last_reported) < DEDUP_WINDOW => {
+                seen.repeats += 1;
+
+                log::error!("[{}] {}", seen.id, report.error);
+
+                return seen.id.clone();
+            }
+            Some(seen) => seen.repeats,
+            None => 0,
+        };
+
+        let id = generate_error_id();
+
+        reporter.seen.insert(
+            fingerprint,
+            SeenError {
+                id: id.clone(),
+                last_reported: now,
+                repeats: 0,
+            },
+        );
+
+        reporter
+            .sent
+            .retain(|sent| now.duration_since(*sent) < RATE_LIMIT_WINDOW);
+
+        if reporter.sent.len() >= RATE_LIMIT {
+            reporter.suppressed += 1;
+
+            log::error!("[{id}] {}", report.error);
+
+            return id;
+        };
+
+        reporter.sent.push(now);
+
+        (id, repeats, std::mem::take(&mut reporter.suppressed))
+    };
+
+    log::error!("[{id}] {}", report.error);
+
+    let config = state.config().errors.clone();
+
+    if let (Some(config), Some(http)) = (config, state.error_reporter.http.get().cloned()) {
+        let embed = report.embed(&id, repeats, suppressed);
+
+        tokio::spawn(async move {
+            let res = if let Some((webhook_id, token)) = config.webhook() {
+                ExecuteWebhookBuilder::new(http, webhook_id, token)
+                    .embed(embed)
+                    .build()
+                    .await
+            } else if let Some(channel) = config.channel {
+                SendMessageBuilder::new(http, channel)
+                    .embed(embed)
+                    .build()
+                    .await
+            } else {
+                return;
+            };
+
+            if let Err(e) = res {
+                log::warn!("Failed to send error report: {e:?}");
+            };
+        });
+    };
+
+    id
+}
+
+pub fn install_panic_reporter(state: State) {
+    let (sender, mut receiver) = mpsc::unbounded_channel::<ErrorReport>();
+
+    let default_hook = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+
+        let message = info
+            .payload()
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| info.payload().downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_string());
+
+        let location = info
+            .location()
+            .map(|location| format!(" at {location}"))
+            .unwrap_or_default();
+
+        let _ = sender.send(ErrorReport {
+            backtrace: Some(Backtrace::force_capture().to_string()),
+            ..ErrorReport::new(format!("panicked{location}: {message}"))
+        });
+    }));
+
+    tokio::spawn(async move {
+        while let Some(report) = receiver.recv().await {
+            report_error(&state, report);
+        }
+    });
+}
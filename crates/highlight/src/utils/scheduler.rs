@@ -0,0 +1,209 @@
+use std::{
+    sync::atomic::Ordering,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use stoat::{EmbedExt, HttpClient, builders::SendMessageBuilder, types::SendableEmbed};
+use tokio::{select, time::interval};
+
+use crate::{
+    CaseAction, Error, NewModCase, Reminder, Result, State, is_not_found, log_mod_case,
+    unlock_channel,
+};
+
+const REMINDER_RETRY_DELAY: i64 = 60;
+const MAX_REMINDER_ATTEMPTS: u32 = 5;
+
+pub fn spawn_scheduler(http: HttpClient, state: State) {
+    if state.scheduler_started.swap(true, Ordering::SeqCst) {
+        return;
+    };
+
+    state.shutdown.clone().spawn(async move {
+        let mut interval = interval(Duration::from_secs(15));
+
+        loop {
+            select! {
+                _ = interval.tick() => {},
+                _ = state.shutdown.stopped() => break,
+            };
+
+            if let Err(e) = process_due_unbans(&http, &state).await {
+                log::error!("{e:?}");
+            };
+
+            if let Err(e) = process_due_reminders(&http, &state).await {
+                log::error!("{e:?}");
+            };
+
+            if let Err(e) = process_due_lockdowns(&http, &state).await {
+                log::error!("{e:?}");
+            };
+        }
+    });
+}
+
+async fn process_due_unbans(http: &HttpClient, state: &State) -> Result<()> {
+    for unban in state.fetch_due_unbans().await? {
+        if let Err(e) = http.unban_member(&unban.server_id, &unban.user_id).await {
+            log::warn!(
+                "Failed to unban {} from {}: {e:?}",
+                unban.user_id,
+                unban.server_id
+            );
+
+            if is_not_found(&e) {
+                state
+                    .remove_scheduled_unban(&unban.server_id, &unban.user_id)
+                    .await?;
+            };
+
+            continue;
+        };
+
+        state
+            .remove_scheduled_unban(&unban.server_id, &unban.user_id)
+            .await?;
+
+        let locale = state.server_locale(&unban.server_id).await?;
+
+        log_mod_case(
+            http,
+            state,
+            NewModCase {
+                server_id: unban.server_id,
+                action: CaseAction::Unban,
+                target_id: Some(unban.user_id),
+                moderator_id: http.user_id.clone().unwrap_or_default(),
+                reason: Some(locale.t("tempban-expired", &[])),
+                duration: None,
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn deliver_reminder(http: &HttpClient, state: &State, reminder: &Reminder) -> Result<()> {
+    let locale = state.resolve_locale(&reminder.user_id, None).await?;
+
+    let jump_link = reminder
+        .message_id
+        .as_ref()
+        .map(|message_id| {
+            format!(
+                "\n\n[{}]({}/channel/{}/{message_id})",
+                locale.t("jump-to", &[]),
+                http.api_config.app,
+                reminder.channel_id
+            )
+        })
+        .unwrap_or_default();
+
+    let embed = SendableEmbed::default()
+        .title(locale.t("remind-title", &[]))
+        .description(format!("{}{jump_link}", reminder.content));
+
+    let dm = match http.open_dm(&reminder.user_id).await {
+        Ok(dm_channel) => {
+            SendMessageBuilder::new(http.clone(), dm_channel.id().to_string())
+                .embed(embed.clone())
+                .build()
+                .await
+        }
+        Err(e) => Err(e),
+    };
+
+    if dm.is_err() {
+        SendMessageBuilder::new(http.clone(), reminder.channel_id.clone())
+            .content(format!("<@{}>", reminder.user_id))
+            .embed(embed)
+            .build()
+            .await?;
+    };
+
+    Ok(())
+}
+
+async fn process_due_reminders(http: &HttpClient, state: &State) -> Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    for reminder in state.fetch_due_reminders().await? {
+        let recurring = matches!(reminder.interval, Some(interval) if interval > 0);
+
+        match deliver_reminder(http, state, &reminder).await {
+            Ok(()) => {}
+            // neither the dm nor the original channel exist anymore, so it can never be delivered
+            Err(Error::StoatError(e)) if is_not_found(&e) => {
+                log::warn!("Dropping reminder {}, target is gone: {e:?}", reminder.id);
+
+                state.reminder_attempts.lock().await.remove(&reminder.id);
+                state.remove_reminder(reminder.id).await?;
+
+                continue;
+            }
+            // recurring reminders keep their schedule, the next occurrence is the retry
+            Err(e) if !recurring => {
+                let attempts = {
+                    let mut attempts = state.reminder_attempts.lock().await;
+                    let attempts = attempts.entry(reminder.id).or_default();
+                    *attempts += 1;
+                    *attempts
+                };
+
+                if attempts < MAX_REMINDER_ATTEMPTS {
+                    log::warn!(
+                        "Failed to deliver reminder {} (attempt {attempts}), retrying: {e:?}",
+                        reminder.id
+                    );
+
+                    state
+                        .reschedule_reminder(
+                            reminder.id,
+                            now + REMINDER_RETRY_DELAY * attempts as i64,
+                        )
+                        .await?;
+
+                    continue;
+                };
+
+                log::warn!(
+                    "Giving up on reminder {} after {attempts} attempts: {e:?}",
+                    reminder.id
+                );
+            }
+            Err(e) => log::warn!("Failed to deliver reminder {}: {e:?}", reminder.id),
+        };
+
+        state.reminder_attempts.lock().await.remove(&reminder.id);
+
+        match reminder.interval {
+            Some(interval) if recurring => {
+                let mut due_at = reminder.due_at + interval;
+
+                if due_at <= now {
+                    due_at += ((now - due_at) / interval + 1) * interval;
+                };
+
+                state.reschedule_reminder(reminder.id, due_at).await?;
+            }
+            _ => state.remove_reminder(reminder.id).await?,
+        };
+    }
+
+    Ok(())
+}
+
+async fn process_due_lockdowns(http: &HttpClient, state: &State) -> Result<()> {
+    for lockdown in state.fetch_due_lockdowns().await? {
+        if let Err(e) = unlock_channel(http, state, &lockdown).await {
+            log::warn!("Failed to unlock {}: {e:?}", lockdown.channel_id);
+        };
+    }
+
+    Ok(())
+}
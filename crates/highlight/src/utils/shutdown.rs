@@ -0,0 +1,77 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{sync::watch, time::timeout};
+
+#[derive(Debug, Clone)]
+pub struct Shutdown {
+    stopping: Arc<watch::Sender<bool>>,
+    tasks: Arc<watch::Sender<usize>>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self {
+            stopping: Arc::new(watch::Sender::new(false)),
+            tasks: Arc::new(watch::Sender::new(0)),
+        }
+    }
+}
+
+struct TaskGuard(Arc<watch::Sender<usize>>);
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        self.0.send_modify(|tasks| *tasks -= 1);
+    }
+}
+
+impl Shutdown {
+    pub fn is_stopping(&self) -> bool {
+        *self.stopping.borrow()
+    }
+
+    pub async fn stopped(&self) {
+        let _ = self
+            .stopping
+            .subscribe()
+            .wait_for(|stopping| *stopping)
+            .await;
+    }
+
+    pub fn spawn<F: Future<Output = ()> + Send + 'static>(&self, fut: F) {
+        self.tasks.send_modify(|tasks| *tasks += 1);
+
+        let guard = TaskGuard(self.tasks.clone());
+
+        tokio::spawn(async move {
+            let _guard = guard;
+
+            fut.await
+        });
+    }
+
+    pub async fn drain(&self, deadline: Duration) -> bool {
+        self.stopping.send_replace(true);
+
+        let mut tasks = self.tasks.subscribe();
+
+        let pending = *tasks.borrow();
+
+        if pending > 0 {
+            log::info!("Waiting for {pending} tasks to finish.");
+        };
+
+        let drained = timeout(deadline, tasks.wait_for(|tasks| *tasks == 0))
+            .await
+            .is_ok();
+
+        if !drained {
+            log::warn!(
+                "Shutdown deadline reached with {} tasks still running.",
+                *tasks.borrow()
+            );
+        };
+
+        drained
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    borrow::Cow,
+    time::{Duration, Instant},
+};
+
+use lru::LruCache;
+use stoat::{
+    Context,
+    permissions::{ChannelPermission, calculate_channel_permissions, user_permissions_query},
+    types::{Message, Server},
+};
+
+use crate::{Result, State};
+
+pub type SlowmodeHistory = LruCache<(String, String), Instant>;
+
+async fn can_bypass(ctx: &Context, message: &Message, server: &Server) -> bool {
+    if message.author == server.owner {
+        return true;
+    };
+
+    let (Some(user), Some(channel)) = (
+        message.user.clone(),
+        ctx.cache.get_channel(&message.channel),
+    ) else {
+        return false;
+    };
+
+    let Some(member) = message
+        .member
+        .clone()
+        .or_else(|| ctx.cache.get_member(&server.id, &message.author))
+    else {
+        return false;
+    };
+
+    let mut query = user_permissions_query(ctx.cache.clone(), ctx.http.clone(), Cow::Owned(user))
+        .channel(Cow::Owned(channel))
+        .server(Cow::Borrowed(server))
+        .member(Cow::Owned(member));
+
+    calculate_channel_permissions(&mut query)
+        .await
+        .has(ChannelPermission::ManageMessages as u64)
+}
+
+pub async fn run_slowmode(
+    ctx: &Context,
+    state: &State,
+    message: &Message,
+    server: &Server,
+) -> Result<bool> {
+    let Some(interval) = state.get_slowmode(&message.channel).await? else {
+        return Ok(false);
+    };
+
+    if can_bypass(ctx, message, server).await {
+        return Ok(false);
+    };
+
+    let key = (message.channel.clone(), message.author.clone());
+    let now = Instant::now();
+
+    {
+        let mut history = state.slowmode_history.lock().await;
+
+        if history
+            .get(&key)
+            .is_none_or(|sent| now.duration_since(*sent) >= Duration::from_secs(interval))
+        {
+            history.put(key, now);
+
+            return Ok(false);
+        };
+    }
+
+    ctx.http
+        .delete_message(&message.channel, &message.id)
+        .await?;
+
+    Ok(true)
+}
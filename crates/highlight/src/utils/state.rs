@@ -1,55 +1,154 @@
-use futures::{TryStreamExt, lock::Mutex};
+use futures::lock::Mutex;
 use lru::LruCache;
 use regex::Regex;
-use sqlx::PgPool;
 use std::{
     collections::{HashMap, HashSet},
     num::NonZero,
-    sync::Arc,
+    sync::{Arc, RwLock as StdRwLock, atomic::AtomicBool},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use sysinfo::System;
 use tokio::sync::RwLock;
 
-use crate::{Config, Error, OriginalMessage, ServerConfig, create_highlight_regex};
+use crate::{
+    AutomodAction, AutomodConfig, AutomodExemption, AutomodHistory, AutomodRuleKind,
+    BlacklistEntry, CaseAction, Cluster, CommandPolicies, CommandPolicy, Config, ConfigError,
+    Error, ErrorReporter, Invalidation, InviteCache, KeyedMutex, Locale, Lockdown, Metrics,
+    ModCase, NewModCase, OriginalMessage, PermissionCache, ReactionRole, ReactionRoleMenu,
+    ReactionRolePanel, Reminder, ScheduledUnban, ServerConfig, Shutdown, SlowmodeHistory,
+    SnipeBuffer, Storage, WarnThreshold, connect_storage, create_highlight_regex,
+};
 
 #[derive(Clone, Debug)]
 pub struct State {
-    pub config: Arc<Config>,
-    pub pool: PgPool,
+    config: Arc<StdRwLock<Arc<Config>>>,
+    pub storage: Arc<dyn Storage>,
     pub cached_keywords: Arc<Mutex<LruCache<String, HashMap<String, (Vec<String>, Regex)>>>>,
     pub cached_blocked: Arc<Mutex<LruCache<String, HashSet<String>>>>,
     pub known_not_in_server: Arc<RwLock<HashMap<String, HashSet<String>>>>,
     pub system: Arc<Mutex<System>>,
+    pub starboard_locks: KeyedMutex,
+    pub scheduler_started: Arc<AtomicBool>,
+    pub caches_warmed: Arc<AtomicBool>,
+    pub cached_automod: Arc<Mutex<LruCache<String, Arc<AutomodConfig>>>>,
+    pub automod_history: Arc<Mutex<AutomodHistory>>,
+    pub invite_cache: Arc<Mutex<InviteCache>>,
+    pub cached_prefixes: Arc<Mutex<LruCache<String, Vec<String>>>>,
+    pub cached_command_policies: Arc<Mutex<LruCache<String, Arc<CommandPolicies>>>>,
+    pub metrics: Arc<Metrics>,
+    pub error_reporter: ErrorReporter,
+    pub blacklist: Arc<RwLock<HashSet<String>>>,
+    pub status_override: Arc<RwLock<Option<String>>>,
+    pub cached_slowmodes: Arc<Mutex<LruCache<String, Option<u64>>>>,
+    pub slowmode_history: Arc<Mutex<SlowmodeHistory>>,
+    pub snipes: Arc<Mutex<SnipeBuffer>>,
+    pub cached_reaction_roles: Arc<Mutex<LruCache<String, Option<Arc<ReactionRoleMenu>>>>>,
+    pub reaction_role_locks: KeyedMutex,
+    pub pending_unreacts: Arc<Mutex<HashSet<String>>>,
+    pub cached_locales: Arc<Mutex<LruCache<String, Option<String>>>>,
+    pub shutdown: Shutdown,
+    pub permission_cache: Arc<Mutex<PermissionCache>>,
+    pub member_counts: Arc<Mutex<HashMap<String, usize>>>,
+    pub reminder_attempts: Arc<Mutex<HashMap<i32, u32>>>,
+    pub cluster: Option<Cluster>,
 }
 
 impl State {
-    pub async fn new() -> Self {
-        let config = Arc::new(
-            toml::from_str::<Config>(&std::fs::read_to_string("Highlight.toml").unwrap()).unwrap(),
-        );
+    pub async fn new(config: Config) -> Result<Self, Error> {
+        let storage = connect_storage(&config.database.url).await?;
 
-        let pool = PgPool::connect(&config.database.url).await.unwrap();
+        let cluster = config
+            .cluster
+            .clone()
+            .map(|cluster| Cluster::new(cluster, storage.as_ref()));
+
+        let config = Arc::new(StdRwLock::new(Arc::new(config)));
 
         let cached_keywords = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
         let cached_blocked = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
         let known_not_in_server = Arc::new(RwLock::new(HashMap::new()));
         let system = Arc::new(Mutex::new(System::new_all()));
-
-        Self {
-            pool,
+        let starboard_locks = KeyedMutex::default();
+        let scheduler_started = Arc::new(AtomicBool::new(false));
+        let caches_warmed = Arc::new(AtomicBool::new(false));
+        let cached_automod = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+        let automod_history = Arc::new(Mutex::new(LruCache::new(NonZero::new(10000).unwrap())));
+        let invite_cache = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+        let cached_prefixes = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+        let cached_command_policies =
+            Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+
+        let metrics = Arc::new(Metrics::default());
+        let error_reporter = ErrorReporter::default();
+        let blacklist = Arc::new(RwLock::new(HashSet::new()));
+        let status_override = Arc::new(RwLock::new(None));
+        let cached_slowmodes = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+        let slowmode_history = Arc::new(Mutex::new(LruCache::new(NonZero::new(10000).unwrap())));
+        let snipes = Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+        let cached_reaction_roles =
+            Arc::new(Mutex::new(LruCache::new(NonZero::new(1000).unwrap())));
+        let reaction_role_locks = KeyedMutex::default();
+        let pending_unreacts = Arc::new(Mutex::new(HashSet::new()));
+        let cached_locales = Arc::new(Mutex::new(LruCache::new(NonZero::new(10000).unwrap())));
+        let shutdown = Shutdown::default();
+        let permission_cache = Arc::new(Mutex::new(LruCache::new(NonZero::new(10000).unwrap())));
+        let member_counts = Arc::new(Mutex::new(HashMap::new()));
+        let reminder_attempts = Arc::new(Mutex::new(HashMap::new()));
+
+        Ok(Self {
+            storage,
             config,
             cached_keywords,
             cached_blocked,
             known_not_in_server,
             system,
-        }
+            starboard_locks,
+            scheduler_started,
+            caches_warmed,
+            cached_automod,
+            automod_history,
+            invite_cache,
+            cached_prefixes,
+            cached_command_policies,
+            metrics,
+            error_reporter,
+            blacklist,
+            status_override,
+            cached_slowmodes,
+            slowmode_history,
+            snipes,
+            cached_reaction_roles,
+            reaction_role_locks,
+            pending_unreacts,
+            cached_locales,
+            shutdown,
+            permission_cache,
+            member_counts,
+            reminder_attempts,
+            cluster,
+        })
+    }
+
+    pub fn config(&self) -> Arc<Config> {
+        self.config.read().unwrap().clone()
+    }
+
+    pub fn reload_config(&self) -> Result<Vec<String>, ConfigError> {
+        let new = Config::load()?;
+
+        let mut config = self.config.write().unwrap();
+
+        config.check_reload(&new)?;
+
+        let changes = config.diff(&new);
+
+        *config = Arc::new(new);
+
+        Ok(changes)
     }
 
     pub async fn ensure_db(&self) {
-        sqlx::raw_sql(include_str!("../../schema.psql"))
-            .execute(&self.pool)
-            .await
-            .unwrap();
+        self.storage.migrate().await.unwrap();
     }
 
     pub async fn fetch_keywords_for_user(
@@ -57,27 +156,18 @@ impl State {
         user_id: &str,
         server_id: &str,
     ) -> Result<Vec<String>, Error> {
-        sqlx::query_scalar("select keyword from highlights where user_id=$1 and server_id=$2")
-            .bind(&user_id)
-            .bind(&server_id)
-            .fetch_all(&self.pool)
+        self.storage
+            .fetch_keywords_for_user(user_id, server_id)
             .await
-            .map_err(|e| e.into())
     }
 
     pub async fn fetch_keywords_for_server(
         &self,
         server_id: &str,
     ) -> Result<HashMap<String, (Vec<String>, Regex)>, Error> {
-        let mut iter = sqlx::query_as::<_, (String, String)>(
-            "select user_id, keyword from highlights where server_id=$1",
-        )
-        .bind(&server_id)
-        .fetch(&self.pool);
-
         let mut mapping = HashMap::<String, Vec<String>>::new();
 
-        while let Some((user_id, keyword)) = iter.try_next().await? {
+        for (user_id, keyword) in self.storage.fetch_keywords_for_server(server_id).await? {
             mapping.entry(user_id).or_default().push(keyword)
         }
 
@@ -109,19 +199,105 @@ impl State {
         }
     }
 
+    pub async fn fetch_busiest_servers(&self) -> Result<Vec<String>, Error> {
+        self.storage.fetch_busiest_servers().await
+    }
+
+    pub async fn load_membership_hints(&self, ttl: Duration) -> Result<(), Error> {
+        let cutoff = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .saturating_sub(ttl)
+            .as_secs() as i64;
+
+        self.storage.remove_stale_membership_hints(cutoff).await?;
+
+        let hints = self.storage.fetch_membership_hints().await?;
+
+        let mut known_not_in_server = self.known_not_in_server.write().await;
+
+        for (server_id, user_id) in hints {
+            known_not_in_server
+                .entry(server_id)
+                .or_default()
+                .insert(user_id);
+        }
+
+        Ok(())
+    }
+
+    pub async fn mark_not_in_server(
+        &self,
+        server_id: &str,
+        user_ids: &[String],
+    ) -> Result<(), Error> {
+        if user_ids.is_empty() {
+            return Ok(());
+        };
+
+        self.storage
+            .add_membership_hints(server_id, user_ids)
+            .await?;
+
+        self.known_not_in_server
+            .write()
+            .await
+            .entry(server_id.to_string())
+            .or_default()
+            .extend(user_ids.iter().cloned());
+
+        Ok(())
+    }
+
+    pub async fn set_member_count(&self, server_id: &str, count: usize) {
+        self.member_counts
+            .lock()
+            .await
+            .insert(server_id.to_string(), count);
+    }
+
+    // only adjusts counts seeded from a full member list, guessing would drift further over time
+    pub async fn adjust_member_count(&self, server_id: &str, joined: bool) {
+        if let Some(count) = self.member_counts.lock().await.get_mut(server_id) {
+            *count = if joined {
+                *count + 1
+            } else {
+                count.saturating_sub(1)
+            };
+        };
+    }
+
+    pub async fn get_member_count(&self, server_id: &str) -> Option<usize> {
+        self.member_counts.lock().await.get(server_id).copied()
+    }
+
+    pub async fn mark_in_server(&self, server_id: &str, user_ids: &[String]) -> Result<(), Error> {
+        let removed = match self.known_not_in_server.write().await.get_mut(server_id) {
+            Some(set) => user_ids
+                .iter()
+                .filter(|user_id| set.remove(*user_id))
+                .cloned()
+                .collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+
+        if !removed.is_empty() {
+            self.storage
+                .remove_membership_hints(server_id, &removed)
+                .await?;
+        };
+
+        Ok(())
+    }
+
     pub async fn add_keyword(
         &self,
         user_id: String,
         server_id: String,
         keyword: String,
     ) -> Result<(), Error> {
-        let mut tx = self.pool.begin().await?;
-
-        sqlx::query("insert into highlights (user_id, server_id, keyword) values ($1, $2, $3)")
-            .bind(&user_id)
-            .bind(&server_id)
-            .bind(&keyword)
-            .execute(&mut *tx)
+        self.storage
+            .add_keyword(&user_id, &server_id, &keyword)
             .await?;
 
         let mut lock = self.cached_keywords.lock().await;
@@ -137,8 +313,6 @@ impl State {
             }
         };
 
-        tx.commit().await?;
-
         Ok(())
     }
 
@@ -148,19 +322,11 @@ impl State {
         server_id: String,
         keyword: String,
     ) -> Result<bool, Error> {
-        let mut tx = self.pool.begin().await?;
-
-        let row_count = sqlx::query(
-            "delete from highlights where user_id=$1 and server_id=$2 and keyword=$3 returning *",
-        )
-        .bind(&user_id)
-        .bind(&server_id)
-        .bind(&keyword)
-        .execute(&mut *tx)
-        .await?
-        .rows_affected();
-
-        if row_count == 0 {
+        if !self
+            .storage
+            .remove_keyword(&user_id, &server_id, &keyword)
+            .await?
+        {
             return Ok(false);
         }
 
@@ -193,12 +359,7 @@ impl State {
         user_id: &str,
         server_id: &str,
     ) -> Result<Vec<String>, Error> {
-        let keywords =
-            sqlx::query_scalar("delete from highlights where user_id=$1 and server_id=$2")
-                .bind(user_id)
-                .bind(server_id)
-                .fetch_all(&self.pool)
-                .await?;
+        let keywords = self.storage.clear_keywords(user_id, server_id).await?;
 
         if let Some(server_keywords) = self.cached_keywords.lock().await.get_mut(server_id) {
             server_keywords.remove(user_id);
@@ -208,11 +369,7 @@ impl State {
     }
 
     pub async fn block_user(&self, user_id: String, blocked_user: String) -> Result<(), Error> {
-        sqlx::query("insert into blocks(user_id, blocked_user) values($1, $2)")
-            .bind(&user_id)
-            .bind(&blocked_user)
-            .execute(&self.pool)
-            .await?;
+        self.storage.block_user(&user_id, &blocked_user).await?;
 
         let mut lock = self.cached_blocked.lock().await;
 
@@ -224,11 +381,7 @@ impl State {
     }
 
     pub async fn unblock_user(&self, user_id: String, blocked_user: String) -> Result<(), Error> {
-        sqlx::query("delete from blocks where user_id=$1 and blocked_user=$2")
-            .bind(&user_id)
-            .bind(&blocked_user)
-            .execute(&self.pool)
-            .await?;
+        self.storage.unblock_user(&user_id, &blocked_user).await?;
 
         let mut lock = self.cached_blocked.lock().await;
 
@@ -239,17 +392,34 @@ impl State {
         Ok(())
     }
 
+    pub fn owns_server(&self, server_id: &str) -> bool {
+        self.cluster
+            .as_ref()
+            .is_none_or(|cluster| cluster.owns_server(server_id))
+    }
+
+    pub async fn apply_invalidation(&self, invalidation: Invalidation) {
+        match invalidation {
+            Invalidation::Keywords { server_id } => {
+                self.cached_keywords.lock().await.pop(&server_id);
+            }
+            Invalidation::Blocked { user_id } => {
+                self.cached_blocked.lock().await.pop(&user_id);
+            }
+            Invalidation::ServerConfig { server_id } => {
+                self.cached_prefixes.lock().await.pop(&server_id);
+                self.cached_locales.lock().await.pop(&server_id);
+            }
+        };
+    }
+
     pub async fn fetch_blocked_users(&self, user_id: String) -> Result<HashSet<String>, Error> {
         let mut lock = self.cached_blocked.lock().await;
 
         if let Some(blocked) = lock.get(&user_id) {
             Ok(blocked.clone())
         } else {
-            let blocked =
-                sqlx::query_scalar::<_, String>("select blocked_user from blocks where user_id=$1")
-                    .bind(&user_id)
-                    .fetch_all(&self.pool)
-                    .await?;
+            let blocked = self.storage.fetch_blocked_users(&user_id).await?;
 
             let set = HashSet::from_iter(blocked.into_iter());
             lock.put(user_id, set.clone());
@@ -259,10 +429,7 @@ impl State {
     }
 
     pub async fn get_total_keyword_count(&self) -> Result<i64, Error> {
-        sqlx::query_scalar::<_, i64>("select count(keyword) from highlights")
-            .fetch_one(&self.pool)
-            .await
-            .map_err(|e| e.into())
+        self.storage.get_total_keyword_count().await
     }
 
     pub async fn update_server_config_star_count(
@@ -270,13 +437,9 @@ impl State {
         server_id: &str,
         star_count: i32,
     ) -> Result<(), Error> {
-        sqlx::query("insert into server_configs(server_id, star_count) values ($1, $2) on conflict (server_id) do update set star_count=$2")
-            .bind(server_id)
-            .bind(star_count)
-            .execute(&self.pool)
-            .await?;
-
-        Ok(())
+        self.storage
+            .update_server_config_star_count(server_id, star_count)
+            .await
     }
 
     pub async fn update_server_config_starboard_channel(
@@ -284,31 +447,156 @@ impl State {
         server_id: &str,
         starboard_channel: &str,
     ) -> Result<(), Error> {
-        sqlx::query("insert into server_configs(server_id, star_count, starboard_channel) values ($1, $2, $3) on conflict (server_id) do update set starboard_channel=$2")
-            .bind(server_id)
-            .bind(self.config.limits.min_stars)
-            .bind(starboard_channel)
-            .execute(&self.pool)
-            .await?;
+        self.storage
+            .update_server_config_starboard_channel(
+                server_id,
+                self.config().limits.min_stars,
+                starboard_channel,
+            )
+            .await
+    }
 
-        Ok(())
+    pub async fn update_server_config_mod_log_channel(
+        &self,
+        server_id: &str,
+        mod_log_channel: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .update_server_config_mod_log_channel(
+                server_id,
+                self.config().limits.min_stars,
+                mod_log_channel,
+            )
+            .await
+    }
+
+    pub async fn update_server_config_message_log_channel(
+        &self,
+        server_id: &str,
+        message_log_channel: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .update_server_config_message_log_channel(
+                server_id,
+                self.config().limits.min_stars,
+                message_log_channel,
+            )
+            .await
+    }
+
+    pub async fn update_server_config_welcome(
+        &self,
+        server_id: &str,
+        welcome_channel: Option<&str>,
+        welcome_message: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .update_server_config_welcome(
+                server_id,
+                self.config().limits.min_stars,
+                welcome_channel,
+                welcome_message,
+            )
+            .await
+    }
+
+    pub async fn update_server_config_goodbye(
+        &self,
+        server_id: &str,
+        goodbye_channel: Option<&str>,
+        goodbye_message: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .update_server_config_goodbye(
+                server_id,
+                self.config().limits.min_stars,
+                goodbye_channel,
+                goodbye_message,
+            )
+            .await
+    }
+
+    pub async fn update_server_config_member_log_channel(
+        &self,
+        server_id: &str,
+        member_log_channel: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .update_server_config_member_log_channel(
+                server_id,
+                self.config().limits.min_stars,
+                member_log_channel,
+            )
+            .await
+    }
+
+    pub async fn update_server_config_auto_roles(
+        &self,
+        server_id: &str,
+        auto_roles: &[String],
+    ) -> Result<(), Error> {
+        self.storage
+            .update_server_config_auto_roles(server_id, self.config().limits.min_stars, auto_roles)
+            .await
     }
 
     pub async fn fetch_server_config(&self, server_id: &str) -> Result<ServerConfig, Error> {
-        let config = sqlx::query_as(
-            "select star_count, starboard_channel from server_configs where server_id=$1",
-        )
-        .bind(server_id)
-        .fetch_optional(&self.pool)
-        .await?
-        .unwrap_or_else(|| ServerConfig {
-            star_count: self.config.limits.min_stars,
-            starboard_channel: None,
-        });
+        let config = self
+            .storage
+            .fetch_server_config(server_id)
+            .await?
+            .unwrap_or_else(|| ServerConfig {
+                star_count: self.config().limits.min_stars,
+                starboard_channel: None,
+                mod_log_channel: None,
+                message_log_channel: None,
+                welcome_channel: None,
+                welcome_message: None,
+                goodbye_channel: None,
+                goodbye_message: None,
+                member_log_channel: None,
+                auto_roles: Vec::new(),
+                locale: None,
+            });
 
         Ok(config)
     }
 
+    pub async fn get_server_prefixes(&self, server_id: &str) -> Result<Vec<String>, Error> {
+        let mut lock = self.cached_prefixes.lock().await;
+
+        if let Some(prefixes) = lock.get(server_id) {
+            return Ok(prefixes.clone());
+        };
+
+        let prefixes = self
+            .storage
+            .fetch_server_prefixes(server_id)
+            .await?
+            .unwrap_or_default();
+
+        lock.put(server_id.to_string(), prefixes.clone());
+
+        Ok(prefixes)
+    }
+
+    pub async fn set_server_prefixes(
+        &self,
+        server_id: &str,
+        prefixes: &[String],
+    ) -> Result<(), Error> {
+        self.storage
+            .set_server_prefixes(server_id, self.config().limits.min_stars, prefixes)
+            .await?;
+
+        self.cached_prefixes
+            .lock()
+            .await
+            .put(server_id.to_string(), prefixes.to_vec());
+
+        Ok(())
+    }
+
     pub async fn add_starboard_message(
         &self,
         starboard_message_id: &str,
@@ -317,63 +605,667 @@ impl State {
         channel_id: &str,
         server_id: &str,
         star_count: i32,
+    ) -> Result<String, Error> {
+        self.storage
+            .add_starboard_message(
+                starboard_message_id,
+                message_id,
+                user_id,
+                channel_id,
+                server_id,
+                star_count,
+            )
+            .await
+    }
+
+    pub async fn update_starboard_message_star_count(
+        &self,
+        starboard_message_id: &str,
+        star_count: i32,
     ) -> Result<(), Error> {
-        sqlx::query("insert into starboard_messages(starboard_message_id, message_id, user_id, channel_id, server_id, star_count) values ($1, $2, $3, $4, $5, $6)")
-            .bind(starboard_message_id)
-            .bind(message_id)
-            .bind(user_id)
-            .bind(channel_id)
-            .bind(server_id)
-            .bind(star_count)
-            .execute(&self.pool)
+        self.storage
+            .update_starboard_message_star_count(starboard_message_id, star_count)
+            .await
+    }
+
+    pub async fn remove_starboard_message(&self, starboard_message_id: &str) -> Result<(), Error> {
+        self.storage
+            .remove_starboard_message(starboard_message_id)
+            .await
+    }
+
+    pub async fn get_starboard_original_message(
+        &self,
+        starboard_message_id: &str,
+    ) -> Result<Option<OriginalMessage>, Error> {
+        self.storage
+            .get_starboard_original_message(starboard_message_id)
+            .await
+    }
+
+    pub async fn get_starboard_message(&self, message_id: &str) -> Result<Option<String>, Error> {
+        self.storage.get_starboard_message(message_id).await
+    }
+
+    pub async fn create_mod_case(&self, case: &NewModCase) -> Result<ModCase, Error> {
+        self.storage.create_mod_case(case).await
+    }
+
+    pub async fn set_mod_case_log_message(
+        &self,
+        server_id: &str,
+        case_id: i32,
+        log_message_id: &str,
+    ) -> Result<(), Error> {
+        self.storage
+            .set_mod_case_log_message(server_id, case_id, log_message_id)
+            .await
+    }
+
+    pub async fn fetch_mod_case(
+        &self,
+        server_id: &str,
+        case_id: i32,
+    ) -> Result<Option<ModCase>, Error> {
+        self.storage.fetch_mod_case(server_id, case_id).await
+    }
+
+    pub async fn fetch_mod_cases_for_user(
+        &self,
+        server_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<ModCase>, Error> {
+        self.storage
+            .fetch_mod_cases_for_user(server_id, target_id)
+            .await
+    }
+
+    pub async fn update_mod_case_reason(
+        &self,
+        server_id: &str,
+        case_id: i32,
+        reason: &str,
+    ) -> Result<Option<ModCase>, Error> {
+        self.storage
+            .update_mod_case_reason(server_id, case_id, reason)
+            .await
+    }
+
+    pub async fn count_warns(&self, server_id: &str, target_id: &str) -> Result<i64, Error> {
+        self.storage.count_warns(server_id, target_id).await
+    }
+
+    pub async fn fetch_warn_thresholds(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<WarnThreshold>, Error> {
+        self.storage.fetch_warn_thresholds(server_id).await
+    }
+
+    pub async fn fetch_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+    ) -> Result<Option<WarnThreshold>, Error> {
+        self.storage
+            .fetch_warn_threshold(server_id, warn_count)
+            .await
+    }
+
+    pub async fn set_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+        action: CaseAction,
+        duration: Option<Duration>,
+    ) -> Result<(), Error> {
+        self.storage
+            .set_warn_threshold(server_id, warn_count, action, duration)
+            .await
+    }
+
+    pub async fn remove_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+    ) -> Result<bool, Error> {
+        self.storage
+            .remove_warn_threshold(server_id, warn_count)
+            .await
+    }
+
+    pub async fn schedule_unban(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        duration: Duration,
+    ) -> Result<(), Error> {
+        self.storage
+            .schedule_unban(server_id, user_id, duration)
+            .await
+    }
+
+    pub async fn remove_scheduled_unban(
+        &self,
+        server_id: &str,
+        user_id: &str,
+    ) -> Result<(), Error> {
+        self.storage
+            .remove_scheduled_unban(server_id, user_id)
+            .await
+    }
+
+    pub async fn fetch_due_unbans(&self) -> Result<Vec<ScheduledUnban>, Error> {
+        self.storage.fetch_due_unbans().await
+    }
+
+    pub async fn get_automod_config(&self, server_id: &str) -> Result<Arc<AutomodConfig>, Error> {
+        let mut lock = self.cached_automod.lock().await;
+
+        if let Some(config) = lock.get(server_id) {
+            return Ok(config.clone());
+        };
+
+        let config = Arc::new(self.storage.fetch_automod_config(server_id).await?);
+
+        lock.put(server_id.to_string(), config.clone());
+
+        Ok(config)
+    }
+
+    pub async fn enable_automod_rule(
+        &self,
+        server_id: &str,
+        rule: AutomodRuleKind,
+        threshold: Option<i32>,
+    ) -> Result<(), Error> {
+        self.storage
+            .enable_automod_rule(server_id, rule, threshold)
             .await?;
 
+        self.cached_automod.lock().await.pop(server_id);
+
         Ok(())
     }
 
-    pub async fn update_starboard_message_star_count(
+    pub async fn disable_automod_rule(
         &self,
-        starboard_message_id: &str,
-        star_count: i32,
+        server_id: &str,
+        rule: AutomodRuleKind,
+    ) -> Result<bool, Error> {
+        let removed = self.storage.disable_automod_rule(server_id, rule).await?;
+
+        self.cached_automod.lock().await.pop(server_id);
+
+        Ok(removed)
+    }
+
+    pub async fn set_automod_actions(
+        &self,
+        server_id: &str,
+        rule: AutomodRuleKind,
+        actions: &[AutomodAction],
+        timeout_duration: Option<Duration>,
+    ) -> Result<bool, Error> {
+        let updated = self
+            .storage
+            .set_automod_actions(server_id, rule, actions, timeout_duration)
+            .await?;
+
+        self.cached_automod.lock().await.pop(server_id);
+
+        Ok(updated)
+    }
+
+    pub async fn fetch_automod_exemptions(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<AutomodExemption>, Error> {
+        self.storage.fetch_automod_exemptions(server_id).await
+    }
+
+    pub async fn add_automod_exemption(
+        &self,
+        server_id: &str,
+        target_id: &str,
+        kind: &str,
     ) -> Result<(), Error> {
-        sqlx::query("update starboard_messages set star_count=$1 where starboard_channel_id=$2")
-            .bind(starboard_message_id)
-            .bind(star_count)
-            .execute(&self.pool)
+        self.storage
+            .add_automod_exemption(server_id, target_id, kind)
             .await?;
 
+        self.cached_automod.lock().await.pop(server_id);
+
         Ok(())
     }
 
-    pub async fn remove_starboard_message(&self, starboard_message_id: &str) -> Result<(), Error> {
-        sqlx::query("delete from starboard_messages where starboard_message=$1")
-            .bind(starboard_message_id)
-            .execute(&self.pool)
+    pub async fn remove_automod_exemption(
+        &self,
+        server_id: &str,
+        target_id: &str,
+    ) -> Result<bool, Error> {
+        let removed = self
+            .storage
+            .remove_automod_exemption(server_id, target_id)
             .await?;
 
+        self.cached_automod.lock().await.pop(server_id);
+
+        Ok(removed)
+    }
+
+    pub async fn add_automod_phrase(&self, server_id: &str, phrase: &str) -> Result<(), Error> {
+        self.storage.add_automod_phrase(server_id, phrase).await?;
+
+        self.cached_automod.lock().await.pop(server_id);
+
         Ok(())
     }
 
-    pub async fn get_starboard_original_message(
+    pub async fn remove_automod_phrase(
         &self,
-        starboard_message_id: &str,
-    ) -> Result<OriginalMessage, Error> {
-        let original_message = sqlx::query_as("select original_message, original_message_channel from starboard_messages where starboard_message=$1")
-            .bind(starboard_message_id)
-            .fetch_one(&self.pool)
+        server_id: &str,
+        phrase: &str,
+    ) -> Result<bool, Error> {
+        let removed = self
+            .storage
+            .remove_automod_phrase(server_id, phrase)
             .await?;
 
-        Ok(original_message)
+        self.cached_automod.lock().await.pop(server_id);
+
+        Ok(removed)
     }
 
-    pub async fn get_starboard_message(&self, message_id: &str) -> Result<Option<String>, Error> {
-        let message_id = sqlx::query_scalar(
-            "select starboard_message from starboard_messages where original_message=$1",
-        )
-        .bind(message_id)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        Ok(message_id)
+    pub async fn get_command_policies(
+        &self,
+        server_id: &str,
+    ) -> Result<Arc<CommandPolicies>, Error> {
+        let mut lock = self.cached_command_policies.lock().await;
+
+        if let Some(policies) = lock.get(server_id) {
+            return Ok(policies.clone());
+        };
+
+        let policies = Arc::new(self.storage.fetch_command_policies(server_id).await?);
+
+        lock.put(server_id.to_string(), policies.clone());
+
+        Ok(policies)
+    }
+
+    pub async fn save_command_policy(
+        &self,
+        server_id: &str,
+        policy: &CommandPolicy,
+    ) -> Result<(), Error> {
+        self.storage.save_command_policy(server_id, policy).await?;
+
+        self.cached_command_policies.lock().await.pop(server_id);
+
+        Ok(())
+    }
+
+    pub async fn remove_command_policy(
+        &self,
+        server_id: &str,
+        command: &str,
+    ) -> Result<bool, Error> {
+        let removed = self
+            .storage
+            .remove_command_policy(server_id, command)
+            .await?;
+
+        self.cached_command_policies.lock().await.pop(server_id);
+
+        Ok(removed)
+    }
+
+    pub async fn status_text(&self) -> String {
+        match self.status_override.read().await.clone() {
+            Some(status) => status,
+            None => format!("{}help", self.config().bot.prefix.first().unwrap()),
+        }
+    }
+
+    pub async fn load_blacklist(&self) -> Result<(), Error> {
+        let targets = self.storage.fetch_blacklisted_ids().await?;
+
+        *self.blacklist.write().await = HashSet::from_iter(targets);
+
+        Ok(())
+    }
+
+    pub async fn is_blacklisted(&self, target_id: &str) -> bool {
+        self.blacklist.read().await.contains(target_id)
+    }
+
+    pub async fn fetch_blacklist(&self) -> Result<Vec<BlacklistEntry>, Error> {
+        self.storage.fetch_blacklist().await
+    }
+
+    pub async fn add_to_blacklist(
+        &self,
+        target_id: &str,
+        kind: &str,
+        reason: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .add_to_blacklist(target_id, kind, reason)
+            .await?;
+
+        self.blacklist.write().await.insert(target_id.to_string());
+
+        Ok(())
+    }
+
+    pub async fn remove_from_blacklist(&self, target_id: &str) -> Result<bool, Error> {
+        let removed = self.storage.remove_from_blacklist(target_id).await?;
+
+        self.blacklist.write().await.remove(target_id);
+
+        Ok(removed)
+    }
+
+    pub async fn fetch_keyword_counts_for_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<(String, i64)>, Error> {
+        self.storage.fetch_keyword_counts_for_user(user_id).await
+    }
+
+    pub async fn count_keywords_for_server(&self, server_id: &str) -> Result<(i64, i64), Error> {
+        self.storage.count_keywords_for_server(server_id).await
+    }
+
+    pub async fn count_mod_cases_for_target(&self, target_id: &str) -> Result<i64, Error> {
+        self.storage.count_mod_cases_for_target(target_id).await
+    }
+
+    pub async fn count_mod_cases_for_server(&self, server_id: &str) -> Result<i64, Error> {
+        self.storage.count_mod_cases_for_server(server_id).await
+    }
+
+    pub async fn flush_caches(&self) -> usize {
+        let mut flushed = 0;
+
+        let mut keywords = self.cached_keywords.lock().await;
+        flushed += keywords.len();
+        keywords.clear();
+
+        let mut blocked = self.cached_blocked.lock().await;
+        flushed += blocked.len();
+        blocked.clear();
+
+        let mut automod = self.cached_automod.lock().await;
+        flushed += automod.len();
+        automod.clear();
+
+        let mut prefixes = self.cached_prefixes.lock().await;
+        flushed += prefixes.len();
+        prefixes.clear();
+
+        let mut policies = self.cached_command_policies.lock().await;
+        flushed += policies.len();
+        policies.clear();
+
+        let mut slowmodes = self.cached_slowmodes.lock().await;
+        flushed += slowmodes.len();
+        slowmodes.clear();
+
+        let mut reaction_roles = self.cached_reaction_roles.lock().await;
+        flushed += reaction_roles.len();
+        reaction_roles.clear();
+
+        let mut locales = self.cached_locales.lock().await;
+        flushed += locales.len();
+        locales.clear();
+
+        let mut permissions = self.permission_cache.lock().await;
+        flushed += permissions.len();
+        permissions.clear();
+
+        flushed
+    }
+
+    pub async fn create_reminder(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        message_id: &str,
+        content: &str,
+        due_at: i64,
+        interval: Option<i64>,
+    ) -> Result<Reminder, Error> {
+        self.storage
+            .create_reminder(user_id, channel_id, message_id, content, due_at, interval)
+            .await
+    }
+
+    pub async fn fetch_reminders_for_user(&self, user_id: &str) -> Result<Vec<Reminder>, Error> {
+        self.storage.fetch_reminders_for_user(user_id).await
+    }
+
+    pub async fn count_reminders_for_user(&self, user_id: &str) -> Result<i64, Error> {
+        self.storage.count_reminders_for_user(user_id).await
+    }
+
+    pub async fn fetch_due_reminders(&self) -> Result<Vec<Reminder>, Error> {
+        self.storage.fetch_due_reminders().await
+    }
+
+    pub async fn reschedule_reminder(&self, id: i32, due_at: i64) -> Result<(), Error> {
+        self.storage.reschedule_reminder(id, due_at).await
+    }
+
+    pub async fn remove_reminder(&self, id: i32) -> Result<(), Error> {
+        self.storage.remove_reminder(id).await
+    }
+
+    pub async fn cancel_reminder(&self, user_id: &str, id: i32) -> Result<bool, Error> {
+        self.storage.cancel_reminder(user_id, id).await
+    }
+
+    pub async fn fetch_lockdown(&self, channel_id: &str) -> Result<Option<Lockdown>, Error> {
+        self.storage.fetch_lockdown(channel_id).await
+    }
+
+    pub async fn fetch_lockdowns_for_server(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<Lockdown>, Error> {
+        self.storage.fetch_lockdowns_for_server(server_id).await
+    }
+
+    pub async fn fetch_due_lockdowns(&self) -> Result<Vec<Lockdown>, Error> {
+        self.storage.fetch_due_lockdowns().await
+    }
+
+    pub async fn save_lockdown(&self, lockdown: &Lockdown) -> Result<(), Error> {
+        self.storage.save_lockdown(lockdown).await
+    }
+
+    pub async fn remove_lockdown(&self, channel_id: &str) -> Result<(), Error> {
+        self.storage.remove_lockdown(channel_id).await
+    }
+
+    pub async fn get_slowmode(&self, channel_id: &str) -> Result<Option<u64>, Error> {
+        let mut lock = self.cached_slowmodes.lock().await;
+
+        if let Some(interval) = lock.get(channel_id) {
+            return Ok(*interval);
+        };
+
+        let interval = self.storage.fetch_slowmode(channel_id).await?;
+
+        lock.put(channel_id.to_string(), interval);
+
+        Ok(interval)
+    }
+
+    pub async fn set_slowmode(
+        &self,
+        server_id: &str,
+        channel_id: &str,
+        interval: Option<u64>,
+    ) -> Result<(), Error> {
+        self.storage
+            .set_slowmode(server_id, channel_id, interval)
+            .await?;
+
+        self.cached_slowmodes
+            .lock()
+            .await
+            .put(channel_id.to_string(), interval);
+
+        Ok(())
+    }
+
+    pub async fn get_reaction_role_menu(
+        &self,
+        message_id: &str,
+    ) -> Result<Option<Arc<ReactionRoleMenu>>, Error> {
+        let mut lock = self.cached_reaction_roles.lock().await;
+
+        if let Some(menu) = lock.get(message_id) {
+            return Ok(menu.clone());
+        };
+
+        let menu = self
+            .storage
+            .fetch_reaction_role_menu(message_id)
+            .await?
+            .map(Arc::new);
+
+        lock.put(message_id.to_string(), menu.clone());
+
+        Ok(menu)
+    }
+
+    pub async fn fetch_reaction_role_panels(
+        &self,
+        server_id: &str,
+    ) -> Result<Vec<ReactionRolePanel>, Error> {
+        self.storage.fetch_reaction_role_panels(server_id).await
+    }
+
+    pub async fn create_reaction_role_panel(
+        &self,
+        panel: &ReactionRolePanel,
+        roles: &[ReactionRole],
+    ) -> Result<(), Error> {
+        self.storage
+            .create_reaction_role_panel(panel, roles)
+            .await?;
+
+        self.cached_reaction_roles.lock().await.put(
+            panel.message_id.clone(),
+            Some(Arc::new(ReactionRoleMenu {
+                panel: panel.clone(),
+                roles: roles.to_vec(),
+            })),
+        );
+
+        Ok(())
+    }
+
+    pub async fn delete_reaction_role_panel(
+        &self,
+        server_id: &str,
+        message_id: &str,
+    ) -> Result<Option<ReactionRolePanel>, Error> {
+        let panel = self
+            .storage
+            .delete_reaction_role_panel(server_id, message_id)
+            .await?;
+
+        self.cached_reaction_roles.lock().await.pop(message_id);
+
+        Ok(panel)
+    }
+
+    pub async fn get_user_locale(&self, user_id: &str) -> Result<Option<String>, Error> {
+        let mut lock = self.cached_locales.lock().await;
+
+        if let Some(locale) = lock.get(user_id) {
+            return Ok(locale.clone());
+        };
+
+        let locale = self.storage.fetch_user_locale(user_id).await?;
+
+        lock.put(user_id.to_string(), locale.clone());
+
+        Ok(locale)
+    }
+
+    pub async fn get_server_locale(&self, server_id: &str) -> Result<Option<String>, Error> {
+        let mut lock = self.cached_locales.lock().await;
+
+        if let Some(locale) = lock.get(server_id) {
+            return Ok(locale.clone());
+        };
+
+        let locale = self.storage.fetch_server_locale(server_id).await?;
+
+        lock.put(server_id.to_string(), locale.clone());
+
+        Ok(locale)
+    }
+
+    pub async fn resolve_locale(
+        &self,
+        user_id: &str,
+        server_id: Option<&str>,
+    ) -> Result<Locale, Error> {
+        let user_locale = self.get_user_locale(user_id).await?;
+
+        let server_locale = match server_id {
+            Some(server_id) => self.get_server_locale(server_id).await?,
+            None => None,
+        };
+
+        Ok(Locale::new(&[
+            user_locale.as_deref(),
+            server_locale.as_deref(),
+            Some(&self.config().bot.locale),
+        ]))
+    }
+
+    // for text posted to the whole server, like logs and audit log reasons
+    pub async fn server_locale(&self, server_id: &str) -> Result<Locale, Error> {
+        let server_locale = self.get_server_locale(server_id).await?;
+
+        Ok(Locale::new(&[
+            server_locale.as_deref(),
+            Some(&self.config().bot.locale),
+        ]))
+    }
+
+    pub async fn set_user_locale(&self, user_id: &str, locale: Option<&str>) -> Result<(), Error> {
+        self.storage.set_user_locale(user_id, locale).await?;
+
+        self.cached_locales
+            .lock()
+            .await
+            .put(user_id.to_string(), locale.map(str::to_string));
+
+        Ok(())
+    }
+
+    pub async fn set_server_locale(
+        &self,
+        server_id: &str,
+        locale: Option<&str>,
+    ) -> Result<(), Error> {
+        self.storage
+            .set_server_locale(server_id, self.config().limits.min_stars, locale)
+            .await?;
+
+        self.cached_locales
+            .lock()
+            .await
+            .put(server_id.to_string(), locale.map(str::to_string));
+
+        Ok(())
     }
 }
@@ -0,0 +1,275 @@
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use sqlx::PgPool;
+use stoat::async_trait;
+
+use crate::{
+    AutomodAction, AutomodConfig, AutomodExemption, AutomodRuleKind, BlacklistEntry, CaseAction,
+    CommandPolicies, CommandPolicy, Lockdown, ModCase, NewModCase, OriginalMessage, ReactionRole,
+    ReactionRoleMenu, ReactionRolePanel, Reminder, Result, ScheduledUnban, ServerConfig,
+    WarnThreshold,
+};
+
+mod postgres;
+mod sqlite;
+
+pub use postgres::*;
+pub use sqlite::*;
+
+#[async_trait]
+pub trait Storage: Debug + Send + Sync {
+    async fn migrate(&self) -> Result<()>;
+    async fn ping(&self) -> Result<()>;
+    async fn close(&self);
+
+    fn postgres(&self) -> Option<&PgPool> {
+        None
+    }
+
+    async fn fetch_keywords_for_user(&self, user_id: &str, server_id: &str) -> Result<Vec<String>>;
+    async fn fetch_keywords_for_server(&self, server_id: &str) -> Result<Vec<(String, String)>>;
+    async fn fetch_busiest_servers(&self) -> Result<Vec<String>>;
+    async fn add_keyword(&self, user_id: &str, server_id: &str, keyword: &str) -> Result<()>;
+    async fn remove_keyword(&self, user_id: &str, server_id: &str, keyword: &str) -> Result<bool>;
+    async fn clear_keywords(&self, user_id: &str, server_id: &str) -> Result<Vec<String>>;
+    async fn get_total_keyword_count(&self) -> Result<i64>;
+    async fn fetch_keyword_counts_for_user(&self, user_id: &str) -> Result<Vec<(String, i64)>>;
+    async fn count_keywords_for_server(&self, server_id: &str) -> Result<(i64, i64)>;
+
+    async fn remove_stale_membership_hints(&self, cutoff: i64) -> Result<()>;
+    async fn fetch_membership_hints(&self) -> Result<Vec<(String, String)>>;
+    async fn add_membership_hints(&self, server_id: &str, user_ids: &[String]) -> Result<()>;
+    async fn remove_membership_hints(&self, server_id: &str, user_ids: &[String]) -> Result<()>;
+
+    async fn block_user(&self, user_id: &str, blocked_user: &str) -> Result<()>;
+    async fn unblock_user(&self, user_id: &str, blocked_user: &str) -> Result<()>;
+    async fn fetch_blocked_users(&self, user_id: &str) -> Result<Vec<String>>;
+
+    async fn update_server_config_star_count(&self, server_id: &str, star_count: i32)
+    -> Result<()>;
+    async fn update_server_config_starboard_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        starboard_channel: &str,
+    ) -> Result<()>;
+    async fn update_server_config_mod_log_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        mod_log_channel: Option<&str>,
+    ) -> Result<()>;
+    async fn update_server_config_message_log_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        message_log_channel: Option<&str>,
+    ) -> Result<()>;
+    async fn update_server_config_welcome(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        welcome_channel: Option<&str>,
+        welcome_message: Option<&str>,
+    ) -> Result<()>;
+    async fn update_server_config_goodbye(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        goodbye_channel: Option<&str>,
+        goodbye_message: Option<&str>,
+    ) -> Result<()>;
+    async fn update_server_config_member_log_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        member_log_channel: Option<&str>,
+    ) -> Result<()>;
+    async fn update_server_config_auto_roles(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        auto_roles: &[String],
+    ) -> Result<()>;
+    async fn fetch_server_config(&self, server_id: &str) -> Result<Option<ServerConfig>>;
+    async fn fetch_server_prefixes(&self, server_id: &str) -> Result<Option<Vec<String>>>;
+    async fn set_server_prefixes(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        prefixes: &[String],
+    ) -> Result<()>;
+    async fn fetch_server_locale(&self, server_id: &str) -> Result<Option<String>>;
+    async fn set_server_locale(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        locale: Option<&str>,
+    ) -> Result<()>;
+
+    async fn fetch_user_locale(&self, user_id: &str) -> Result<Option<String>>;
+    async fn set_user_locale(&self, user_id: &str, locale: Option<&str>) -> Result<()>;
+
+    async fn add_starboard_message(
+        &self,
+        starboard_message_id: &str,
+        message_id: &str,
+        user_id: &str,
+        channel_id: &str,
+        server_id: &str,
+        star_count: i32,
+    ) -> Result<String>;
+    async fn update_starboard_message_star_count(
+        &self,
+        starboard_message_id: &str,
+        star_count: i32,
+    ) -> Result<()>;
+    async fn remove_starboard_message(&self, starboard_message_id: &str) -> Result<()>;
+    async fn get_starboard_original_message(
+        &self,
+        starboard_message_id: &str,
+    ) -> Result<Option<OriginalMessage>>;
+    async fn get_starboard_message(&self, message_id: &str) -> Result<Option<String>>;
+
+    async fn create_mod_case(&self, case: &NewModCase) -> Result<ModCase>;
+    async fn set_mod_case_log_message(
+        &self,
+        server_id: &str,
+        case_id: i32,
+        log_message_id: &str,
+    ) -> Result<()>;
+    async fn fetch_mod_case(&self, server_id: &str, case_id: i32) -> Result<Option<ModCase>>;
+    async fn fetch_mod_cases_for_user(
+        &self,
+        server_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<ModCase>>;
+    async fn update_mod_case_reason(
+        &self,
+        server_id: &str,
+        case_id: i32,
+        reason: &str,
+    ) -> Result<Option<ModCase>>;
+    async fn count_warns(&self, server_id: &str, target_id: &str) -> Result<i64>;
+    async fn count_mod_cases_for_target(&self, target_id: &str) -> Result<i64>;
+    async fn count_mod_cases_for_server(&self, server_id: &str) -> Result<i64>;
+
+    async fn fetch_warn_thresholds(&self, server_id: &str) -> Result<Vec<WarnThreshold>>;
+    async fn fetch_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+    ) -> Result<Option<WarnThreshold>>;
+    async fn set_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+        action: CaseAction,
+        duration: Option<Duration>,
+    ) -> Result<()>;
+    async fn remove_warn_threshold(&self, server_id: &str, warn_count: i32) -> Result<bool>;
+
+    async fn schedule_unban(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        duration: Duration,
+    ) -> Result<()>;
+    async fn remove_scheduled_unban(&self, server_id: &str, user_id: &str) -> Result<()>;
+    async fn fetch_due_unbans(&self) -> Result<Vec<ScheduledUnban>>;
+
+    async fn fetch_automod_config(&self, server_id: &str) -> Result<AutomodConfig>;
+    async fn enable_automod_rule(
+        &self,
+        server_id: &str,
+        rule: AutomodRuleKind,
+        threshold: Option<i32>,
+    ) -> Result<()>;
+    async fn disable_automod_rule(&self, server_id: &str, rule: AutomodRuleKind) -> Result<bool>;
+    async fn set_automod_actions(
+        &self,
+        server_id: &str,
+        rule: AutomodRuleKind,
+        actions: &[AutomodAction],
+        timeout_duration: Option<Duration>,
+    ) -> Result<bool>;
+    async fn fetch_automod_exemptions(&self, server_id: &str) -> Result<Vec<AutomodExemption>>;
+    async fn add_automod_exemption(
+        &self,
+        server_id: &str,
+        target_id: &str,
+        kind: &str,
+    ) -> Result<()>;
+    async fn remove_automod_exemption(&self, server_id: &str, target_id: &str) -> Result<bool>;
+    async fn add_automod_phrase(&self, server_id: &str, phrase: &str) -> Result<()>;
+    async fn remove_automod_phrase(&self, server_id: &str, phrase: &str) -> Result<bool>;
+
+    async fn fetch_command_policies(&self, server_id: &str) -> Result<CommandPolicies>;
+    async fn save_command_policy(&self, server_id: &str, policy: &CommandPolicy) -> Result<()>;
+    async fn remove_command_policy(&self, server_id: &str, command: &str) -> Result<bool>;
+
+    async fn fetch_blacklisted_ids(&self) -> Result<Vec<String>>;
+    async fn fetch_blacklist(&self) -> Result<Vec<BlacklistEntry>>;
+    async fn add_to_blacklist(
+        &self,
+        target_id: &str,
+        kind: &str,
+        reason: Option<&str>,
+    ) -> Result<()>;
+    async fn remove_from_blacklist(&self, target_id: &str) -> Result<bool>;
+
+    async fn create_reminder(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        message_id: &str,
+        content: &str,
+        due_at: i64,
+        interval: Option<i64>,
+    ) -> Result<Reminder>;
+    async fn fetch_reminders_for_user(&self, user_id: &str) -> Result<Vec<Reminder>>;
+    async fn count_reminders_for_user(&self, user_id: &str) -> Result<i64>;
+    async fn fetch_due_reminders(&self) -> Result<Vec<Reminder>>;
+    async fn reschedule_reminder(&self, id: i32, due_at: i64) -> Result<()>;
+    async fn remove_reminder(&self, id: i32) -> Result<()>;
+    async fn cancel_reminder(&self, user_id: &str, id: i32) -> Result<bool>;
+
+    async fn fetch_lockdown(&self, channel_id: &str) -> Result<Option<Lockdown>>;
+    async fn fetch_lockdowns_for_server(&self, server_id: &str) -> Result<Vec<Lockdown>>;
+    async fn fetch_due_lockdowns(&self) -> Result<Vec<Lockdown>>;
+    async fn save_lockdown(&self, lockdown: &Lockdown) -> Result<()>;
+    async fn remove_lockdown(&self, channel_id: &str) -> Result<()>;
+
+    async fn fetch_slowmode(&self, channel_id: &str) -> Result<Option<u64>>;
+    async fn set_slowmode(
+        &self,
+        server_id: &str,
+        channel_id: &str,
+        interval: Option<u64>,
+    ) -> Result<()>;
+
+    async fn fetch_reaction_role_menu(&self, message_id: &str) -> Result<Option<ReactionRoleMenu>>;
+    async fn fetch_reaction_role_panels(&self, server_id: &str) -> Result<Vec<ReactionRolePanel>>;
+    async fn create_reaction_role_panel(
+        &self,
+        panel: &ReactionRolePanel,
+        roles: &[ReactionRole],
+    ) -> Result<()>;
+    async fn delete_reaction_role_panel(
+        &self,
+        server_id: &str,
+        message_id: &str,
+    ) -> Result<Option<ReactionRolePanel>>;
+}
+
+pub fn is_sqlite_url(url: &str) -> bool {
+    url.starts_with("sqlite:")
+}
+
+pub async fn connect_storage(url: &str) -> Result<Arc<dyn Storage>> {
+    if is_sqlite_url(url) {
+        Ok(Arc::new(SqliteStorage::connect(url).await?))
+    } else {
+        Ok(Arc::new(PostgresStorage::connect(url).await?))
+    }
+}
@@ -0,0 +1,1119 @@
+use std::{collections::HashSet, time::Duration};
+
+use futures::TryStreamExt;
+use sqlx::PgPool;
+use stoat::async_trait;
+
+use crate::{
+    AutomodAction, AutomodConfig, AutomodExemption, AutomodRule, AutomodRuleKind, BlacklistEntry,
+    CaseAction, CommandPolicies, CommandPolicy, Lockdown, ModCase, NewModCase, OriginalMessage,
+    ReactionRole, ReactionRoleMenu, ReactionRolePanel, Reminder, Result, ScheduledUnban,
+    ServerConfig, Storage, WarnThreshold,
+};
+
+#[derive(Debug, Clone)]
+pub struct PostgresStorage {
+    pub pool: PgPool,
+}
+
+impl PostgresStorage {
+    pub async fn connect(url: &str) -> Result<Self> {
+        Ok(Self {
+            pool: PgPool::connect(url).await?,
+        })
+    }
+}
+
+#[async_trait]
+impl Storage for PostgresStorage {
+    async fn migrate(&self) -> Result<()> {
+        sqlx::raw_sql(include_str!("../../../schema.psql"))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<()> {
+        sqlx::query("select 1").execute(&self.pool).await?;
+
+        Ok(())
+    }
+
+    async fn close(&self) {
+        self.pool.close().await;
+    }
+
+    fn postgres(&self) -> Option<&PgPool> {
+        Some(&self.pool)
+    }
+
+    async fn fetch_keywords_for_user(&self, user_id: &str, server_id: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar("select keyword from highlights where user_id=$1 and server_id=$2")
+            .bind(user_id)
+            .bind(server_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_keywords_for_server(&self, server_id: &str) -> Result<Vec<(String, String)>> {
+        sqlx::query_as("select user_id, keyword from highlights where server_id=$1")
+            .bind(server_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_busiest_servers(&self) -> Result<Vec<String>> {
+        sqlx::query_scalar(
+            "select server_id from highlights group by server_id order by count(distinct user_id) desc",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn add_keyword(&self, user_id: &str, server_id: &str, keyword: &str) -> Result<()> {
+        sqlx::query("insert into highlights (user_id, server_id, keyword) values ($1, $2, $3)")
+            .bind(user_id)
+            .bind(server_id)
+            .bind(keyword)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_keyword(&self, user_id: &str, server_id: &str, keyword: &str) -> Result<bool> {
+        let row_count =
+            sqlx::query("delete from highlights where user_id=$1 and server_id=$2 and keyword=$3")
+                .bind(user_id)
+                .bind(server_id)
+                .bind(keyword)
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+
+        Ok(row_count != 0)
+    }
+
+    async fn clear_keywords(&self, user_id: &str, server_id: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar(
+            "delete from highlights where user_id=$1 and server_id=$2 returning keyword",
+        )
+        .bind(user_id)
+        .bind(server_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn get_total_keyword_count(&self) -> Result<i64> {
+        sqlx::query_scalar("select count(keyword) from highlights")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_keyword_counts_for_user(&self, user_id: &str) -> Result<Vec<(String, i64)>> {
+        sqlx::query_as(
+            "select server_id, count(keyword) from highlights where user_id=$1 group by server_id",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn count_keywords_for_server(&self, server_id: &str) -> Result<(i64, i64)> {
+        sqlx::query_as(
+            "select count(keyword), count(distinct user_id) from highlights where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn remove_stale_membership_hints(&self, cutoff: i64) -> Result<()> {
+        sqlx::query("delete from membership_hints where updated_at < $1")
+            .bind(cutoff)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_membership_hints(&self) -> Result<Vec<(String, String)>> {
+        sqlx::query_as("select server_id, user_id from membership_hints")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn add_membership_hints(&self, server_id: &str, user_ids: &[String]) -> Result<()> {
+        sqlx::query("insert into membership_hints (server_id, user_id) select $1, unnest($2::text[]) on conflict (server_id, user_id) do update set updated_at=extract(epoch from now())::bigint")
+            .bind(server_id)
+            .bind(user_ids)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_membership_hints(&self, server_id: &str, user_ids: &[String]) -> Result<()> {
+        sqlx::query("delete from membership_hints where server_id=$1 and user_id = any($2)")
+            .bind(server_id)
+            .bind(user_ids)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn block_user(&self, user_id: &str, blocked_user: &str) -> Result<()> {
+        sqlx::query("insert into blocks(user_id, blocked_user) values($1, $2)")
+            .bind(user_id)
+            .bind(blocked_user)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn unblock_user(&self, user_id: &str, blocked_user: &str) -> Result<()> {
+        sqlx::query("delete from blocks where user_id=$1 and blocked_user=$2")
+            .bind(user_id)
+            .bind(blocked_user)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_blocked_users(&self, user_id: &str) -> Result<Vec<String>> {
+        sqlx::query_scalar("select blocked_user from blocks where user_id=$1")
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn update_server_config_star_count(
+        &self,
+        server_id: &str,
+        star_count: i32,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count) values ($1, $2) on conflict (server_id) do update set star_count=$2")
+            .bind(server_id)
+            .bind(star_count)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_starboard_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        starboard_channel: &str,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, starboard_channel) values ($1, $2, $3) on conflict (server_id) do update set starboard_channel=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(starboard_channel)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_mod_log_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        mod_log_channel: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, mod_log_channel) values ($1, $2, $3) on conflict (server_id) do update set mod_log_channel=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(mod_log_channel)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_message_log_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        message_log_channel: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, message_log_channel) values ($1, $2, $3) on conflict (server_id) do update set message_log_channel=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(message_log_channel)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_welcome(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        welcome_channel: Option<&str>,
+        welcome_message: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, welcome_channel, welcome_message) values ($1, $2, $3, $4) on conflict (server_id) do update set welcome_channel=$3, welcome_message=$4")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(welcome_channel)
+            .bind(welcome_message)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_goodbye(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        goodbye_channel: Option<&str>,
+        goodbye_message: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, goodbye_channel, goodbye_message) values ($1, $2, $3, $4) on conflict (server_id) do update set goodbye_channel=$3, goodbye_message=$4")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(goodbye_channel)
+            .bind(goodbye_message)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_member_log_channel(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        member_log_channel: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, member_log_channel) values ($1, $2, $3) on conflict (server_id) do update set member_log_channel=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(member_log_channel)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn update_server_config_auto_roles(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        auto_roles: &[String],
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, auto_roles) values ($1, $2, $3) on conflict (server_id) do update set auto_roles=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(auto_roles)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_server_config(&self, server_id: &str) -> Result<Option<ServerConfig>> {
+        sqlx::query_as(
+            "select star_count, starboard_channel, mod_log_channel, message_log_channel, welcome_channel, welcome_message, goodbye_channel, goodbye_message, member_log_channel, auto_roles, locale from server_configs where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn fetch_server_prefixes(&self, server_id: &str) -> Result<Option<Vec<String>>> {
+        sqlx::query_scalar("select prefixes from server_configs where server_id=$1")
+            .bind(server_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn set_server_prefixes(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        prefixes: &[String],
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, prefixes) values ($1, $2, $3) on conflict (server_id) do update set prefixes=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(prefixes)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_server_locale(&self, server_id: &str) -> Result<Option<String>> {
+        let locale = sqlx::query_scalar::<_, Option<String>>(
+            "select locale from server_configs where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(locale.flatten())
+    }
+
+    async fn set_server_locale(
+        &self,
+        server_id: &str,
+        star_count: i32,
+        locale: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into server_configs(server_id, star_count, locale) values ($1, $2, $3) on conflict (server_id) do update set locale=$3")
+            .bind(server_id)
+            .bind(star_count)
+            .bind(locale)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_user_locale(&self, user_id: &str) -> Result<Option<String>> {
+        let locale = sqlx::query_scalar::<_, Option<String>>(
+            "select locale from user_settings where user_id=$1",
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(locale.flatten())
+    }
+
+    async fn set_user_locale(&self, user_id: &str, locale: Option<&str>) -> Result<()> {
+        sqlx::query("insert into user_settings(user_id, locale) values ($1, $2) on conflict (user_id) do update set locale=$2")
+            .bind(user_id)
+            .bind(locale)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add_starboard_message(
+        &self,
+        starboard_message_id: &str,
+        message_id: &str,
+        user_id: &str,
+        channel_id: &str,
+        server_id: &str,
+        star_count: i32,
+    ) -> Result<String> {
+        sqlx::query_scalar("insert into starboard_messages(starboard_message_id, message_id, user_id, channel_id, server_id, star_count) values ($1, $2, $3, $4, $5, $6) on conflict (message_id) do update set star_count=excluded.star_count returning starboard_message_id")
+            .bind(starboard_message_id)
+            .bind(message_id)
+            .bind(user_id)
+            .bind(channel_id)
+            .bind(server_id)
+            .bind(star_count)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn update_starboard_message_star_count(
+        &self,
+        starboard_message_id: &str,
+        star_count: i32,
+    ) -> Result<()> {
+        sqlx::query("update starboard_messages set star_count=$1 where starboard_message_id=$2")
+            .bind(star_count)
+            .bind(starboard_message_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_starboard_message(&self, starboard_message_id: &str) -> Result<()> {
+        sqlx::query("delete from starboard_messages where starboard_message_id=$1")
+            .bind(starboard_message_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_starboard_original_message(
+        &self,
+        starboard_message_id: &str,
+    ) -> Result<Option<OriginalMessage>> {
+        sqlx::query_as(
+            "select message_id, channel_id from starboard_messages where starboard_message_id=$1",
+        )
+        .bind(starboard_message_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn get_starboard_message(&self, message_id: &str) -> Result<Option<String>> {
+        sqlx::query_scalar(
+            "select starboard_message_id from starboard_messages where message_id=$1",
+        )
+        .bind(message_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn create_mod_case(&self, case: &NewModCase) -> Result<ModCase> {
+        let mut tx = self.pool.begin().await?;
+
+        // case ids are allocated from max(case_id), so serialise inserts per server until commit
+        sqlx::query("select pg_advisory_xact_lock(hashtext('mod_cases:' || $1))")
+            .bind(&case.server_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let case = sqlx::query_as("insert into mod_cases(server_id, case_id, action, target_id, moderator_id, reason, duration) values ($1, coalesce((select max(case_id) from mod_cases where server_id=$1), 0) + 1, $2, $3, $4, $5, $6) returning *")
+            .bind(&case.server_id)
+            .bind(case.action.as_str())
+            .bind(&case.target_id)
+            .bind(&case.moderator_id)
+            .bind(&case.reason)
+            .bind(case.duration.map(|duration| duration.as_secs() as i64))
+            .fetch_one(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(case)
+    }
+
+    async fn set_mod_case_log_message(
+        &self,
+        server_id: &str,
+        case_id: i32,
+        log_message_id: &str,
+    ) -> Result<()> {
+        sqlx::query("update mod_cases set log_message_id=$3 where server_id=$1 and case_id=$2")
+            .bind(server_id)
+            .bind(case_id)
+            .bind(log_message_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_mod_case(&self, server_id: &str, case_id: i32) -> Result<Option<ModCase>> {
+        sqlx::query_as("select * from mod_cases where server_id=$1 and case_id=$2")
+            .bind(server_id)
+            .bind(case_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_mod_cases_for_user(
+        &self,
+        server_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<ModCase>> {
+        sqlx::query_as(
+            "select * from mod_cases where server_id=$1 and target_id=$2 order by case_id desc",
+        )
+        .bind(server_id)
+        .bind(target_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn update_mod_case_reason(
+        &self,
+        server_id: &str,
+        case_id: i32,
+        reason: &str,
+    ) -> Result<Option<ModCase>> {
+        sqlx::query_as("update mod_cases set reason=$3, updated_at=extract(epoch from now())::bigint where server_id=$1 and case_id=$2 returning *")
+            .bind(server_id)
+            .bind(case_id)
+            .bind(reason)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn count_warns(&self, server_id: &str, target_id: &str) -> Result<i64> {
+        sqlx::query_scalar(
+            "select count(*) from mod_cases where server_id=$1 and target_id=$2 and action='warn'",
+        )
+        .bind(server_id)
+        .bind(target_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn count_mod_cases_for_target(&self, target_id: &str) -> Result<i64> {
+        sqlx::query_scalar("select count(*) from mod_cases where target_id=$1")
+            .bind(target_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn count_mod_cases_for_server(&self, server_id: &str) -> Result<i64> {
+        sqlx::query_scalar("select count(*) from mod_cases where server_id=$1")
+            .bind(server_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_warn_thresholds(&self, server_id: &str) -> Result<Vec<WarnThreshold>> {
+        sqlx::query_as(
+            "select warn_count, action, duration from warn_thresholds where server_id=$1 order by warn_count",
+        )
+        .bind(server_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn fetch_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+    ) -> Result<Option<WarnThreshold>> {
+        sqlx::query_as(
+            "select warn_count, action, duration from warn_thresholds where server_id=$1 and warn_count=$2",
+        )
+        .bind(server_id)
+        .bind(warn_count)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn set_warn_threshold(
+        &self,
+        server_id: &str,
+        warn_count: i32,
+        action: CaseAction,
+        duration: Option<Duration>,
+    ) -> Result<()> {
+        sqlx::query("insert into warn_thresholds(server_id, warn_count, action, duration) values ($1, $2, $3, $4) on conflict (server_id, warn_count) do update set action=$3, duration=$4")
+            .bind(server_id)
+            .bind(warn_count)
+            .bind(action.as_str())
+            .bind(duration.map(|duration| duration.as_secs() as i64))
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_warn_threshold(&self, server_id: &str, warn_count: i32) -> Result<bool> {
+        let row_count =
+            sqlx::query("delete from warn_thresholds where server_id=$1 and warn_count=$2")
+                .bind(server_id)
+                .bind(warn_count)
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+
+        Ok(row_count != 0)
+    }
+
+    async fn schedule_unban(
+        &self,
+        server_id: &str,
+        user_id: &str,
+        duration: Duration,
+    ) -> Result<()> {
+        sqlx::query("insert into scheduled_unbans(server_id, user_id, unban_at) values ($1, $2, extract(epoch from now())::bigint + $3) on conflict (server_id, user_id) do update set unban_at=excluded.unban_at")
+            .bind(server_id)
+            .bind(user_id)
+            .bind(duration.as_secs() as i64)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_scheduled_unban(&self, server_id: &str, user_id: &str) -> Result<()> {
+        sqlx::query("delete from scheduled_unbans where server_id=$1 and user_id=$2")
+            .bind(server_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_due_unbans(&self) -> Result<Vec<ScheduledUnban>> {
+        sqlx::query_as(
+            "select server_id, user_id from scheduled_unbans where unban_at <= extract(epoch from now())::bigint",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn fetch_automod_config(&self, server_id: &str) -> Result<AutomodConfig> {
+        let rules = sqlx::query_as::<_, AutomodRule>(
+            "select rule, threshold, actions, timeout_duration from automod_rules where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let exemptions = sqlx::query_scalar::<_, String>(
+            "select target_id from automod_exemptions where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let phrases = sqlx::query_scalar::<_, String>(
+            "select phrase from automod_phrases where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(AutomodConfig {
+            rules,
+            exemptions: HashSet::from_iter(exemptions),
+            phrases,
+        })
+    }
+
+    async fn enable_automod_rule(
+        &self,
+        server_id: &str,
+        rule: AutomodRuleKind,
+        threshold: Option<i32>,
+    ) -> Result<()> {
+        sqlx::query("insert into automod_rules(server_id, rule, threshold, actions) values ($1, $2, $3, $4) on conflict (server_id, rule) do update set threshold=$3")
+            .bind(server_id)
+            .bind(rule.as_str())
+            .bind(threshold)
+            .bind(vec![AutomodAction::Delete.as_str()])
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn disable_automod_rule(&self, server_id: &str, rule: AutomodRuleKind) -> Result<bool> {
+        let row_count = sqlx::query("delete from automod_rules where server_id=$1 and rule=$2")
+            .bind(server_id)
+            .bind(rule.as_str())
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+
+        Ok(row_count != 0)
+    }
+
+    async fn set_automod_actions(
+        &self,
+        server_id: &str,
+        rule: AutomodRuleKind,
+        actions: &[AutomodAction],
+        timeout_duration: Option<Duration>,
+    ) -> Result<bool> {
+        let row_count = sqlx::query("update automod_rules set actions=$3, timeout_duration=$4 where server_id=$1 and rule=$2")
+            .bind(server_id)
+            .bind(rule.as_str())
+            .bind(actions.iter().map(|action| action.as_str()).collect::<Vec<_>>())
+            .bind(timeout_duration.map(|duration| duration.as_secs() as i64))
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+
+        Ok(row_count != 0)
+    }
+
+    async fn fetch_automod_exemptions(&self, server_id: &str) -> Result<Vec<AutomodExemption>> {
+        sqlx::query_as("select target_id, kind from automod_exemptions where server_id=$1")
+            .bind(server_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn add_automod_exemption(
+        &self,
+        server_id: &str,
+        target_id: &str,
+        kind: &str,
+    ) -> Result<()> {
+        sqlx::query("insert into automod_exemptions(server_id, target_id, kind) values ($1, $2, $3) on conflict do nothing")
+            .bind(server_id)
+            .bind(target_id)
+            .bind(kind)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_automod_exemption(&self, server_id: &str, target_id: &str) -> Result<bool> {
+        let row_count =
+            sqlx::query("delete from automod_exemptions where server_id=$1 and target_id=$2")
+                .bind(server_id)
+                .bind(target_id)
+                .execute(&self.pool)
+                .await?
+                .rows_affected();
+
+        Ok(row_count != 0)
+    }
+
+    async fn add_automod_phrase(&self, server_id: &str, phrase: &str) -> Result<()> {
+        sqlx::query(
+            "insert into automod_phrases(server_id, phrase) values ($1, $2) on conflict do nothing",
+        )
+        .bind(server_id)
+        .bind(phrase)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_automod_phrase(&self, server_id: &str, phrase: &str) -> Result<bool> {
+        let row_count = sqlx::query("delete from automod_phrases where server_id=$1 and phrase=$2")
+            .bind(server_id)
+            .bind(phrase)
+            .execute(&self.pool)
+            .await?
+            .rows_affected();
+
+        Ok(row_count != 0)
+    }
+
+    async fn fetch_command_policies(&self, server_id: &str) -> Result<CommandPolicies> {
+        sqlx::query_as::<_, CommandPolicy>(
+            "select command, disabled, channels, allowed_roles, denied_roles from command_policies where server_id=$1",
+        )
+        .bind(server_id)
+        .fetch(&self.pool)
+        .map_ok(|policy| (policy.command.clone(), policy))
+        .try_collect()
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn save_command_policy(&self, server_id: &str, policy: &CommandPolicy) -> Result<()> {
+        sqlx::query("insert into command_policies(server_id, command, disabled, channels, allowed_roles, denied_roles) values ($1, $2, $3, $4, $5, $6) on conflict (server_id, command) do update set disabled=$3, channels=$4, allowed_roles=$5, denied_roles=$6")
+            .bind(server_id)
+            .bind(&policy.command)
+            .bind(policy.disabled)
+            .bind(&policy.channels)
+            .bind(&policy.allowed_roles)
+            .bind(&policy.denied_roles)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_command_policy(&self, server_id: &str, command: &str) -> Result<bool> {
+        let result = sqlx::query("delete from command_policies where server_id=$1 and command=$2")
+            .bind(server_id)
+            .bind(command)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn fetch_blacklisted_ids(&self) -> Result<Vec<String>> {
+        sqlx::query_scalar("select target_id from blacklist")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_blacklist(&self) -> Result<Vec<BlacklistEntry>> {
+        sqlx::query_as(
+            "select target_id, kind, reason, created_at from blacklist order by created_at desc",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn add_to_blacklist(
+        &self,
+        target_id: &str,
+        kind: &str,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        sqlx::query("insert into blacklist(target_id, kind, reason) values ($1, $2, $3) on conflict (target_id) do update set kind=$2, reason=$3")
+            .bind(target_id)
+            .bind(kind)
+            .bind(reason)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_from_blacklist(&self, target_id: &str) -> Result<bool> {
+        let result = sqlx::query("delete from blacklist where target_id=$1")
+            .bind(target_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_reminder(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        message_id: &str,
+        content: &str,
+        due_at: i64,
+        interval: Option<i64>,
+    ) -> Result<Reminder> {
+        sqlx::query_as("insert into reminders(user_id, channel_id, message_id, content, due_at, interval) values ($1, $2, $3, $4, $5, $6) returning id, user_id, channel_id, message_id, content, due_at, interval")
+            .bind(user_id)
+            .bind(channel_id)
+            .bind(message_id)
+            .bind(content)
+            .bind(due_at)
+            .bind(interval)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_reminders_for_user(&self, user_id: &str) -> Result<Vec<Reminder>> {
+        sqlx::query_as("select id, user_id, channel_id, message_id, content, due_at, interval from reminders where user_id=$1 order by due_at")
+            .bind(user_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn count_reminders_for_user(&self, user_id: &str) -> Result<i64> {
+        sqlx::query_scalar("select count(*) from reminders where user_id=$1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_due_reminders(&self) -> Result<Vec<Reminder>> {
+        sqlx::query_as("select id, user_id, channel_id, message_id, content, due_at, interval from reminders where due_at <= extract(epoch from now())::bigint")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn reschedule_reminder(&self, id: i32, due_at: i64) -> Result<()> {
+        sqlx::query("update reminders set due_at=$2 where id=$1")
+            .bind(id)
+            .bind(due_at)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_reminder(&self, id: i32) -> Result<()> {
+        sqlx::query("delete from reminders where id=$1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn cancel_reminder(&self, user_id: &str, id: i32) -> Result<bool> {
+        let result = sqlx::query("delete from reminders where id=$1 and user_id=$2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn fetch_lockdown(&self, channel_id: &str) -> Result<Option<Lockdown>> {
+        sqlx::query_as("select * from lockdowns where channel_id=$1")
+            .bind(channel_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_lockdowns_for_server(&self, server_id: &str) -> Result<Vec<Lockdown>> {
+        sqlx::query_as("select * from lockdowns where server_id=$1")
+            .bind(server_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn fetch_due_lockdowns(&self) -> Result<Vec<Lockdown>> {
+        sqlx::query_as(
+            "select * from lockdowns where expires_at <= extract(epoch from now())::bigint",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| e.into())
+    }
+
+    async fn save_lockdown(&self, lockdown: &Lockdown) -> Result<()> {
+        sqlx::query("insert into lockdowns(channel_id, server_id, previous_allow, previous_deny, expires_at) values ($1, $2, $3, $4, $5) on conflict (channel_id) do update set expires_at=excluded.expires_at")
+            .bind(&lockdown.channel_id)
+            .bind(&lockdown.server_id)
+            .bind(lockdown.previous_allow)
+            .bind(lockdown.previous_deny)
+            .bind(lockdown.expires_at)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn remove_lockdown(&self, channel_id: &str) -> Result<()> {
+        sqlx::query("delete from lockdowns where channel_id=$1")
+            .bind(channel_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn fetch_slowmode(&self, channel_id: &str) -> Result<Option<u64>> {
+        let interval =
+            sqlx::query_scalar::<_, i64>("select interval from slowmodes where channel_id=$1")
+                .bind(channel_id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(interval.map(|interval| interval as u64))
+    }
+
+    async fn set_slowmode(
+        &self,
+        server_id: &str,
+        channel_id: &str,
+        interval: Option<u64>,
+    ) -> Result<()> {
+        match interval {
+            Some(interval) => {
+                sqlx::query("insert into slowmodes(channel_id, server_id, interval) values ($1, $2, $3) on conflict (channel_id) do update set interval=excluded.interval")
+                    .bind(channel_id)
+                    .bind(server_id)
+                    .bind(interval as i64)
+                    .execute(&self.pool)
+                    .await?;
+            }
+            None => {
+                sqlx::query("delete from slowmodes where channel_id=$1")
+                    .bind(channel_id)
+                    .execute(&self.pool)
+                    .await?;
+            }
+        };
+
+        Ok(())
+    }
+
+    async fn fetch_reaction_role_menu(&self, message_id: &str) -> Result<Option<ReactionRoleMenu>> {
+        let panel = sqlx::query_as::<_, ReactionRolePanel>(
+            "select message_id, server_id, channel_id, mode from reaction_role_panels where message_id=$1",
+        )
+        .bind(message_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(panel) = panel else {
+            return Ok(None);
+        };
+
+        let roles = sqlx::query_as::<_, ReactionRole>(
+            "select emoji, role_id from reaction_roles where message_id=$1",
+        )
+        .bind(message_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(Some(ReactionRoleMenu { panel, roles }))
+    }
+
+    async fn fetch_reaction_role_panels(&self, server_id: &str) -> Result<Vec<ReactionRolePanel>> {
+        sqlx::query_as("select message_id, server_id, channel_id, mode from reaction_role_panels where server_id=$1")
+            .bind(server_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
+    async fn create_reaction_role_panel(
+        &self,
+        panel: &ReactionRolePanel,
+        roles: &[ReactionRole],
+    ) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query("insert into reaction_role_panels(message_id, server_id, channel_id, mode) values ($1, $2, $3, $4)")
+            .bind(&panel.message_id)
+            .bind(&panel.server_id)
+            .bind(&panel.channel_id)
+            .bind(panel.mode.as_str())
+            .execute(&mut *tx)
+            .await?;
+
+        for role in roles {
+            sqlx::query(
+                "insert into reaction_roles(message_id, emoji, role_id) values ($1, $2, $3)",
+            )
+            .bind(&panel.message_id)
+            .bind(&role.emoji)
+            .bind(&role.role_id)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_reaction_role_panel(
+        &self,
+        server_id: &str,
+        message_id: &str,
+    ) -> Result<Option<ReactionRolePanel>> {
+        sqlx::query_as("delete from reaction_role_panels where server_id=$1 and message_id=$2 returning message_id, server_id, channel_id, mode")
+            .bind(server_id)
+            .bind(message_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+}
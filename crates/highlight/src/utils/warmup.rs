@@ -0,0 +1,125 @@
+use std::{
+    collections::HashSet,
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+
+use stoat::{Context, types::OptionsFetchAllMembers};
+
+use crate::{Result, State};
+
+pub const MEMBERSHIP_HINT_TTL: Duration = Duration::from_secs(60 * 60 * 24 * 7);
+const WARM_SERVERS: usize = 25;
+
+pub fn spawn_cache_warmer(ctx: Context, state: State) {
+    if state.caches_warmed.swap(true, Ordering::SeqCst) {
+        return;
+    };
+
+    tokio::spawn(async move {
+        if let Err(e) = warm_caches(&ctx, &state).await {
+            log::error!("Failed to warm caches: {e:?}");
+        };
+    });
+}
+
+async fn clear_stale_hints(ctx: &Context, state: &State) -> Result<()> {
+    let hints = state.known_not_in_server.read().await.clone();
+
+    for (server_id, user_ids) in hints {
+        let members = user_ids
+            .into_iter()
+            .filter(|user_id| ctx.cache.get_member(&server_id, user_id).is_some())
+            .collect::<Vec<_>>();
+
+        state.mark_in_server(&server_id, &members).await?;
+    }
+
+    Ok(())
+}
+
+async fn warm_server(ctx: &Context, state: &State, server_id: &str) -> Result<()> {
+    let owners = state
+        .get_keywords(server_id.to_string())
+        .await?
+        .into_keys()
+        .collect::<HashSet<_>>();
+
+    let mut members = owners
+        .iter()
+        .filter(|user_id| ctx.cache.get_member(server_id, user_id).is_some())
+        .cloned()
+        .collect::<HashSet<_>>();
+
+    if members.len() < owners.len() {
+        let response = ctx
+            .http
+            .fetch_server_members(
+                server_id,
+                &OptionsFetchAllMembers {
+                    exclude_offline: None,
+                },
+            )
+            .await?;
+
+        state
+            .set_member_count(server_id, response.members.len())
+            .await;
+
+        for member in response.members {
+            if owners.contains(&member.id.user) {
+                members.insert(member.id.user.clone());
+                ctx.cache.insert_member(member);
+            };
+        }
+
+        for user in response.users {
+            if owners.contains(&user.id) {
+                ctx.cache.insert_user(user);
+            };
+        }
+    };
+
+    let (present, absent): (Vec<_>, Vec<_>) = owners
+        .into_iter()
+        .partition(|user_id| members.contains(user_id));
+
+    state.mark_in_server(server_id, &present).await?;
+    state.mark_not_in_server(server_id, &absent).await?;
+
+    Ok(())
+}
+
+async fn warm_caches(ctx: &Context, state: &State) -> Result<()> {
+    let started = Instant::now();
+
+    clear_stale_hints(ctx, state).await?;
+
+    let servers = state
+        .fetch_busiest_servers()
+        .await?
+        .into_iter()
+        .filter(|server_id| {
+            state.owns_server(server_id) && ctx.cache.get_server(server_id).is_some()
+        })
+        .take(WARM_SERVERS)
+        .collect::<Vec<_>>();
+
+    for server_id in &servers {
+        if state.shutdown.is_stopping() {
+            return Ok(());
+        };
+
+        if let Err(e) = warm_server(ctx, state, server_id).await {
+            log::warn!("Failed to warm caches for server {server_id}: {e:?}");
+        };
+    }
+
+    log::info!(
+        "Warmed caches for {} servers in {:?}",
+        servers.len(),
+        started.elapsed()
+    );
+
+    Ok(())
+}
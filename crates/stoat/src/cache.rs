@@ -1,7 +1,10 @@
 use scc::HashMap;
 use std::{
     collections::VecDeque,
-    sync::{Arc, RwLock},
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 use stoat_models::v0::{
     Channel, ChannelVoiceState, Emoji, EmojiParent, Member, Message, Server, User, UserVoiceState,
@@ -44,6 +47,9 @@ pub struct GlobalCache {
     pub voice_connections: Arc<HashMap<String, crate::VoiceConnection>>,
 
     pub current_user_id: Arc<RwLock<Option<String>>>,
+
+    /// Whether the websocket is currently connected.
+    pub connected: Arc<AtomicBool>,
 }
 
 impl GlobalCache {
@@ -63,6 +69,8 @@ impl GlobalCache {
             voice_connections: Arc::new(HashMap::new()),
 
             current_user_id: Arc::new(RwLock::new(None)),
+
+            connected: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -432,6 +440,28 @@ impl GlobalCache {
         *self.current_user_id.write().unwrap() = Some(user_id);
     }
 
+    /// Whether the websocket is currently connected.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Amount of messages currently cached.
+    pub fn message_count(&self) -> usize {
+        self.messages.read().unwrap().len()
+    }
+
+    /// Amount of members currently cached across all servers.
+    pub fn member_count(&self) -> usize {
+        let mut count = 0;
+
+        self.members.iter_sync(|_, members| {
+            count += members.len();
+            true
+        });
+
+        count
+    }
+
     // Gets the current logged in user id.
     pub fn get_current_user_id(&self) -> Option<String> {
         self.current_user_id
@@ -1,6 +1,10 @@
-use std::{panic::AssertUnwindSafe, sync::Arc, time::Duration};
+use std::{
+    panic::AssertUnwindSafe,
+    sync::{Arc, atomic::Ordering},
+    time::Duration,
+};
 
-use futures::FutureExt;
+use futures::{FutureExt, future::BoxFuture};
 use stoat_database::events::client::EventV1;
 use tokio::{
     select,
@@ -17,6 +21,9 @@ use crate::{
     websocket::run,
 };
 
+/// Spawns the task for each received event, see [`Client::spawner`].
+pub type Spawner = Arc<dyn Fn(BoxFuture<'static, ()>) + Send + Sync>;
+
 /// # Stoat Client
 ///
 /// Entrypoint for connecting to the Stoat API.
@@ -64,6 +71,7 @@ pub struct Client<H> {
     pub http: HttpClient,
     pub waiters: Notifiers,
     pub events: Option<Events>,
+    pub spawner: Spawner,
 }
 
 impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
@@ -93,9 +101,21 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
             http,
             waiters: Notifiers::default(),
             events: None,
+            spawner: Arc::new(|fut| {
+                tokio::spawn(fut);
+            }),
         })
     }
 
+    /// Replaces how event tasks are spawned, useful for tracking in-flight events so they can be awaited before shutting down.
+    pub fn spawner(
+        mut self,
+        spawner: impl Fn(BoxFuture<'static, ()>) + Send + Sync + 'static,
+    ) -> Self {
+        self.spawner = Arc::new(spawner);
+        self
+    }
+
     /// Connects to the api and sets the current user.
     ///
     /// You will usually not need to call this directly as [`Self::run`] handles this.
@@ -110,7 +130,8 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
 
     /// Connects and starts the bot, this connects to the websocket to receive events, this is the main entry point for starting the bot.
     ///
-    /// Reconnects are handled automatically.
+    /// Reconnects are handled automatically. Returns once the bot stops receiving events, events which are still being handled keep running
+    /// so call [`Self::cleanup`] after they have finished.
     pub async fn run(&mut self, token: impl Into<String>) -> Result<(), H::Error> {
         let token = token.into();
 
@@ -129,14 +150,17 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
 
             async move {
                 loop {
-                    if let Err(e) = run(
+                    let res = run(
                         sender.clone(),
                         client_receiver.clone(),
                         state.clone(),
                         token.clone(),
                     )
-                    .await
-                    {
+                    .await;
+
+                    state.connected.store(false, Ordering::Relaxed);
+
+                    if let Err(e) = res {
                         log::error!("{e:?}");
 
                         if let Error::Close = e {
@@ -153,8 +177,8 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
 
         let res = select! {
             e = handle => e,
-            _ = tokio::signal::ctrl_c() => {
-                log::info!("Received ctrl+c. exiting.");
+            signal = shutdown_signal() => {
+                log::info!("Received {signal}. exiting.");
                 Ok(())
             }
             _ = self.handle_events(receiver) => {
@@ -162,12 +186,34 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
             }
         };
 
-        self.cleanup().await;
-
         res
     }
 
-    /// Clears the internal cache.
+    /// Processes events received from an external source instead of the websocket, such as a queue fed by another process.
+    ///
+    /// The client cannot send websocket messages while consuming, [`Context::events`] will return [`Error::BrokenChannel`].
+    /// Like [`Self::run`] this does not wait for in-flight events, call [`Self::cleanup`] after they have finished.
+    pub async fn consume(
+        &mut self,
+        token: impl Into<String>,
+        receiver: mpsc::UnboundedReceiver<EventV1>,
+    ) -> Result<(), H::Error> {
+        self.start(token).await?;
+
+        let (client_sender, _) = mpsc::unbounded_channel();
+        self.events = Some(Events(Arc::new(client_sender)));
+
+        select! {
+            signal = shutdown_signal() => {
+                log::info!("Received {signal}. exiting.");
+            }
+            _ = self.handle_events(receiver) => {}
+        };
+
+        Ok(())
+    }
+
+    /// Clears the internal cache and wakes up anything waiting on a notifier.
     pub async fn cleanup(&mut self) {
         self.state.cleanup().await;
         self.waiters.clear_all_waiters().await;
@@ -178,9 +224,9 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
         while let Some(event) = receiver.recv().await {
             let this = self.clone();
 
-            tokio::spawn(async move {
+            (self.spawner)(Box::pin(async move {
                 this.handle_event(event).await;
-            });
+            }));
         }
     }
 
@@ -204,3 +250,30 @@ impl<H: EventHandler + Clone + Send + Sync + 'static> Client<H> {
         }
     }
 }
+
+/// Waits for ctrl+c or, on unix, SIGTERM which is what container runtimes send on stop.
+async fn shutdown_signal() -> &'static str {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => select! {
+                _ = tokio::signal::ctrl_c() => "ctrl+c",
+                _ = sigterm.recv() => "SIGTERM",
+            },
+            Err(e) => {
+                log::error!("Failed to install SIGTERM handler: {e:?}");
+
+                let _ = tokio::signal::ctrl_c().await;
+                "ctrl+c"
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+        "ctrl+c"
+    }
+}
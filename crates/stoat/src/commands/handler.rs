@@ -118,8 +118,12 @@ impl<
 
         let Some(prefix) = prefixes
             .into_iter()
-            .filter(|prefix| message_content.starts_with(prefix))
-            .next()
+            .filter(|prefix| {
+                message_content
+                    .get(..prefix.len())
+                    .is_some_and(|start| start.to_lowercase() == prefix.to_lowercase())
+            })
+            .max_by_key(|prefix| prefix.len())
         else {
             // doesnt start with prefix
             return Ok(());
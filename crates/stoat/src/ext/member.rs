@@ -25,6 +25,13 @@ pub trait MemberExt {
         roles: &[Role],
     ) -> Result<Member>;
 
+    /// Removes roles from a member.
+    async fn remove_roles(
+        &self,
+        http: impl AsRef<HttpClient> + Send,
+        roles: &[Role],
+    ) -> Result<Member>;
+
     /// Gets the current voice channel and user voice state for a member if they are connected to a voice channel in this server
     fn voice(&self, cache: impl AsRef<GlobalCache>) -> Option<(Channel, UserVoiceState)>;
 
@@ -85,6 +92,36 @@ impl MemberExt for Member {
             .await
     }
 
+    async fn remove_roles(
+        &self,
+        http: impl AsRef<HttpClient> + Send,
+        roles: &[Role],
+    ) -> Result<Member> {
+        let new_roles = self
+            .roles
+            .iter()
+            .filter(|id| !roles.iter().any(|r| &r.id == *id))
+            .cloned()
+            .collect();
+
+        http.as_ref()
+            .edit_member(
+                &self.id.server,
+                &self.id.user,
+                &DataMemberEdit {
+                    nickname: None,
+                    avatar: None,
+                    roles: Some(new_roles),
+                    timeout: None,
+                    can_publish: None,
+                    can_receive: None,
+                    voice_channel: None,
+                    remove: Vec::new(),
+                },
+            )
+            .await
+    }
+
     fn voice(&self, cache: impl AsRef<GlobalCache>) -> Option<(Channel, UserVoiceState)> {
         let server_channels = cache
             .as_ref()
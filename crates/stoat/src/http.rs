@@ -12,14 +12,15 @@ use reqwest::{
 use scc::HashMap;
 use serde::{Deserialize, Serialize};
 use stoat_models::v0::{
-    BanListResult, BulkMessageResponse, Channel, CreateVoiceUserResponse, CreateWebhookBody,
-    DataBanCreate, DataCreateRole, DataCreateServerChannel, DataDefaultChannelPermissions,
-    DataEditChannel, DataEditMessage, DataEditRole, DataEditRoleRanks, DataEditServer,
-    DataEditUser, DataEditWebhook, DataJoinCall, DataMemberEdit, DataMessageSend,
-    DataSetRolePermissions, DataSetServerRolePermission, Emoji, FetchServerResponse, FlagResponse,
-    Invite, Member, Message, MutualResponse, NewRoleResponse, OptionsBulkDelete,
-    OptionsFetchServer, OptionsQueryMessages, OptionsServerDelete, OptionsUnreact, ResponseWebhook,
-    Role, Server, ServerBan, User, UserProfile, Webhook,
+    AllMemberResponse, BanListResult, BulkMessageResponse, Channel, CreateVoiceUserResponse,
+    CreateWebhookBody, DataBanCreate, DataCreateRole, DataCreateServerChannel,
+    DataDefaultChannelPermissions, DataEditChannel, DataEditMessage, DataEditRole,
+    DataEditRoleRanks, DataEditServer, DataEditUser, DataEditWebhook, DataJoinCall, DataMemberEdit,
+    DataMessageSend, DataSetRolePermissions, DataSetServerRolePermission, Emoji,
+    FetchServerResponse, FlagResponse, Invite, Member, Message, MutualResponse, NewRoleResponse,
+    OptionsBulkDelete, OptionsFetchAllMembers, OptionsFetchServer, OptionsQueryMessages,
+    OptionsServerDelete, OptionsUnreact, ResponseWebhook, Role, Server, ServerBan, User,
+    UserProfile, Webhook,
 };
 use stoat_permissions::DataPermissionsValue;
 use tokio::time::sleep;
@@ -53,6 +54,8 @@ pub struct HttpClient {
     pub user_id: Option<String>,
     pub inner: Client,
     pub ratelimits: Arc<HashMap<u64, RatelimitEntry>>,
+    /// Amount of requests made per ratelimit bucket.
+    pub request_counts: Arc<HashMap<String, u64>>,
 }
 
 impl AsRef<HttpClient> for HttpClient {
@@ -81,9 +84,11 @@ impl HttpClient {
     pub async fn new(base: String, token: Option<String>, user_id: Option<String>) -> Result<Self> {
         let client = Client::new();
         let ratelimits = Arc::new(HashMap::new());
+        let request_counts = Arc::new(HashMap::new());
 
         let api_config = HttpRequest {
             ratelimits: ratelimits.clone(),
+            request_counts: request_counts.clone(),
             service: Service::Api,
             builder: client.get(&base),
         }
@@ -97,6 +102,7 @@ impl HttpClient {
             user_id,
             inner: client,
             ratelimits,
+            request_counts,
         })
     }
 
@@ -113,6 +119,7 @@ impl HttpClient {
 
         HttpRequest {
             ratelimits: self.ratelimits.clone(),
+            request_counts: self.request_counts.clone(),
             service: Service::Api,
             builder,
         }
@@ -134,6 +141,7 @@ impl HttpClient {
 
         HttpRequest {
             ratelimits: self.ratelimits.clone(),
+            request_counts: self.request_counts.clone(),
             service: Service::Autumn,
             builder,
         }
@@ -413,6 +421,17 @@ impl HttpClient {
             .await
     }
 
+    pub async fn fetch_server_members(
+        &self,
+        server_id: &str,
+        options: &OptionsFetchAllMembers,
+    ) -> Result<AllMemberResponse> {
+        self.request(Method::GET, format!("/servers/{server_id}/members"))
+            .query(options)
+            .response()
+            .await
+    }
+
     pub async fn fetch_bans(&self, server_id: &str) -> Result<BanListResult> {
         self.request(Method::GET, format!("/servers/{server_id}/bans"))
             .response()
@@ -688,6 +707,18 @@ impl HttpClient {
             .await
     }
 
+    /// Returns the amount of requests made per ratelimit bucket.
+    pub fn request_counts(&self) -> Vec<(String, u64)> {
+        let mut counts = Vec::new();
+
+        self.request_counts.iter_sync(|bucket, count| {
+            counts.push((bucket.clone(), *count));
+            true
+        });
+
+        counts
+    }
+
     pub async fn execute_webhook_token(
         &self,
         webhook_id: &str,
@@ -703,6 +734,7 @@ impl HttpClient {
 
 pub struct HttpRequest {
     ratelimits: Arc<HashMap<u64, RatelimitEntry>>,
+    request_counts: Arc<HashMap<String, u64>>,
     service: Service,
     builder: RequestBuilder,
 }
@@ -806,6 +838,13 @@ impl HttpRequest {
 
         let key = key.finish();
 
+        *self
+            .request_counts
+            .entry_async(bucket.to_string())
+            .await
+            .or_default()
+            .get_mut() += 1;
+
         if let Some(entry) = self.ratelimits.get_async(&key).await {
             if entry.remaining == 0 {
                 let duration = Duration::from_millis(entry.reset as u64);
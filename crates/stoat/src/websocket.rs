@@ -1,5 +1,8 @@
 use futures::{FutureExt, SinkExt, StreamExt, future::select};
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{Arc, atomic::Ordering},
+    time::Duration,
+};
 use stoat_database::events::{
     client::{EventV1, Ping},
     server::ClientMessage,
@@ -77,6 +80,8 @@ pub(crate) async fn run(
             };
         })?;
 
+    global_state.connected.store(true, Ordering::Relaxed);
+
     let (ws_send, mut ws_receive) = ws.split();
 
     let ws_send = Arc::new(Mutex::new(ws_send));